@@ -0,0 +1,41 @@
+use bevy::asset::io::AssetSource;
+use bevy::asset::AssetApp;
+use bevy::window::WindowResolution;
+use bevy::{prelude::*, window::WindowMode};
+
+use dungeon_quest_v2::config::*;
+use dungeon_quest_v2::editor::EditorPlugin;
+use dungeon_quest_v2::resources::mods::ModList;
+
+fn main() {
+    App::new()
+        .register_asset_source(
+            MODS_ASSET_SOURCE,
+            AssetSource::build()
+                .with_reader(AssetSource::get_default_reader(MODS_DIRECTORY.to_string())),
+        )
+        .add_plugins(
+            DefaultPlugins
+                .set(WindowPlugin {
+                    primary_window: Some(Window {
+                        resolution: WindowResolution::new(
+                            WINDOW_HEIGHT * RESOLUTION,
+                            WINDOW_HEIGHT,
+                        ),
+                        title: format!("{} Editor", TITLE),
+                        position: WindowPosition::At(IVec2::new(
+                            MONITOR_WIDTH / 4,
+                            MONITOR_HEIGHT / 4,
+                        )),
+                        resizable: false,
+                        mode: WindowMode::Windowed,
+                        ..default()
+                    }),
+                    ..default()
+                })
+                .set(ImagePlugin::default_nearest()),
+        )
+        .init_resource::<ModList>()
+        .add_plugins(EditorPlugin)
+        .run();
+}
@@ -0,0 +1,62 @@
+use std::env;
+use std::process;
+
+use dungeon_quest_v2::resources::dungeon::floor::Floor;
+use dungeon_quest_v2::resources::dungeon::room::Room;
+use dungeon_quest_v2::resources::dungeon::rooms::Rooms;
+use dungeon_quest_v2::resources::dungeon::validation::{validate_floor, validate_room};
+use dungeon_quest_v2::resources::game_data::GameData;
+use dungeon_quest_v2::resources::mods::ModList;
+use dungeon_quest_v2::resources::tiled::TiledMap;
+
+const USAGE: &str = "Usage: tiled <room|floor> <map.tmx|map.json> <output.txt>";
+
+fn main() {
+    let arguments: Vec<String> = env::args().skip(1).collect();
+    if arguments.len() != 3 {
+        eprintln!("{}", USAGE);
+        process::exit(2);
+    }
+
+    let (kind, input, output) = (&arguments[0], &arguments[1], &arguments[2]);
+
+    let mod_list = ModList::load();
+    let game_data = GameData::new(&mod_list);
+    let map = TiledMap::load(input);
+
+    let errors = match kind.as_str() {
+        "room" => {
+            let room = Room {
+                id: Room::get_id_from_path(output),
+                tilemap: map.get_room_tilemap(Some(&game_data)),
+            };
+            let errors = validate_room(&room, Some(&game_data));
+            if errors.is_empty() {
+                room.save(output);
+            }
+            errors
+        }
+        "floor" => {
+            let floor_map = map.get_floor_map();
+            let errors = validate_floor(output, &floor_map, &Rooms::new(&mod_list, &game_data));
+            if errors.is_empty() {
+                Floor::save_map(&floor_map, output);
+            }
+            errors
+        }
+        _ => {
+            eprintln!("{}", USAGE);
+            process::exit(2);
+        }
+    };
+
+    if !errors.is_empty() {
+        eprintln!("Not saved:");
+        for error in errors.iter() {
+            eprintln!("{}", error);
+        }
+        process::exit(1);
+    }
+
+    println!("Saved {}", output);
+}
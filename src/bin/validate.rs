@@ -0,0 +1,18 @@
+use std::process;
+
+use dungeon_quest_v2::validator::ValidationReport;
+
+fn main() {
+    let report = ValidationReport::new();
+
+    println!("{}", serde_json::to_string_pretty(&report).unwrap());
+    eprintln!(
+        "Checked {} files, found {} errors",
+        report.checked_files.len(),
+        report.errors.len()
+    );
+
+    if !report.is_valid() {
+        process::exit(1);
+    }
+}
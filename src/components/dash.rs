@@ -0,0 +1,49 @@
+use bevy::prelude::*;
+use std::time::Duration;
+
+use crate::resources::hero::dash::Dash;
+use crate::resources::upgrade::Upgrade;
+
+#[derive(Component)]
+pub struct DashComponent {
+    pub dash: Dash,
+    pub direction: Vec3,
+    pub duration: Timer,
+    pub cooldown: Timer,
+}
+
+impl DashComponent {
+    pub fn new(dash: Dash) -> Self {
+        let mut duration = Timer::new(Duration::from_secs(0), TimerMode::Once);
+        duration.tick(Duration::from_secs(0));
+
+        let mut cooldown = Timer::new(Duration::from_secs(0), TimerMode::Once);
+        cooldown.tick(Duration::from_secs(0));
+
+        DashComponent {
+            dash,
+            direction: Vec3::ZERO,
+            duration,
+            cooldown,
+        }
+    }
+
+    pub fn start(&mut self, direction: Vec3) {
+        self.direction = direction.normalize();
+        self.duration = Timer::new(Duration::from_secs_f32(self.dash.duration), TimerMode::Once);
+        self.cooldown = Timer::new(Duration::from_secs_f32(self.dash.cooldown), TimerMode::Once);
+    }
+
+    pub fn upgrade(&mut self, upgrade: Upgrade) {
+        let stats_upgrade = upgrade.stats_upgrade.unwrap();
+        let cooldown_reduce = stats_upgrade.dash_cooldown_reduce.unwrap_or(0.0);
+        let invincible_duration_bonus = stats_upgrade.dash_invincible_duration_bonus.unwrap_or(0.0);
+
+        self.dash.cooldown = if self.dash.cooldown - cooldown_reduce > 0.3 {
+            self.dash.cooldown - cooldown_reduce
+        } else {
+            0.3
+        };
+        self.dash.invincible_duration += invincible_duration_bonus;
+    }
+}
@@ -0,0 +1,8 @@
+use bevy::prelude::*;
+
+#[derive(Component)]
+pub struct GoldComponent {
+    pub value: usize,
+    pub height: f32,
+    pub width: f32,
+}
@@ -0,0 +1,12 @@
+use bevy::prelude::*;
+
+use crate::resources::dungeon::hazard_type::HazardType;
+
+#[derive(Component)]
+pub struct HazardComponent {
+    pub hazard_type: HazardType,
+    pub timer: Timer,
+    pub is_active: bool,
+    pub width: f32,
+    pub height: f32,
+}
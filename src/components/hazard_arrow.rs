@@ -0,0 +1,9 @@
+use bevy::prelude::*;
+
+#[derive(Component)]
+pub struct HazardArrowComponent {
+    pub direction: Vec3,
+    pub duration: Timer,
+    pub width: f32,
+    pub height: f32,
+}
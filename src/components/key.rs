@@ -0,0 +1,7 @@
+use bevy::prelude::*;
+
+#[derive(Component)]
+pub struct KeyComponent {
+    pub width: f32,
+    pub height: f32,
+}
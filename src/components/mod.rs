@@ -1,13 +1,25 @@
 pub mod bullet;
+pub mod dash;
+pub mod gold;
+pub mod hazard;
+pub mod hazard_arrow;
 pub mod invinsible_cooldown;
+pub mod key;
 pub mod monster;
 pub mod monster_animation;
 pub mod monster_list_effects;
 pub mod player;
 pub mod player_animation;
+pub mod player_experience;
 pub mod player_list_effects;
+pub mod player_relics;
 pub mod potion;
+pub mod potion_belt;
+pub mod prop;
+pub mod relic_pickup;
 pub mod skill;
 pub mod weapon;
+pub mod weapon_inventory;
+pub mod weapon_pickup;
 pub mod weapon_shoot_attack;
 pub mod weapon_swing_attack;
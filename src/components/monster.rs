@@ -1,7 +1,7 @@
 use bevy::prelude::*;
 use bevy_inspector_egui::InspectorOptions;
 
-use crate::resources::effect::effect_type::EffectType;
+use crate::resources::effect::effect_id::EffectId;
 use crate::resources::monster::monster_class::MonsterClass;
 use crate::resources::monster::monster_skill::MonsterSkill;
 
@@ -11,9 +11,11 @@ pub struct MonsterComponent {
     pub max_health_points: f32,
     pub class: MonsterClass,
     pub damage: f32,
+    pub gold: usize,
+    pub experience: usize,
     pub level: u8,
     pub speed: f32,
-    pub trigger_effect: Option<EffectType>,
+    pub trigger_effect: Option<EffectId>,
     pub trigger_chance: f32,
     pub skill: Option<MonsterSkill>,
     pub width: f32,
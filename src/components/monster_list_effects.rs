@@ -28,6 +28,10 @@ impl MonsterListEffectsComponent {
             Timer::new(Duration::from_secs(0), TimerMode::Once),
         );
 
+        monster_list_effects
+            .activated_effects
+            .insert(EffectType::Poison, Timer::new(Duration::from_secs(0), TimerMode::Once));
+
         monster_list_effects
     }
 
@@ -47,6 +51,10 @@ impl MonsterListEffectsComponent {
                 self.activated_effects
                     .insert(EffectType::Slow, Timer::new(Duration::from_secs(3), TimerMode::Once));
             }
+            EffectType::Poison => {
+                self.activated_effects
+                    .insert(EffectType::Poison, Timer::new(Duration::from_secs(3), TimerMode::Once));
+            }
             _ => {}
         }
     }
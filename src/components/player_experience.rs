@@ -0,0 +1,40 @@
+use bevy::prelude::*;
+
+const BASE_REQUIRED_EXPERIENCE: usize = 10;
+const REQUIRED_EXPERIENCE_PER_LEVEL: usize = 8;
+
+#[derive(Component)]
+pub struct PlayerExperienceComponent {
+    pub level: usize,
+    pub experience: usize,
+    pub pending_level_ups: usize,
+}
+
+impl Default for PlayerExperienceComponent {
+    fn default() -> Self {
+        PlayerExperienceComponent::new()
+    }
+}
+
+impl PlayerExperienceComponent {
+    pub fn new() -> Self {
+        PlayerExperienceComponent {
+            level: 1,
+            experience: 0,
+            pending_level_ups: 0,
+        }
+    }
+
+    pub fn required_experience(&self) -> usize {
+        BASE_REQUIRED_EXPERIENCE + (self.level - 1) * REQUIRED_EXPERIENCE_PER_LEVEL
+    }
+
+    pub fn add(&mut self, experience: usize) {
+        self.experience += experience;
+        while self.experience >= self.required_experience() {
+            self.experience -= self.required_experience();
+            self.level += 1;
+            self.pending_level_ups += 1;
+        }
+    }
+}
@@ -2,14 +2,14 @@ use bevy::prelude::*;
 use std::collections::HashMap;
 use std::time::Duration;
 
-use crate::resources::effect::effect_type::EffectType;
+use crate::resources::effect::effect_id::EffectId;
 use crate::resources::effect::Effect;
 use crate::resources::upgrade::Upgrade;
 
 #[derive(Component, Debug)]
 pub struct PlayerListEffectsComponent {
     pub information: Vec<Effect>,
-    pub activated_effects: HashMap<EffectType, Timer>,
+    pub activated_effects: HashMap<EffectId, Timer>,
 }
 
 impl PlayerListEffectsComponent {
@@ -50,16 +50,23 @@ impl PlayerListEffectsComponent {
         information.bonus = information.bonus + bonus;
     }
 
-    pub fn activate(&mut self, effect_type: EffectType) {
-        let information = self
+    pub fn get(&self, effect_id: &EffectId) -> &Effect {
+        match self
             .information
-            .iter_mut()
-            .find(|effect_information| effect_information.name == effect_type)
-            .unwrap();
+            .iter()
+            .find(|effect_information| effect_information.name == *effect_id)
+        {
+            Some(information) => information,
+            None => panic!("Unknown effect {}", effect_id.0),
+        }
+    }
+
+    pub fn activate(&mut self, effect_id: &EffectId) {
+        let duration = self.get(effect_id).duration;
 
         self.activated_effects.insert(
-            effect_type,
-            Timer::new(Duration::from_secs(information.duration as u64), TimerMode::Once),
+            effect_id.clone(),
+            Timer::new(Duration::from_secs(duration as u64), TimerMode::Once),
         );
     }
 }
@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+
+use crate::resources::effect::effect_id::EffectId;
+use crate::resources::relic::Relic;
+
+#[derive(Component, Default)]
+pub struct PlayerRelicsComponent {
+    pub relics: Vec<Relic>,
+}
+
+impl PlayerRelicsComponent {
+    pub fn new() -> Self {
+        PlayerRelicsComponent { relics: Vec::new() }
+    }
+
+    pub fn add(&mut self, relic: Relic) {
+        self.relics.push(relic);
+    }
+
+    pub fn contains(&self, relic_name: &str) -> bool {
+        self.relics.iter().any(|relic| relic.name == relic_name)
+    }
+
+    pub fn damage_percent_bonus(&self) -> f32 {
+        self.relics
+            .iter()
+            .map(|relic| relic.damage_percent_bonus.unwrap_or(0.0))
+            .sum()
+    }
+
+    pub fn effect_bonus(&self, effect_id: &EffectId) -> f32 {
+        self.relics
+            .iter()
+            .filter(|relic| relic.boosted_effect.as_ref() == Some(effect_id))
+            .map(|relic| relic.boosted_effect_bonus.unwrap_or(0.0))
+            .sum()
+    }
+}
@@ -0,0 +1,58 @@
+use bevy::prelude::*;
+
+use crate::resources::potion::potion_type::PotionType;
+
+pub const POTION_BELT_SLOTS: [PotionType; 4] = [
+    PotionType::Heal,
+    PotionType::SpeedUp,
+    PotionType::EvasionUp,
+    PotionType::Focus,
+];
+pub const POTION_BELT_CAPACITY: usize = 3;
+
+#[derive(Component)]
+pub struct PotionBeltComponent {
+    pub counts: [usize; 4],
+}
+
+impl Default for PotionBeltComponent {
+    fn default() -> Self {
+        PotionBeltComponent::new()
+    }
+}
+
+impl PotionBeltComponent {
+    pub fn new() -> Self {
+        PotionBeltComponent { counts: [0; 4] }
+    }
+
+    pub fn count(&self, potion_type: PotionType) -> usize {
+        self.counts[Self::slot_index(potion_type)]
+    }
+
+    pub fn is_full(&self, potion_type: PotionType) -> bool {
+        self.count(potion_type) >= POTION_BELT_CAPACITY
+    }
+
+    pub fn add(&mut self, potion_type: PotionType) {
+        self.counts[Self::slot_index(potion_type)] += 1;
+    }
+
+    pub fn take(&mut self, potion_type: PotionType) -> bool {
+        let index = Self::slot_index(potion_type);
+        if self.counts[index] == 0 {
+            return false;
+        }
+        self.counts[index] -= 1;
+        true
+    }
+
+    fn slot_index(potion_type: PotionType) -> usize {
+        match potion_type {
+            PotionType::Heal => 0,
+            PotionType::SpeedUp => 1,
+            PotionType::EvasionUp => 2,
+            PotionType::Focus => 3,
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use bevy::prelude::*;
+
+#[derive(Component)]
+pub struct PropComponent {
+    pub name: String,
+    pub current_health_points: f32,
+    pub width: f32,
+    pub height: f32,
+    pub color: Color,
+    pub row_index: usize,
+    pub column_index: usize,
+    pub hurt_duration: Timer,
+}
+
+impl PropComponent {
+    pub fn hurt(&mut self, damage: f32) {
+        self.hurt_duration.reset();
+
+        self.current_health_points = if damage > self.current_health_points {
+            0.0
+        } else {
+            self.current_health_points - damage
+        };
+    }
+}
@@ -0,0 +1,10 @@
+use bevy::prelude::*;
+
+use crate::resources::relic::Relic;
+
+#[derive(Component)]
+pub struct RelicPickupComponent {
+    pub relic: Relic,
+    pub height: f32,
+    pub width: f32,
+}
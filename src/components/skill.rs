@@ -1,7 +1,7 @@
 use bevy::prelude::*;
+use std::ops::Add;
 use std::time::Duration;
 
-use crate::resources::skill::skill_type::SkillType;
 use crate::resources::skill::Skill;
 use crate::resources::upgrade::Upgrade;
 
@@ -31,44 +31,48 @@ impl SkillComponent {
     pub fn upgrade(&mut self, upgrade: Upgrade) {
         let skill_upgrade = upgrade.skill_upgrade.unwrap();
 
-        let duration_bonus = skill_upgrade.duration_bonus.unwrap_or(0);
-        let cooldown_reduce = skill_upgrade.cooldown_reduce.unwrap_or(0);
-        let require_monsters_reduce = skill_upgrade.require_monsters_reduce.unwrap_or(0);
+        self.skill.duration = add_bonus(self.skill.duration, skill_upgrade.duration_bonus);
 
-        let speed_percent_bonus = skill_upgrade.speed_percent_bonus.unwrap_or(0.0);
-        let critical_chance_bonus = skill_upgrade.critical_chance_bonus.unwrap_or(0.0);
-        let restore_chance_bonus = skill_upgrade.restore_chance_bonus.unwrap_or(0.0);
-        let dodge_chance_bonus = skill_upgrade.dodge_chance_bonus.unwrap_or(0.0);
+        if let (Some(cooldown), Some(cooldown_reduce)) =
+            (self.skill.cooldown, skill_upgrade.cooldown_reduce)
+        {
+            self.skill.cooldown = Some((cooldown - cooldown_reduce).max(1));
+        }
+
+        self.skill.area = add_bonus(self.skill.area, skill_upgrade.area_bonus);
+        self.skill.heal = add_bonus(self.skill.heal, skill_upgrade.heal_bonus);
+
+        if let (Some(damage), Some(multiplier_bonus)) =
+            (self.skill.damage.as_mut(), skill_upgrade.damage_multiplier_bonus)
+        {
+            damage.multiplier += multiplier_bonus;
+        }
 
-        let skill_duration = self.skill.duration.unwrap_or(0);
-        let skill_cooldown = self.skill.cooldown.unwrap_or(0);
-        let speed_percent = self.skill.speed_percent_bonus.unwrap_or(0.0);
-        let critical_chance = self.skill.speed_percent_bonus.unwrap_or(0.0);
-        let require_monsters = self.skill.require_monsters.unwrap_or(0);
-        let restore_chance = self.skill.restore_chance_bonus.unwrap_or(0.0);
-        let dodge_chance = self.skill.dodge_chance_bonus.unwrap_or(0.0);
+        if let (Some(require_monsters), Some(require_monsters_reduce)) =
+            (self.skill.require_monsters, skill_upgrade.require_monsters_reduce)
+        {
+            let require_monsters = require_monsters.saturating_sub(require_monsters_reduce).max(1);
+            self.skill.require_monsters = Some(require_monsters);
+            self.require_monsters = require_monsters;
+        }
+
+        self.skill.speed_percent_bonus =
+            add_bonus(self.skill.speed_percent_bonus, skill_upgrade.speed_percent_bonus);
+        self.skill.damge_precent_bonus =
+            add_bonus(self.skill.damge_precent_bonus, skill_upgrade.damage_percent_bonus);
+        self.skill.critical_chance_bonus =
+            add_bonus(self.skill.critical_chance_bonus, skill_upgrade.critical_chance_bonus);
+        self.skill.dodge_chance_bonus =
+            add_bonus(self.skill.dodge_chance_bonus, skill_upgrade.dodge_chance_bonus);
+        self.skill.restore_chance_bonus =
+            add_bonus(self.skill.restore_chance_bonus, skill_upgrade.restore_chance_bonus);
+    }
+}
 
-        match self.skill.name {
-            SkillType::TimeToHunt => {
-                self.skill.duration = Some(skill_duration + duration_bonus);
-                self.skill.cooldown = Some(skill_cooldown - cooldown_reduce);
-                self.skill.speed_percent_bonus = Some(speed_percent_bonus + speed_percent);
-                self.skill.critical_chance_bonus = Some(critical_chance + critical_chance_bonus);
-            }
-            SkillType::Armor => {
-                self.skill.require_monsters = Some(require_monsters - require_monsters_reduce);
-            }
-            SkillType::Thunderstorm => {
-                self.skill.cooldown = Some(skill_cooldown - cooldown_reduce);
-            }
-            SkillType::AnimalInstinct => {
-                self.skill.duration = Some(skill_duration + duration_bonus);
-                self.skill.cooldown = Some(skill_cooldown - cooldown_reduce);
-                self.skill.speed_percent_bonus = Some(speed_percent_bonus + speed_percent);
-                self.skill.critical_chance_bonus = Some(critical_chance + critical_chance_bonus);
-                self.skill.restore_chance_bonus = Some(restore_chance + restore_chance_bonus);
-                self.skill.dodge_chance_bonus = Some(dodge_chance + dodge_chance_bonus);
-            }
-        };
+fn add_bonus<T: Add<Output = T>>(value: Option<T>, bonus: Option<T>) -> Option<T> {
+    match (value, bonus) {
+        (Some(value), Some(bonus)) => Some(value + bonus),
+        (None, Some(bonus)) => Some(bonus),
+        (value, None) => value,
     }
 }
@@ -1,11 +1,11 @@
 use bevy::prelude::*;
 
-use crate::resources::effect::effect_type::EffectType;
+use crate::resources::effect::effect_id::EffectId;
 use crate::resources::weapon::attack_type::AttackType;
 use crate::resources::weapon::weapon_type::WeaponType;
 use crate::resources::weapon::Weapon;
 
-#[derive(Component, Copy, Clone)]
+#[derive(Component, Clone)]
 pub struct WeaponComponent {
     pub attack_type: AttackType,
     pub name: WeaponType,
@@ -15,9 +15,11 @@ pub struct WeaponComponent {
     pub scale: f32,
     pub strength: f32,
     pub intelligence: f32,
-    pub buff_effect: Option<EffectType>,
-    pub debuff_effect: Option<EffectType>,
+    pub effect: Option<EffectId>,
     pub trigger_chance: f32,
+    pub sprite: String,
+    pub aim_rotation: Option<f32>,
+    pub shoot_while_moving: bool,
 }
 
 impl WeaponComponent {
@@ -30,20 +32,10 @@ impl WeaponComponent {
         self.size_width = weapon.width;
         self.size_height = weapon.height;
         self.scale = weapon.scale;
-        self.buff_effect = if weapon.name == WeaponType::Spear {
-            Some(EffectType::SpeedUp)
-        } else {
-            None
-        };
-        self.debuff_effect = if weapon.name == WeaponType::BigHammer {
-            Some(EffectType::Stun)
-        } else if weapon.name == WeaponType::BigMachete {
-            Some(EffectType::ReduceDamage)
-        } else if weapon.name == WeaponType::MagicWand {
-            Some(EffectType::Slow)
-        } else {
-            None
-        };
+        self.effect = weapon.effect.clone();
         self.trigger_chance = weapon.trigger_chance.unwrap_or(0.0);
+        self.sprite = weapon.sprite.clone();
+        self.aim_rotation = weapon.aim_rotation;
+        self.shoot_while_moving = weapon.shoot_while_moving;
     }
 }
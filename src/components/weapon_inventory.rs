@@ -0,0 +1,41 @@
+use bevy::prelude::*;
+
+use crate::resources::weapon::Weapon;
+
+pub const WEAPON_INVENTORY_SLOTS: usize = 3;
+
+#[derive(Component)]
+pub struct WeaponInventoryComponent {
+    pub weapons: Vec<Weapon>,
+    pub current_index: usize,
+}
+
+impl WeaponInventoryComponent {
+    pub fn new(weapon: Weapon) -> Self {
+        WeaponInventoryComponent {
+            weapons: vec![weapon],
+            current_index: 0,
+        }
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.weapons.len() >= WEAPON_INVENTORY_SLOTS
+    }
+
+    pub fn current_weapon(&self) -> Weapon {
+        self.weapons[self.current_index].clone()
+    }
+
+    pub fn add(&mut self, weapon: Weapon) {
+        self.weapons.push(weapon);
+        self.current_index = self.weapons.len() - 1;
+    }
+
+    pub fn replace_current(&mut self, weapon: Weapon) -> Weapon {
+        std::mem::replace(&mut self.weapons[self.current_index], weapon)
+    }
+
+    pub fn next(&mut self) {
+        self.current_index = (self.current_index + 1) % self.weapons.len();
+    }
+}
@@ -0,0 +1,10 @@
+use bevy::prelude::*;
+
+use crate::resources::weapon::Weapon;
+
+#[derive(Component)]
+pub struct WeaponPickupComponent {
+    pub weapon: Weapon,
+    pub height: f32,
+    pub width: f32,
+}
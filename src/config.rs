@@ -22,5 +22,21 @@ pub const DATA_FILE: &str = "data.json";
 pub const LIST_FLOOR_FILE: &str = "list_floor.txt";
 pub const LIST_ROOM_FILE: &str = "list_room.txt";
 pub const UPGRADES_FILE: &str = "upgrades.json";
+pub const META_PROGRESSION_FILE: &str = "meta_progression.json";
+
+pub const FLOORS_DIRECTORY: &str = "./assets/floors/";
+pub const ROOMS_DIRECTORY: &str = "./assets/rooms/";
+
+// Mods config
+pub const MODS_DIRECTORY: &str = "mods";
+pub const MODS_ASSET_SOURCE: &str = "mods";
+pub const MOD_MANIFEST_FILE: &str = "mod.json";
+pub const MOD_FLOORS_DIRECTORY: &str = "floors/";
+pub const MOD_ROOMS_DIRECTORY: &str = "rooms/";
+pub const MOD_SPRITES_DIRECTORY: &str = "sprites/";
+
+// Editor config
+pub const GAME_BINARY: &str = "dungeon-quest-v2";
+pub const PLAYTEST_ARGUMENT: &str = "--playtest";
 
 pub const TILE_SIZE: f32 = 64.0;
@@ -0,0 +1,408 @@
+use bevy::prelude::*;
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use crate::config::*;
+use crate::editor::{EditorData, EditorMode};
+use crate::resources::dungeon::floor::Floor;
+use crate::resources::dungeon::room::Room;
+use crate::resources::dungeon::rooms::Rooms;
+use crate::resources::dungeon::validation::{validate_floor, validate_room, WALL_VALUES};
+use crate::resources::game_data::GameData;
+use crate::resources::mods::ModList;
+
+const NEW_ROOM_WIDTH: usize = 16;
+const NEW_ROOM_HEIGHT: usize = 9;
+const NEW_FLOOR_SIZE: usize = 4;
+const MIN_ROOM_WIDTH: usize = 5;
+const MIN_ROOM_HEIGHT: usize = 6;
+
+pub fn keyboard_system(
+    keyboard_input: Res<Input<KeyCode>>,
+    mut editor_data: ResMut<EditorData>,
+    mod_list: Res<ModList>,
+    game_data: Res<GameData>,
+) {
+    if keyboard_input.just_pressed(KeyCode::Tab) {
+        editor_data.mode = match editor_data.mode {
+            EditorMode::Room => EditorMode::Floor,
+            EditorMode::Floor => EditorMode::Room,
+        };
+        editor_data.messages.clear();
+    }
+
+    if keyboard_input.just_pressed(KeyCode::PageUp) {
+        switch_file(&mut editor_data, false);
+    } else if keyboard_input.just_pressed(KeyCode::PageDown) {
+        switch_file(&mut editor_data, true);
+    }
+
+    if keyboard_input.just_pressed(KeyCode::Q) || keyboard_input.just_pressed(KeyCode::E) {
+        let total_entries = editor_data.get_palette().len();
+        let index = editor_data.get_palette_index();
+        let index = if keyboard_input.just_pressed(KeyCode::E) {
+            (index + 1) % total_entries
+        } else {
+            (index + total_entries - 1) % total_entries
+        };
+        editor_data.set_palette_index(index);
+    }
+
+    if keyboard_input.just_pressed(KeyCode::B) {
+        editor_data.biome_index = (editor_data.biome_index + 1) % game_data.get_biomes().len();
+    }
+
+    if keyboard_input.just_pressed(KeyCode::BracketLeft) {
+        let total_room_ids = editor_data.room_ids.len();
+        editor_data.room_id_index =
+            (editor_data.room_id_index + total_room_ids - 1) % total_room_ids;
+    } else if keyboard_input.just_pressed(KeyCode::BracketRight) {
+        editor_data.room_id_index = (editor_data.room_id_index + 1) % editor_data.room_ids.len();
+    }
+
+    for key in [KeyCode::Left, KeyCode::Right, KeyCode::Up, KeyCode::Down] {
+        if keyboard_input.just_pressed(key) {
+            resize(&mut editor_data, key);
+        }
+    }
+
+    if keyboard_input.just_pressed(KeyCode::N) {
+        new_file(&mut editor_data);
+    }
+
+    if keyboard_input.just_pressed(KeyCode::R) {
+        load_current_file(&mut editor_data);
+        editor_data.messages = vec![format!("Reverted {}", editor_data.get_current_file())];
+    }
+
+    if keyboard_input.just_pressed(KeyCode::V) {
+        let errors = validate(&editor_data, &mod_list, &game_data);
+        editor_data.messages = if errors.is_empty() {
+            vec![format!("{} is valid", editor_data.get_current_file())]
+        } else {
+            errors
+        };
+    }
+
+    if keyboard_input.just_pressed(KeyCode::S) {
+        save(&mut editor_data, &mod_list, &game_data);
+    }
+
+    if keyboard_input.just_pressed(KeyCode::P) {
+        if editor_data.mode != EditorMode::Room {
+            editor_data.messages = vec!["Only rooms can be playtested".to_string()];
+        } else if save(&mut editor_data, &mod_list, &game_data) {
+            playtest(&mut editor_data, &game_data);
+        }
+    }
+}
+
+fn switch_file(editor_data: &mut EditorData, is_next: bool) {
+    if editor_data.is_dirty {
+        editor_data.messages =
+            vec!["Unsaved changes, save (S) or revert (R) before switching".to_string()];
+        return;
+    }
+
+    let (index, total_files) = match editor_data.mode {
+        EditorMode::Room => (editor_data.room_file_index, editor_data.room_files.len()),
+        EditorMode::Floor => (editor_data.floor_file_index, editor_data.floor_files.len()),
+    };
+
+    let index = if is_next {
+        (index + 1) % total_files
+    } else {
+        (index + total_files - 1) % total_files
+    };
+
+    match editor_data.mode {
+        EditorMode::Room => editor_data.room_file_index = index,
+        EditorMode::Floor => editor_data.floor_file_index = index,
+    }
+
+    load_current_file(editor_data);
+    editor_data.messages.clear();
+}
+
+fn load_current_file(editor_data: &mut EditorData) {
+    let path = editor_data.get_current_file();
+    let is_file = Path::new(&path).is_file();
+
+    match editor_data.mode {
+        EditorMode::Room => {
+            editor_data.room = if is_file {
+                Room::new(path)
+            } else {
+                Room {
+                    id: editor_data.room.id,
+                    tilemap: new_room_tilemap(NEW_ROOM_WIDTH, NEW_ROOM_HEIGHT),
+                }
+            };
+        }
+        EditorMode::Floor => {
+            editor_data.floor_map = if is_file {
+                Floor::load_map(&path)
+            } else {
+                new_floor_map(NEW_FLOOR_SIZE)
+            };
+        }
+    }
+
+    editor_data.is_dirty = false;
+}
+
+fn new_file(editor_data: &mut EditorData) {
+    if editor_data.is_dirty {
+        editor_data.messages =
+            vec!["Unsaved changes, save (S) or revert (R) before creating a file".to_string()];
+        return;
+    }
+
+    match editor_data.mode {
+        EditorMode::Room => {
+            let room_id = editor_data
+                .room_ids
+                .iter()
+                .fold(0.0, |max_id: f32, room_id| max_id.max(*room_id))
+                + 1.0;
+            let path = format!("{}{:02}.txt", ROOMS_DIRECTORY, room_id as i32);
+
+            editor_data.room_files.push(path);
+            editor_data.room_file_index = editor_data.room_files.len() - 1;
+            editor_data.room_ids.push(room_id);
+            editor_data.room = Room {
+                id: room_id,
+                tilemap: new_room_tilemap(NEW_ROOM_WIDTH, NEW_ROOM_HEIGHT),
+            };
+        }
+        EditorMode::Floor => {
+            let mut floor_number = 1;
+            let path = loop {
+                let path = format!("{}{:02}.txt", FLOORS_DIRECTORY, floor_number);
+                if !editor_data.floor_files.contains(&path) && !Path::new(&path).exists() {
+                    break path;
+                }
+                floor_number += 1;
+            };
+
+            editor_data.floor_files.push(path);
+            editor_data.floor_file_index = editor_data.floor_files.len() - 1;
+            editor_data.floor_map = new_floor_map(NEW_FLOOR_SIZE);
+        }
+    }
+
+    editor_data.is_dirty = true;
+    editor_data.messages = vec![format!("Created {}", editor_data.get_current_file())];
+}
+
+fn new_room_tilemap(width: usize, height: usize) -> Vec<Vec<i32>> {
+    let door_columns = width / 2 - 2..width / 2 + 2;
+    let door_row = height / 2;
+
+    (0..height)
+        .map(|row_index| {
+            (0..width)
+                .map(|column_index| {
+                    let is_first_column = column_index == 0;
+                    let is_last_column = column_index == width - 1;
+                    let is_door_column = door_columns.contains(&column_index);
+
+                    let value = if row_index == 0 {
+                        match (is_first_column, is_last_column) {
+                            (true, _) => 3,
+                            (_, true) => 4,
+                            _ => 2,
+                        }
+                    } else if row_index == height - 1 {
+                        match (is_first_column, is_last_column) {
+                            (true, _) => 5,
+                            (_, true) => 6,
+                            _ => 1,
+                        }
+                    } else if is_first_column {
+                        7
+                    } else if is_last_column {
+                        8
+                    } else if row_index == 1 {
+                        1
+                    } else if row_index == height - 2 {
+                        2
+                    } else {
+                        0
+                    };
+
+                    let is_door = match value {
+                        1 | 2 => is_door_column,
+                        7 | 8 => row_index == door_row,
+                        _ => false,
+                    };
+
+                    if is_door {
+                        -value
+                    } else {
+                        value
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn new_floor_map(size: usize) -> Vec<Vec<f32>> {
+    let mut map = vec![vec![0.0; size]; size];
+    map[0][0] = 1.1;
+    map[0][1] = 1.2;
+    map
+}
+
+fn copy_tile(value: i32) -> i32 {
+    if WALL_VALUES.contains(&value) {
+        value.abs()
+    } else {
+        0
+    }
+}
+
+fn resize(editor_data: &mut EditorData, key: KeyCode) {
+    let (total_rows, total_columns) = editor_data.get_grid_size();
+
+    match editor_data.mode {
+        EditorMode::Room => {
+            let tilemap = &mut editor_data.room.tilemap;
+            match key {
+                KeyCode::Right if total_columns >= 2 => {
+                    for row in tilemap.iter_mut() {
+                        let index = row.len() - 1;
+                        row.insert(index, copy_tile(row[index - 1]));
+                    }
+                }
+                KeyCode::Left if total_columns > MIN_ROOM_WIDTH => {
+                    for row in tilemap.iter_mut() {
+                        let index = row.len() - 2;
+                        row.remove(index);
+                    }
+                }
+                KeyCode::Down if total_rows >= 3 => {
+                    let row = tilemap[total_rows - 3]
+                        .iter()
+                        .map(|value| copy_tile(*value))
+                        .collect();
+                    tilemap.insert(total_rows - 2, row);
+                }
+                KeyCode::Up if total_rows > MIN_ROOM_HEIGHT => {
+                    tilemap.remove(total_rows - 3);
+                }
+                _ => return,
+            }
+        }
+        EditorMode::Floor => {
+            let map = &mut editor_data.floor_map;
+            match key {
+                KeyCode::Right => {
+                    for row in map.iter_mut() {
+                        row.push(0.0);
+                    }
+                }
+                KeyCode::Left if total_columns > 1 => {
+                    for row in map.iter_mut() {
+                        row.pop();
+                    }
+                }
+                KeyCode::Down => map.push(vec![0.0; total_columns]),
+                KeyCode::Up if total_rows > 1 => {
+                    map.pop();
+                }
+                _ => return,
+            }
+        }
+    }
+
+    editor_data.is_dirty = true;
+}
+
+fn validate(editor_data: &EditorData, mod_list: &ModList, game_data: &GameData) -> Vec<String> {
+    match editor_data.mode {
+        EditorMode::Room => validate_room(&editor_data.room, Some(game_data)),
+        EditorMode::Floor => validate_floor(
+            &editor_data.get_current_file(),
+            &editor_data.floor_map,
+            &Rooms::new(mod_list, game_data),
+        ),
+    }
+}
+
+fn save(editor_data: &mut EditorData, mod_list: &ModList, game_data: &GameData) -> bool {
+    let errors = validate(editor_data, mod_list, game_data);
+    if !errors.is_empty() {
+        editor_data.messages = errors;
+        editor_data.messages.insert(0, "Not saved:".to_string());
+        return false;
+    }
+
+    let path = editor_data.get_current_file();
+
+    match editor_data.mode {
+        EditorMode::Room => {
+            editor_data.room.save(&path);
+            register_file(LIST_ROOM_FILE, ROOMS_DIRECTORY, &path);
+        }
+        EditorMode::Floor => {
+            Floor::save_map(&editor_data.floor_map, &path);
+            register_file(LIST_FLOOR_FILE, FLOORS_DIRECTORY, &path);
+        }
+    }
+
+    editor_data.is_dirty = false;
+    editor_data.messages = vec![format!("Saved {}", path)];
+    true
+}
+
+fn register_file(list_file: &str, directory: &str, path: &str) {
+    let file_name = match path.strip_prefix(directory) {
+        Some(file_name) => file_name,
+        None => return,
+    };
+
+    let content = match fs::read_to_string(list_file) {
+        Ok(content) => content,
+        Err(err) => panic!("Can't read {}: {}", list_file, err.to_string()),
+    };
+
+    if content.lines().any(|line| line == file_name) {
+        return;
+    }
+
+    let content = if content.is_empty() {
+        file_name.to_string()
+    } else {
+        format!("{}\n{}", content.trim_end(), file_name)
+    };
+
+    match fs::write(list_file, content) {
+        Ok(_) => (),
+        Err(err) => panic!("Can't update {}: {}", list_file, err.to_string()),
+    }
+}
+
+fn playtest(editor_data: &mut EditorData, game_data: &GameData) {
+    let game_path = match env::current_exe() {
+        Ok(path) => path.with_file_name(format!("{}{}", GAME_BINARY, env::consts::EXE_SUFFIX)),
+        Err(err) => panic!("Can't find the editor executable: {}", err.to_string()),
+    };
+
+    let room_id = editor_data.room.id;
+    let biome = editor_data.get_biome(game_data).name;
+
+    let result = Command::new(&game_path)
+        .arg(PLAYTEST_ARGUMENT)
+        .arg(room_id.to_string())
+        .arg(&biome)
+        .spawn();
+
+    editor_data.messages.push(match result {
+        Ok(_) => format!("Playtesting room {} in {}", room_id, biome),
+        Err(err) => format!("Can't start {}: {}", game_path.display(), err),
+    });
+}
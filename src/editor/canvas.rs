@@ -0,0 +1,293 @@
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use crate::config::*;
+use crate::editor::palette::*;
+use crate::editor::ui::{PaletteButton, PALETTE_HEIGHT, STATUS_HEIGHT};
+use crate::editor::{EditorData, EditorMode};
+use crate::materials::ingame::InGameMaterials;
+use crate::resources::game_data::GameData;
+
+const FLOOR_CELL_GAP: f32 = 6.0;
+const FLOOR_LABEL_FONT_SIZE: f32 = 14.0;
+const CURSOR_COLOR: Color = Color::rgba(1.0, 1.0, 1.0, 0.25);
+
+#[derive(Component)]
+pub struct EditorCamera;
+
+#[derive(Component)]
+pub struct EditorCanvas;
+
+#[derive(Component)]
+pub struct EditorCursor;
+
+pub fn setup(mut commands: Commands) {
+    commands
+        .spawn(Camera2dBundle::default())
+        .insert(EditorCamera)
+        .insert(Name::new("EditorCamera"));
+
+    commands
+        .spawn(SpatialBundle::default())
+        .insert(EditorCanvas)
+        .insert(Name::new("EditorCanvas"));
+
+    commands
+        .spawn(SpriteBundle {
+            sprite: Sprite {
+                custom_size: Some(Vec2::new(TILE_SIZE, TILE_SIZE)),
+                color: CURSOR_COLOR,
+                ..Default::default()
+            },
+            visibility: Visibility::Hidden,
+            ..Default::default()
+        })
+        .insert(EditorCursor)
+        .insert(Name::new("EditorCursor"));
+}
+
+pub fn get_cell_translation(
+    total_rows: usize,
+    total_columns: usize,
+    row_index: usize,
+    column_index: usize,
+) -> Vec2 {
+    let start_x = -(total_columns as f32 - 1.0) * TILE_SIZE / 2.0;
+    let start_y = (total_rows as f32 - 1.0) * TILE_SIZE / 2.0;
+
+    Vec2::new(
+        start_x + column_index as f32 * TILE_SIZE,
+        start_y - row_index as f32 * TILE_SIZE,
+    )
+}
+
+fn get_cell(
+    total_rows: usize,
+    total_columns: usize,
+    world_position: Vec2,
+) -> Option<(usize, usize)> {
+    let start_x = -(total_columns as f32 - 1.0) * TILE_SIZE / 2.0;
+    let start_y = (total_rows as f32 - 1.0) * TILE_SIZE / 2.0;
+
+    let column_index = ((world_position.x - start_x) / TILE_SIZE).round();
+    let row_index = ((start_y - world_position.y) / TILE_SIZE).round();
+
+    if row_index < 0.0
+        || column_index < 0.0
+        || row_index >= total_rows as f32
+        || column_index >= total_columns as f32
+    {
+        None
+    } else {
+        Some((row_index as usize, column_index as usize))
+    }
+}
+
+pub fn paint_system(
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<EditorCamera>>,
+    mut cursor_query: Query<(&mut Transform, &mut Visibility), With<EditorCursor>>,
+    button_query: Query<&Interaction, With<PaletteButton>>,
+    mouse_button_input: Res<Input<MouseButton>>,
+    mut editor_data: ResMut<EditorData>,
+) {
+    let window = window_query.single();
+    let (camera, camera_transform) = camera_query.single();
+    let (mut cursor_transform, mut cursor_visibility) = cursor_query.single_mut();
+
+    let (total_rows, total_columns) = editor_data.get_grid_size();
+    let is_over_palette = button_query
+        .iter()
+        .any(|interaction| *interaction != Interaction::None);
+
+    let cell = match window.cursor_position() {
+        Some(cursor_position) if !is_over_palette => {
+            match camera.viewport_to_world_2d(camera_transform, cursor_position) {
+                Some(world_position) => get_cell(total_rows, total_columns, world_position),
+                None => None,
+            }
+        }
+        _ => None,
+    };
+
+    let (row_index, column_index) = match cell {
+        Some(cell) => cell,
+        None => {
+            *cursor_visibility = Visibility::Hidden;
+            return;
+        }
+    };
+
+    *cursor_visibility = Visibility::Inherited;
+    cursor_transform.translation =
+        get_cell_translation(total_rows, total_columns, row_index, column_index).extend(1.0);
+
+    let brush = if mouse_button_input.pressed(MouseButton::Left) {
+        editor_data.get_brush().value
+    } else if mouse_button_input.pressed(MouseButton::Right) {
+        0.0
+    } else {
+        return;
+    };
+
+    match editor_data.mode {
+        EditorMode::Room => {
+            let value = brush as i32;
+            match editor_data.room.tilemap[row_index].get(column_index) {
+                Some(tile) if *tile != value => {
+                    editor_data.room.tilemap[row_index][column_index] = value;
+                    editor_data.is_dirty = true;
+                }
+                _ => (),
+            }
+        }
+        EditorMode::Floor => {
+            let value = get_floor_value(brush, editor_data.get_room_id());
+            match editor_data.floor_map[row_index].get(column_index) {
+                Some(room_value) if *room_value != value => {
+                    editor_data.floor_map[row_index][column_index] = value;
+                    editor_data.is_dirty = true;
+                }
+                _ => (),
+            }
+        }
+    }
+}
+
+pub fn canvas_handle_system(
+    mut commands: Commands,
+    canvas_query: Query<Entity, With<EditorCanvas>>,
+    mut cursor_query: Query<&mut Sprite, With<EditorCursor>>,
+    editor_data: Res<EditorData>,
+    ingame_materials: Res<InGameMaterials>,
+    game_data: Res<GameData>,
+    asset_server: Res<AssetServer>,
+) {
+    if !editor_data.is_changed() {
+        return;
+    }
+
+    let biome = editor_data.get_biome(&game_data);
+    let font = asset_server.load(ROBOTO_FONT);
+
+    for mut cursor_sprite in cursor_query.iter_mut() {
+        let cursor_size = match editor_data.mode {
+            EditorMode::Room => TILE_SIZE,
+            EditorMode::Floor => TILE_SIZE - FLOOR_CELL_GAP,
+        };
+        cursor_sprite.custom_size = Some(Vec2::new(cursor_size, cursor_size));
+    }
+
+    for canvas_entity in canvas_query.iter() {
+        commands
+            .entity(canvas_entity)
+            .despawn_descendants()
+            .with_children(|parent| match editor_data.mode {
+                EditorMode::Room => {
+                    let room = &editor_data.room;
+                    let (floor_texture, floor_color, _) =
+                        get_tile_appearance(0, &ingame_materials, &game_data, &biome);
+
+                    for (row_index, row) in room.tilemap.iter().enumerate() {
+                        for (column_index, value) in row.iter().enumerate() {
+                            let translation =
+                                room.get_tile_translation(row_index as f32, column_index as f32);
+
+                            parent.spawn(SpriteBundle {
+                                sprite: Sprite {
+                                    custom_size: Some(Vec2::new(TILE_SIZE, TILE_SIZE)),
+                                    color: floor_color,
+                                    ..Default::default()
+                                },
+                                transform: Transform::from_translation(translation.extend(0.0)),
+                                texture: floor_texture.clone().unwrap_or_default(),
+                                ..Default::default()
+                            });
+
+                            if *value == 0 {
+                                continue;
+                            }
+
+                            let (texture, color, size) =
+                                get_tile_appearance(*value, &ingame_materials, &game_data, &biome);
+
+                            parent.spawn(SpriteBundle {
+                                sprite: Sprite {
+                                    custom_size: Some(size),
+                                    color,
+                                    ..Default::default()
+                                },
+                                transform: Transform::from_translation(translation.extend(0.1)),
+                                texture: texture.unwrap_or_default(),
+                                ..Default::default()
+                            });
+                        }
+                    }
+                }
+                EditorMode::Floor => {
+                    let (total_rows, total_columns) = editor_data.get_grid_size();
+
+                    for (row_index, row) in editor_data.floor_map.iter().enumerate() {
+                        for (column_index, value) in row.iter().enumerate() {
+                            let translation = get_cell_translation(
+                                total_rows,
+                                total_columns,
+                                row_index,
+                                column_index,
+                            );
+
+                            parent.spawn(SpriteBundle {
+                                sprite: Sprite {
+                                    custom_size: Some(Vec2::new(
+                                        TILE_SIZE - FLOOR_CELL_GAP,
+                                        TILE_SIZE - FLOOR_CELL_GAP,
+                                    )),
+                                    color: get_floor_cell_color(*value),
+                                    ..Default::default()
+                                },
+                                transform: Transform::from_translation(translation.extend(0.0)),
+                                ..Default::default()
+                            });
+
+                            parent.spawn(Text2dBundle {
+                                text: Text::from_section(
+                                    get_floor_cell_label(*value),
+                                    TextStyle {
+                                        font: font.clone(),
+                                        font_size: FLOOR_LABEL_FONT_SIZE,
+                                        color: Color::WHITE,
+                                    },
+                                )
+                                .with_alignment(TextAlignment::Center),
+                                transform: Transform::from_translation(translation.extend(0.1)),
+                                ..Default::default()
+                            });
+                        }
+                    }
+                }
+            });
+    }
+}
+
+pub fn camera_handle_system(
+    mut camera_query: Query<(&mut OrthographicProjection, &mut Transform), With<EditorCamera>>,
+    editor_data: Res<EditorData>,
+) {
+    if !editor_data.is_changed() {
+        return;
+    }
+
+    let (total_rows, total_columns) = editor_data.get_grid_size();
+    let available_width = WINDOW_HEIGHT * RESOLUTION - TILE_SIZE;
+    let available_height = WINDOW_HEIGHT - STATUS_HEIGHT - PALETTE_HEIGHT;
+
+    let scale = (total_columns as f32 * TILE_SIZE / available_width)
+        .max(total_rows as f32 * TILE_SIZE / available_height)
+        .max(1.0);
+
+    for (mut projection, mut transform) in camera_query.iter_mut() {
+        projection.scale = scale;
+        transform.translation.x = 0.0;
+        transform.translation.y = (STATUS_HEIGHT - PALETTE_HEIGHT) / 2.0 * scale;
+    }
+}
@@ -0,0 +1,177 @@
+use bevy::prelude::*;
+use std::path::Path;
+
+pub mod actions;
+pub mod canvas;
+pub mod palette;
+pub mod ui;
+
+use crate::materials::ingame::InGameMaterials;
+use crate::resources::biome::Biome;
+use crate::resources::dungeon::floor::Floor;
+use crate::resources::dungeon::room::Room;
+use crate::resources::game_data::GameData;
+use crate::resources::mods::ModList;
+use crate::resources::tiled::is_tiled_file;
+use palette::PaletteEntry;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum EditorMode {
+    Room,
+    Floor,
+}
+
+#[derive(Resource)]
+pub struct EditorData {
+    pub mode: EditorMode,
+    pub room_files: Vec<String>,
+    pub floor_files: Vec<String>,
+    pub room_file_index: usize,
+    pub floor_file_index: usize,
+    pub room: Room,
+    pub floor_map: Vec<Vec<f32>>,
+    pub room_palette: Vec<PaletteEntry>,
+    pub floor_palette: Vec<PaletteEntry>,
+    pub room_palette_index: usize,
+    pub floor_palette_index: usize,
+    pub room_ids: Vec<f32>,
+    pub room_id_index: usize,
+    pub biome_index: usize,
+    pub messages: Vec<String>,
+    pub is_dirty: bool,
+}
+
+impl EditorData {
+    pub fn new(mod_list: &ModList, game_data: &GameData) -> Self {
+        let mut room_files = mod_list.get_room_files();
+        let mut floor_files = mod_list.get_floor_files();
+
+        let mut room_ids: Vec<f32> = room_files.iter().map(|path| get_file_id(path)).collect();
+        room_ids.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        room_ids.dedup();
+        room_ids.insert(0, 0.0);
+
+        // Tiled maps are edited in Tiled, only their room ids can be painted
+        room_files.retain(|path| !is_tiled_file(path));
+        floor_files.retain(|path| !is_tiled_file(path));
+
+        EditorData {
+            mode: EditorMode::Room,
+            room: Room::new(room_files[0].clone()),
+            floor_map: Floor::load_map(&floor_files[0]),
+            room_files,
+            floor_files,
+            room_file_index: 0,
+            floor_file_index: 0,
+            room_palette: palette::room_palette(game_data),
+            floor_palette: palette::floor_palette(),
+            room_palette_index: 1,
+            floor_palette_index: 1,
+            room_ids,
+            room_id_index: 0,
+            biome_index: 0,
+            messages: Vec::new(),
+            is_dirty: false,
+        }
+    }
+
+    pub fn get_palette(&self) -> &Vec<PaletteEntry> {
+        match self.mode {
+            EditorMode::Room => &self.room_palette,
+            EditorMode::Floor => &self.floor_palette,
+        }
+    }
+
+    pub fn get_palette_index(&self) -> usize {
+        match self.mode {
+            EditorMode::Room => self.room_palette_index,
+            EditorMode::Floor => self.floor_palette_index,
+        }
+    }
+
+    pub fn set_palette_index(&mut self, index: usize) {
+        match self.mode {
+            EditorMode::Room => self.room_palette_index = index,
+            EditorMode::Floor => self.floor_palette_index = index,
+        }
+    }
+
+    pub fn get_brush(&self) -> PaletteEntry {
+        self.get_palette()[self.get_palette_index()].clone()
+    }
+
+    pub fn get_room_id(&self) -> f32 {
+        self.room_ids[self.room_id_index]
+    }
+
+    pub fn get_biome(&self, game_data: &GameData) -> Biome {
+        let biomes = game_data.get_biomes();
+        biomes[self.biome_index % biomes.len()].clone()
+    }
+
+    pub fn get_current_file(&self) -> String {
+        match self.mode {
+            EditorMode::Room => self.room_files[self.room_file_index].clone(),
+            EditorMode::Floor => self.floor_files[self.floor_file_index].clone(),
+        }
+    }
+
+    pub fn get_grid_size(&self) -> (usize, usize) {
+        match self.mode {
+            EditorMode::Room => (self.room.get_height(), self.room.get_width()),
+            EditorMode::Floor => (
+                self.floor_map.len(),
+                self.floor_map
+                    .iter()
+                    .map(|row| row.len())
+                    .max()
+                    .unwrap_or(0),
+            ),
+        }
+    }
+}
+
+pub fn get_file_id(path: &str) -> f32 {
+    let file_stem = Path::new(path).file_stem().unwrap().to_string_lossy();
+    match file_stem.parse::<f32>() {
+        Ok(id) => id,
+        Err(_) => panic!("Invalid file name {}, expected a number", path),
+    }
+}
+
+pub struct EditorPlugin;
+
+impl Plugin for EditorPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Startup,
+            (load_editor, apply_deferred, canvas::setup, ui::setup).chain(),
+        );
+        app.add_systems(
+            Update,
+            (
+                (
+                    actions::keyboard_system,
+                    ui::palette_button_system,
+                    canvas::paint_system,
+                )
+                    .chain(),
+                (
+                    canvas::canvas_handle_system,
+                    canvas::camera_handle_system,
+                    ui::palette_handle_system,
+                    ui::status_handle_system,
+                ),
+            )
+                .chain(),
+        );
+    }
+}
+
+fn load_editor(mut commands: Commands, asset_server: Res<AssetServer>, mod_list: Res<ModList>) {
+    let game_data = GameData::new(&mod_list);
+
+    commands.insert_resource(InGameMaterials::new(&asset_server, &game_data, &mod_list));
+    commands.insert_resource(EditorData::new(&mod_list, &game_data));
+    commands.insert_resource(game_data);
+}
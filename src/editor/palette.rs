@@ -0,0 +1,192 @@
+use bevy::prelude::*;
+
+use crate::config::TILE_SIZE;
+use crate::materials::ingame::InGameMaterials;
+use crate::plugins::classic_mode::dungeon::ground::get_biome_color;
+use crate::plugins::classic_mode::dungeon::hazards::*;
+use crate::plugins::classic_mode::dungeon::walls::get_wall_image;
+use crate::resources::biome::Biome;
+use crate::resources::dungeon::hazard_type::HazardType;
+use crate::resources::dungeon::room_access::RoomAccess;
+use crate::resources::dungeon::room_type::RoomType;
+use crate::resources::dungeon::validation::WALL_VALUES;
+use crate::resources::game_data::GameData;
+
+const DOOR_COLOR: Color = Color::rgba(1.0, 0.55, 0.55, 0.75);
+const UNKNOWN_TILE_COLOR: Color = Color::FUCHSIA;
+
+#[derive(Clone, Debug)]
+pub struct PaletteEntry {
+    pub name: String,
+    pub value: f32,
+}
+
+impl PaletteEntry {
+    pub fn new(name: &str, value: f32) -> Self {
+        PaletteEntry {
+            name: name.to_string(),
+            value,
+        }
+    }
+}
+
+pub fn room_palette(game_data: &GameData) -> Vec<PaletteEntry> {
+    let mut palette = vec![
+        PaletteEntry::new("Floor", 0.0),
+        PaletteEntry::new("Wall", 1.0),
+        PaletteEntry::new("Wall door", -1.0),
+        PaletteEntry::new("Border", 2.0),
+        PaletteEntry::new("Border door", -2.0),
+        PaletteEntry::new("Corner top left", 3.0),
+        PaletteEntry::new("Corner top right", 4.0),
+        PaletteEntry::new("Wall bottom left", 5.0),
+        PaletteEntry::new("Wall bottom right", 6.0),
+        PaletteEntry::new("Border left", 7.0),
+        PaletteEntry::new("Left door", -7.0),
+        PaletteEntry::new("Border right", 8.0),
+        PaletteEntry::new("Right door", -8.0),
+    ];
+
+    for value in 10..=13 {
+        if let Some(hazard_type) = HazardType::from_value(value) {
+            palette.push(PaletteEntry::new(
+                &format!("{:?}", hazard_type),
+                value as f32,
+            ));
+        }
+    }
+
+    for prop in game_data.get_props() {
+        palette.push(PaletteEntry::new(&prop.name, prop.tile as f32));
+    }
+
+    palette
+}
+
+pub fn floor_palette() -> Vec<PaletteEntry> {
+    vec![
+        PaletteEntry::new("Empty", 0.0),
+        PaletteEntry::new("Normal", 1.0),
+        PaletteEntry::new("Treasure", 2.0),
+        PaletteEntry::new("Shrine", 3.0),
+        PaletteEntry::new("Challenge", 4.0),
+        PaletteEntry::new("Locked", 11.0),
+        PaletteEntry::new("Secret", 21.0),
+        PaletteEntry::new("Start", 1.1),
+        PaletteEntry::new("End", 1.2),
+        PaletteEntry::new("Shop", 1.3),
+    ]
+}
+
+pub fn get_tile_appearance(
+    value: i32,
+    ingame_materials: &InGameMaterials,
+    game_data: &GameData,
+    biome: &Biome,
+) -> (Option<Handle<Image>>, Color, Vec2) {
+    let tile_size = Vec2::new(TILE_SIZE, TILE_SIZE);
+
+    if value == 0 {
+        (
+            Some(
+                ingame_materials
+                    .biomes_materials
+                    .get_texture(&biome.floor_sprite),
+            ),
+            get_biome_color(biome.tint),
+            tile_size,
+        )
+    } else if WALL_VALUES.contains(&value) {
+        let color = if value < 0 {
+            DOOR_COLOR
+        } else {
+            get_biome_color(biome.tint)
+        };
+        (
+            Some(get_wall_image(value, ingame_materials, biome)),
+            color,
+            tile_size,
+        )
+    } else if let Some(hazard_type) = HazardType::from_value(value) {
+        let (size, color) = match hazard_type {
+            HazardType::SpikeTrap => (TILE_SIZE * 0.8, SPIKE_TRAP_ACTIVE_COLOR),
+            HazardType::ArrowTurret => (TILE_SIZE * 0.6, ARROW_TURRET_COLOR),
+            HazardType::Mud => (TILE_SIZE, MUD_COLOR),
+            HazardType::Poison => (TILE_SIZE, POISON_COLOR),
+        };
+        (None, color, Vec2::new(size, size))
+    } else if let Some(prop) = game_data.get_prop_by_tile(value) {
+        let color = match prop.color {
+            Some([red, green, blue]) => Color::rgb(red, green, blue),
+            None => Color::WHITE,
+        };
+        (
+            Some(ingame_materials.props_materials.get_texture(&prop.sprite)),
+            color,
+            Vec2::new(prop.width, prop.height),
+        )
+    } else {
+        (None, UNKNOWN_TILE_COLOR, tile_size)
+    }
+}
+
+pub fn get_floor_value(base: f32, room_id: f32) -> f32 {
+    if base == 0.0 {
+        0.0
+    } else {
+        base + room_id * 100.0
+    }
+}
+
+pub fn get_floor_cell_color(value: f32) -> Color {
+    if value == 0.0 {
+        return Color::rgba(0.15, 0.15, 0.15, 0.6);
+    }
+
+    let marker = ((value - value.floor()) * 10.0).round() as i32;
+    let room_type = value.floor() as i32 % 10;
+
+    let color = match (marker, room_type) {
+        (1, _) => Color::rgb(0.3, 0.75, 0.3),
+        (2, _) => Color::rgb(0.3, 0.45, 0.9),
+        (3, _) => Color::rgb(0.9, 0.8, 0.25),
+        (_, 1) => Color::rgb(0.55, 0.55, 0.55),
+        (_, 2) => Color::rgb(0.85, 0.6, 0.2),
+        (_, 3) => Color::rgb(0.6, 0.35, 0.8),
+        (_, 4) => Color::rgb(0.8, 0.25, 0.25),
+        _ => return UNKNOWN_TILE_COLOR,
+    };
+
+    match value.floor() as i32 / 10 % 10 {
+        1 => color * 0.6,
+        2 => color.with_a(0.45),
+        _ => color,
+    }
+}
+
+pub fn get_floor_cell_label(value: f32) -> String {
+    if value == 0.0 {
+        return String::new();
+    }
+
+    let room_type = match value.floor() as i32 % 10 {
+        1 => Some(RoomType::Normal),
+        2 => Some(RoomType::Treasure),
+        3 => Some(RoomType::Shrine),
+        4 => Some(RoomType::Challenge),
+        _ => None,
+    };
+    let room_access = match value.floor() as i32 / 10 % 10 {
+        0 => Some(RoomAccess::Open),
+        1 => Some(RoomAccess::Locked),
+        2 => Some(RoomAccess::Secret),
+        _ => None,
+    };
+
+    match (room_type, room_access) {
+        (Some(RoomType::Normal), Some(RoomAccess::Open)) => format!("{:.1}", value),
+        (Some(room_type), Some(RoomAccess::Open)) => format!("{:.1}\n{:?}", value, room_type),
+        (Some(_), Some(room_access)) => format!("{:.1}\n{:?}", value, room_access),
+        _ => format!("{:.1}\n?", value),
+    }
+}
@@ -0,0 +1,255 @@
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+use crate::config::*;
+use crate::editor::palette::*;
+use crate::editor::{EditorData, EditorMode};
+use crate::materials::ingame::InGameMaterials;
+use crate::resources::game_data::GameData;
+
+pub const STATUS_HEIGHT: f32 = 130.0;
+pub const PALETTE_HEIGHT: f32 = 100.0;
+
+const STATUS_FONT_SIZE: f32 = 15.0;
+const PALETTE_FONT_SIZE: f32 = 11.0;
+const PALETTE_BUTTON_SIZE: f32 = 40.0;
+const MAX_MESSAGES: usize = 3;
+
+const PANEL_COLOR: Color = Color::rgba(0.0, 0.0, 0.0, 0.8);
+const SELECTED_COLOR: Color = Color::YELLOW;
+
+const HELP_TEXT: &str = "Tab mode | PgUp/PgDn file | Q/E brush | Arrows resize | B biome | [ ] room id | N new | V validate | S save | R revert | P playtest";
+
+#[derive(Component)]
+pub struct StatusText;
+
+#[derive(Component)]
+pub struct PaletteRoot;
+
+#[derive(Component)]
+pub struct PaletteButton(pub usize);
+
+#[derive(SystemParam)]
+pub struct PaletteAssets<'w> {
+    ingame_materials: Res<'w, InGameMaterials>,
+    game_data: Res<'w, GameData>,
+    asset_server: Res<'w, AssetServer>,
+}
+
+pub fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(0.0),
+                left: Val::Px(0.0),
+                width: Val::Percent(100.0),
+                height: Val::Px(STATUS_HEIGHT),
+                padding: UiRect::all(Val::Px(8.0)),
+                ..Default::default()
+            },
+            background_color: BackgroundColor(PANEL_COLOR),
+            ..Default::default()
+        })
+        .with_children(|parent| {
+            parent
+                .spawn(TextBundle {
+                    text: Text::from_section(
+                        "",
+                        TextStyle {
+                            font: asset_server.load(ROBOTO_FONT),
+                            font_size: STATUS_FONT_SIZE,
+                            color: Color::WHITE,
+                        },
+                    ),
+                    ..Default::default()
+                })
+                .insert(StatusText);
+        })
+        .insert(Name::new("StatusBar"));
+
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(0.0),
+                left: Val::Px(0.0),
+                width: Val::Percent(100.0),
+                height: Val::Px(PALETTE_HEIGHT),
+                padding: UiRect::all(Val::Px(4.0)),
+                flex_wrap: FlexWrap::Wrap,
+                align_content: AlignContent::FlexStart,
+                ..Default::default()
+            },
+            background_color: BackgroundColor(PANEL_COLOR),
+            ..Default::default()
+        })
+        .insert(PaletteRoot)
+        .insert(Name::new("Palette"));
+}
+
+pub fn palette_button_system(
+    button_query: Query<(&Interaction, &PaletteButton), Changed<Interaction>>,
+    mut editor_data: ResMut<EditorData>,
+) {
+    for (interaction, button) in button_query.iter() {
+        if *interaction == Interaction::Pressed && editor_data.get_palette_index() != button.0 {
+            editor_data.set_palette_index(button.0);
+        }
+    }
+}
+
+pub fn palette_handle_system(
+    mut commands: Commands,
+    palette_query: Query<Entity, With<PaletteRoot>>,
+    mut button_query: Query<(&PaletteButton, &mut BorderColor)>,
+    editor_data: Res<EditorData>,
+    palette_assets: PaletteAssets,
+    mut palette_key: Local<Option<(EditorMode, usize)>>,
+) {
+    if !editor_data.is_changed() {
+        return;
+    }
+    let PaletteAssets {
+        ingame_materials,
+        game_data,
+        asset_server,
+    } = palette_assets;
+
+    let selected_index = editor_data.get_palette_index();
+    let key = (editor_data.mode, editor_data.biome_index);
+
+    if *palette_key == Some(key) {
+        for (button, mut border_color) in button_query.iter_mut() {
+            *border_color = get_border_color(button.0 == selected_index);
+        }
+        return;
+    }
+    *palette_key = Some(key);
+
+    let biome = editor_data.get_biome(&game_data);
+    let font = asset_server.load(ROBOTO_FONT);
+
+    for palette_entity in palette_query.iter() {
+        commands
+            .entity(palette_entity)
+            .despawn_descendants()
+            .with_children(|parent| {
+                for (index, entry) in editor_data.get_palette().iter().enumerate() {
+                    let (texture, color, label) = match editor_data.mode {
+                        EditorMode::Room => {
+                            let (texture, color, _) = get_tile_appearance(
+                                entry.value as i32,
+                                &ingame_materials,
+                                &game_data,
+                                &biome,
+                            );
+                            (texture, color, format!("{}", entry.value as i32))
+                        }
+                        EditorMode::Floor => (
+                            None,
+                            get_floor_cell_color(entry.value),
+                            format!("{:.1}", entry.value),
+                        ),
+                    };
+
+                    parent
+                        .spawn(ButtonBundle {
+                            style: Style {
+                                width: Val::Px(PALETTE_BUTTON_SIZE),
+                                height: Val::Px(PALETTE_BUTTON_SIZE),
+                                margin: UiRect::all(Val::Px(2.0)),
+                                border: UiRect::all(Val::Px(2.0)),
+                                align_items: AlignItems::FlexEnd,
+                                justify_content: JustifyContent::FlexEnd,
+                                ..Default::default()
+                            },
+                            background_color: BackgroundColor(color),
+                            border_color: get_border_color(index == selected_index),
+                            image: match texture {
+                                Some(texture) => UiImage::new(texture),
+                                None => UiImage::default(),
+                            },
+                            ..Default::default()
+                        })
+                        .with_children(|parent| {
+                            parent.spawn(TextBundle::from_section(
+                                label,
+                                TextStyle {
+                                    font: font.clone(),
+                                    font_size: PALETTE_FONT_SIZE,
+                                    color: Color::WHITE,
+                                },
+                            ));
+                        })
+                        .insert(PaletteButton(index))
+                        .insert(Name::new(entry.name.clone()));
+                }
+            });
+    }
+}
+
+fn get_border_color(is_selected: bool) -> BorderColor {
+    if is_selected {
+        BorderColor(SELECTED_COLOR)
+    } else {
+        BorderColor(Color::NONE)
+    }
+}
+
+pub fn status_handle_system(
+    mut text_query: Query<&mut Text, With<StatusText>>,
+    editor_data: Res<EditorData>,
+    game_data: Res<GameData>,
+) {
+    if !editor_data.is_changed() {
+        return;
+    }
+
+    let (total_rows, total_columns) = editor_data.get_grid_size();
+    let brush = editor_data.get_brush();
+
+    let file = format!(
+        "{:?}: {}{} ({}x{})",
+        editor_data.mode,
+        editor_data.get_current_file(),
+        if editor_data.is_dirty { " *" } else { "" },
+        total_columns,
+        total_rows
+    );
+
+    let brush = match editor_data.mode {
+        EditorMode::Room => format!(
+            "Brush: {} ({}) | Biome: {}",
+            brush.name,
+            brush.value as i32,
+            editor_data.get_biome(&game_data).name
+        ),
+        EditorMode::Floor => {
+            let room_id = if editor_data.get_room_id() == 0.0 {
+                "default".to_string()
+            } else {
+                editor_data.get_room_id().to_string()
+            };
+            format!(
+                "Brush: {} ({:.1}) | Room id: {}",
+                brush.name,
+                get_floor_value(brush.value, editor_data.get_room_id()),
+                room_id
+            )
+        }
+    };
+
+    let mut lines = vec![file, brush, HELP_TEXT.to_string()];
+    lines.extend(editor_data.messages.iter().take(MAX_MESSAGES).cloned());
+    if editor_data.messages.len() > MAX_MESSAGES {
+        lines.push(format!(
+            "... and {} more",
+            editor_data.messages.len() - MAX_MESSAGES
+        ));
+    }
+
+    for mut text in text_query.iter_mut() {
+        text.sections[0].value = lines.join("\n");
+    }
+}
@@ -0,0 +1,8 @@
+pub mod components;
+pub mod config;
+pub mod editor;
+pub mod materials;
+pub mod plugins;
+pub mod resources;
+pub mod scenes;
+pub mod validator;
@@ -1,18 +1,19 @@
+use bevy::asset::io::AssetSource;
+use bevy::asset::AssetApp;
 use bevy::{prelude::*, window::WindowMode};
 use bevy::window::{WindowResolution, WindowResizeConstraints};
 use bevy_kira_audio::{AudioPlugin};
 
-use config::*;
-
-mod components;
-mod config;
-mod materials;
-mod plugins;
-mod resources;
-mod scenes;
+use dungeon_quest_v2::config::*;
+use dungeon_quest_v2::{plugins, resources, scenes};
 
 fn main() {
     App::new()
+        .register_asset_source(
+            MODS_ASSET_SOURCE,
+            AssetSource::build()
+                .with_reader(AssetSource::get_default_reader(MODS_DIRECTORY.to_string())),
+        )
         .add_plugins(DefaultPlugins.set(WindowPlugin {
             primary_window: Some(Window {
                 resolution: WindowResolution::new(WINDOW_HEIGHT * RESOLUTION, WINDOW_HEIGHT),
@@ -33,7 +34,10 @@ fn main() {
             ImagePlugin::default_nearest()
         ))
         .init_resource::<resources::setting::Setting>()
+        .init_resource::<resources::mods::ModList>()
         .init_resource::<resources::dictionary::Dictionary>()
+        .init_resource::<resources::meta_progression::MetaProgression>()
+        .insert_resource(resources::playtest::Playtest::from_args())
         .add_state::<scenes::SceneState>()
 
         .add_plugins(AudioPlugin)
@@ -42,6 +46,7 @@ fn main() {
         .add_plugins(plugins::camera::CameraPlugin)
         .add_plugins(scenes::loading_scene::LoadingScenePlugin)
         .add_plugins(scenes::main_menu_scene::MainMenuScenePlugin)
+        .add_plugins(scenes::meta_progression_scene::MetaProgressionScenePlugin)
         .add_plugins(scenes::highscore_scene::HighscoreScenePlugin)
         .add_plugins(scenes::options_scene::OptionsScenePlugin)
         .add_plugins(scenes::help_scene::HelpScenePlugin)
@@ -49,6 +54,7 @@ fn main() {
         .add_plugins(scenes::game_mode_select_scene::GameModeSelectScenePlugin)
         .add_plugins(scenes::hero_select_scene::HeroSelectScenePlugin)
         .add_plugins(scenes::result_scene::ResultScenePlugin)
+        .add_plugins(scenes::reward_scene::RewardScenePlugin)
         .add_plugins(plugins::input::InputHandlePlugin)
         .add_plugins(plugins::player::PlayerPlugin)
         .add_plugins(plugins::weapon::WeaponPlugin)
@@ -0,0 +1,16 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+#[derive(Clone)]
+pub struct BiomesMaterials {
+    pub textures: HashMap<String, Handle<Image>>,
+}
+
+impl BiomesMaterials {
+    pub fn get_texture(&self, path: &str) -> Handle<Image> {
+        match self.textures.get(path) {
+            Some(texture) => texture.clone(),
+            None => panic!("Biome texture {} was not loaded", path),
+        }
+    }
+}
@@ -1,7 +1,16 @@
 use bevy::prelude::*;
+use std::collections::HashMap;
 
 #[derive(Clone)]
 pub struct BulletsMaterials {
-    pub arrow: Handle<Image>,
-    pub bullet: Handle<Image>,
+    pub textures: HashMap<String, Handle<Image>>,
+}
+
+impl BulletsMaterials {
+    pub fn get_texture(&self, path: &str) -> Handle<Image> {
+        match self.textures.get(path) {
+            Some(texture) => texture.clone(),
+            None => panic!("Bullet texture {} was not loaded", path),
+        }
+    }
 }
@@ -6,6 +6,7 @@ pub struct DungeonMaterials {
     pub ladder: Handle<Image>,
     pub wall: Handle<Image>,
     pub treasure: Handle<Image>,
+    pub arrow: Handle<Image>,
     pub wall_border_mid: Handle<Image>,
     pub wall_border_corner_bottom_left: Handle<Image>,
     pub wall_border_corner_bottom_right: Handle<Image>,
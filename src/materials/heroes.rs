@@ -1,39 +1,16 @@
 use bevy::prelude::*;
-
-use crate::resources::hero::gender::Gender;
-use crate::resources::hero::hero_class::HeroClass;
+use std::collections::HashMap;
 
 #[derive(Clone)]
 pub struct HeroesMaterials {
-    pub male_elf: Handle<Image>,
-    pub male_knight: Handle<Image>,
-    pub male_wizard: Handle<Image>,
-    pub male_lizard: Handle<Image>,
-    pub female_elf: Handle<Image>,
-    pub female_knight: Handle<Image>,
-    pub female_wizard: Handle<Image>,
-    pub female_lizard: Handle<Image>,
+    pub textures: HashMap<String, Handle<Image>>,
 }
 
 impl HeroesMaterials {
-    pub fn get_texture(&self, hero_class: HeroClass, gender: Gender) -> Handle<Image> {
-        return match hero_class {
-            HeroClass::Elf => match gender {
-                Gender::Male => self.male_elf.clone(),
-                Gender::Female => self.female_elf.clone(),
-            },
-            HeroClass::Knight => match gender {
-                Gender::Male => self.male_knight.clone(),
-                Gender::Female => self.female_knight.clone(),
-            },
-            HeroClass::Lizard => match gender {
-                Gender::Male => self.male_lizard.clone(),
-                Gender::Female => self.female_lizard.clone(),
-            },
-            HeroClass::Wizard => match gender {
-                Gender::Male => self.male_wizard.clone(),
-                Gender::Female => self.female_wizard.clone(),
-            },
-        };
+    pub fn get_texture(&self, path: &str) -> Handle<Image> {
+        match self.textures.get(path) {
+            Some(texture) => texture.clone(),
+            None => panic!("Hero texture {} was not loaded", path),
+        }
     }
 }
@@ -1,19 +1,176 @@
 use bevy::prelude::*;
+use crate::materials::biomes::BiomesMaterials;
 use crate::materials::bullets::BulletsMaterials;
 use crate::materials::dungeon::DungeonMaterials;
 use crate::materials::hearts::HeartsMaterials;
 use crate::materials::heroes::HeroesMaterials;
 use crate::materials::monsters::MonstersMaterials;
 use crate::materials::potions::PotionsMaterials;
+use crate::materials::props::PropsMaterials;
+use crate::materials::relics::RelicsMaterials;
+use crate::materials::shop::ShopMaterials;
 use crate::materials::weapons::WeaponsMaterials;
+use crate::resources::game_data::GameData;
+use crate::resources::mods::ModList;
 
 #[derive(Resource)]
 pub struct InGameMaterials {
     pub heroes_materials: HeroesMaterials,
     pub weapons_materials: WeaponsMaterials,
     pub dungeon_materials: DungeonMaterials,
+    pub biomes_materials: BiomesMaterials,
     pub hearts_materials: HeartsMaterials,
     pub bullet_materials: BulletsMaterials,
     pub monsters_materials: MonstersMaterials,
     pub potions_materials: PotionsMaterials,
+    pub props_materials: PropsMaterials,
+    pub relics_materials: RelicsMaterials,
+    pub shop_materials: ShopMaterials,
+}
+
+impl InGameMaterials {
+    pub fn new(asset_server: &AssetServer, game_data: &GameData, mod_list: &ModList) -> Self {
+        InGameMaterials {
+            heroes_materials: HeroesMaterials {
+                textures: game_data
+                    .get_heroes()
+                    .iter()
+                    .flat_map(|hero| hero.variants.iter())
+                    .map(|variant| {
+                        (
+                            variant.sprite.clone(),
+                            asset_server.load(mod_list.resolve_sprite(&variant.sprite)),
+                        )
+                    })
+                    .collect(),
+            },
+            weapons_materials: WeaponsMaterials {
+                textures: game_data
+                    .get_all_weapons()
+                    .iter()
+                    .map(|weapon| {
+                        (
+                            weapon.sprite.clone(),
+                            asset_server.load(mod_list.resolve_sprite(&weapon.sprite)),
+                        )
+                    })
+                    .collect(),
+            },
+            bullet_materials: BulletsMaterials {
+                textures: game_data
+                    .get_all_weapons()
+                    .iter()
+                    .filter_map(|weapon| weapon.bullet.clone())
+                    .map(|bullet| {
+                        (
+                            bullet.sprite.clone(),
+                            asset_server.load(mod_list.resolve_sprite(&bullet.sprite)),
+                        )
+                    })
+                    .collect(),
+            },
+            dungeon_materials: DungeonMaterials {
+                floor: asset_server.load(mod_list.resolve_sprite("ingame/dungeon/floor.png")),
+                ladder: asset_server.load(mod_list.resolve_sprite("ingame/dungeon/ladder.png")),
+                wall: asset_server.load(mod_list.resolve_sprite("ingame/dungeon/wall.png")),
+                treasure: asset_server.load(mod_list.resolve_sprite("ingame/dungeon/treasure.png")),
+                arrow: asset_server.load(mod_list.resolve_sprite("ingame/bullets/arrow.png")),
+                wall_border_mid: asset_server
+                    .load(mod_list.resolve_sprite("ingame/dungeon/wall_border_mid.png")),
+                wall_border_corner_top_left: asset_server.load(
+                    mod_list.resolve_sprite("ingame/dungeon/wall_border_corner_top_left.png"),
+                ),
+                wall_border_corner_top_right: asset_server.load(
+                    mod_list.resolve_sprite("ingame/dungeon/wall_border_corner_top_right.png"),
+                ),
+                wall_border_corner_bottom_left: asset_server.load(
+                    mod_list.resolve_sprite("ingame/dungeon/wall_border_corner_bottom_left.png"),
+                ),
+                wall_border_corner_bottom_right: asset_server.load(
+                    mod_list.resolve_sprite("ingame/dungeon/wall_border_corner_bottom_right.png"),
+                ),
+                wall_border_left: asset_server
+                    .load(mod_list.resolve_sprite("ingame/dungeon/wall_border_left.png")),
+                wall_border_right: asset_server
+                    .load(mod_list.resolve_sprite("ingame/dungeon/wall_border_right.png")),
+                wall_border_corner_left: asset_server
+                    .load(mod_list.resolve_sprite("ingame/dungeon/wall_border_corner_left.png")),
+                wall_border_corner_right: asset_server
+                    .load(mod_list.resolve_sprite("ingame/dungeon/wall_border_corner_right.png")),
+                wall_left: asset_server
+                    .load(mod_list.resolve_sprite("ingame/dungeon/wall_left.png")),
+                wall_right: asset_server
+                    .load(mod_list.resolve_sprite("ingame/dungeon/wall_right.png")),
+                door_opened: asset_server
+                    .load(mod_list.resolve_sprite("ingame/dungeon/door_opened.png")),
+                door_closed: asset_server
+                    .load(mod_list.resolve_sprite("ingame/dungeon/door_closed.png")),
+                door_top_part: asset_server
+                    .load(mod_list.resolve_sprite("ingame/dungeon/door_top_part.png")),
+                door_left_part: asset_server
+                    .load(mod_list.resolve_sprite("ingame/dungeon/door_left_part.png")),
+                door_right_part: asset_server
+                    .load(mod_list.resolve_sprite("ingame/dungeon/door_right_part.png")),
+            },
+            biomes_materials: BiomesMaterials {
+                textures: game_data
+                    .get_biomes()
+                    .iter()
+                    .flat_map(|biome| biome.get_sprites())
+                    .map(|sprite| {
+                        (
+                            sprite.clone(),
+                            asset_server.load(mod_list.resolve_sprite(&sprite)),
+                        )
+                    })
+                    .collect(),
+            },
+            hearts_materials: HeartsMaterials {
+                full_heart: asset_server
+                    .load(mod_list.resolve_sprite("ingame/hearts/full_heart.png")),
+                half_heart: asset_server
+                    .load(mod_list.resolve_sprite("ingame/hearts/half_heart.png")),
+                empty_heart: asset_server
+                    .load(mod_list.resolve_sprite("ingame/hearts/empty_heart.png")),
+            },
+            monsters_materials: MonstersMaterials {
+                textures: game_data
+                    .get_monsters()
+                    .iter()
+                    .map(|monster| {
+                        (
+                            monster.sprite.clone(),
+                            asset_server.load(mod_list.resolve_sprite(&monster.sprite)),
+                        )
+                    })
+                    .collect(),
+            },
+            potions_materials: PotionsMaterials {
+                heal: asset_server.load(mod_list.resolve_sprite("ingame/potions/heal.png")),
+                focus: asset_server.load(mod_list.resolve_sprite("ingame/potions/focus.png")),
+                speed_up: asset_server.load(mod_list.resolve_sprite("ingame/potions/speed_up.png")),
+                evasion_up: asset_server
+                    .load(mod_list.resolve_sprite("ingame/potions/evasion_up.png")),
+            },
+            props_materials: PropsMaterials {
+                textures: game_data
+                    .get_props()
+                    .iter()
+                    .map(|prop| {
+                        (
+                            prop.sprite.clone(),
+                            asset_server.load(mod_list.resolve_sprite(&prop.sprite)),
+                        )
+                    })
+                    .collect(),
+            },
+            relics_materials: RelicsMaterials {
+                relic: asset_server.load(mod_list.resolve_sprite("ingame/relics/relic.png")),
+            },
+            shop_materials: ShopMaterials {
+                coin: asset_server.load(mod_list.resolve_sprite("ingame/shop/coin.png")),
+                shop: asset_server.load(mod_list.resolve_sprite("ingame/shop/shop.png")),
+            },
+        }
+    }
 }
@@ -1,3 +1,4 @@
+pub mod biomes;
 pub mod bullets;
 pub mod dungeon;
 pub mod flag;
@@ -9,5 +10,8 @@ pub mod ingame;
 pub mod menu_box;
 pub mod monsters;
 pub mod potions;
+pub mod props;
+pub mod relics;
 pub mod scenes;
+pub mod shop;
 pub mod weapons;
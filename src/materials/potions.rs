@@ -1,8 +1,21 @@
 use bevy::prelude::*;
 
+use crate::resources::potion::potion_type::PotionType;
+
 pub struct PotionsMaterials {
     pub heal: Handle<Image>,
     pub focus: Handle<Image>,
     pub evasion_up: Handle<Image>,
     pub speed_up: Handle<Image>,
 }
+
+impl PotionsMaterials {
+    pub fn get_texture(&self, potion_type: PotionType) -> Handle<Image> {
+        match potion_type {
+            PotionType::Heal => self.heal.clone(),
+            PotionType::Focus => self.focus.clone(),
+            PotionType::EvasionUp => self.evasion_up.clone(),
+            PotionType::SpeedUp => self.speed_up.clone(),
+        }
+    }
+}
@@ -0,0 +1,5 @@
+use bevy::prelude::*;
+
+pub struct RelicsMaterials {
+    pub relic: Handle<Image>,
+}
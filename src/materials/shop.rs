@@ -0,0 +1,6 @@
+use bevy::prelude::*;
+
+pub struct ShopMaterials {
+    pub coin: Handle<Image>,
+    pub shop: Handle<Image>,
+}
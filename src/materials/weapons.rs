@@ -1,16 +1,16 @@
 use bevy::prelude::*;
+use std::collections::HashMap;
 
 #[derive(Clone)]
 pub struct WeaponsMaterials {
-    pub bow: Handle<Image>,
-    pub short_sword: Handle<Image>,
-    pub sword: Handle<Image>,
-    pub machete: Handle<Image>,
-    pub small_hammer: Handle<Image>,
-    pub mace: Handle<Image>,
-    pub big_hammer: Handle<Image>,
-    pub small_wand: Handle<Image>,
-    pub magic_wand: Handle<Image>,
-    pub magic_sword: Handle<Image>,
-    pub spear: Handle<Image>,
+    pub textures: HashMap<String, Handle<Image>>,
+}
+
+impl WeaponsMaterials {
+    pub fn get_texture(&self, path: &str) -> Handle<Image> {
+        match self.textures.get(path) {
+            Some(texture) => texture.clone(),
+            None => panic!("Weapon texture {} was not loaded", path),
+        }
+    }
 }
@@ -1,8 +1,13 @@
 use bevy::prelude::*;
 
 use crate::components::player::PlayerComponent;
+use crate::config::*;
+use crate::resources::dungeon::current_room::CurrentRoom;
 use crate::scenes::SceneState;
 
+const CAMERA_FOLLOW_SPEED: f32 = 8.0;
+const CAMERA_TRANSITION_THRESHOLD: f32 = 0.25;
+
 #[derive(Component)]
 pub struct UserInterfaceCamera;
 
@@ -18,7 +23,10 @@ impl Plugin for CameraPlugin {
 
         app.add_systems(Update, camera_follow.run_if(in_state(SceneState::InGameSurvivalMode)));
 
+        app.add_systems(Update, classic_camera_follow.run_if(in_state(SceneState::InGameClassicMode).and_then(resource_exists::<CurrentRoom>())));
+
         app.add_systems(OnExit(SceneState::InGameSurvivalMode), reset_camera);
+        app.add_systems(OnExit(SceneState::InGameClassicMode), reset_camera);
     }
 }
 
@@ -57,6 +65,48 @@ fn camera_follow(
     camera_transform.translation.y = player_transform.translation.y;
 }
 
+fn classic_camera_follow(
+    player_query: Query<&Transform, With<PlayerComponent>>,
+    mut camera_query: Query<&mut Transform, (Without<PlayerComponent>, With<Orthographic2DCamera>)>,
+    current_room: Res<CurrentRoom>,
+    time: Res<Time>,
+) {
+    let player_transform = player_query.single();
+    let mut camera_transform = camera_query.single_mut();
+
+    let view_size = Vec2::new(WINDOW_HEIGHT * RESOLUTION, WINDOW_HEIGHT);
+    let half_room_size = current_room.room.get_size() / 2.0;
+    let max_offset = (half_room_size - view_size / 2.0).max(Vec2::ZERO);
+
+    let player_position = player_transform.translation.truncate();
+    let target = player_position.clamp(-max_offset, max_offset);
+
+    if current_room.is_changed() {
+        // slide in from the side of the room the player came from
+        let entry_side = player_position / half_room_size;
+        let start = if entry_side.x.abs() >= entry_side.y.abs()
+            && entry_side.x.abs() > CAMERA_TRANSITION_THRESHOLD
+        {
+            target + Vec2::new(view_size.x * entry_side.x.signum(), 0.0)
+        } else if entry_side.y.abs() > CAMERA_TRANSITION_THRESHOLD {
+            target + Vec2::new(0.0, view_size.y * entry_side.y.signum())
+        } else {
+            target
+        };
+
+        camera_transform.translation.x = start.x;
+        camera_transform.translation.y = start.y;
+        return;
+    }
+
+    let camera_position = camera_transform.translation.truncate();
+    let new_position =
+        camera_position.lerp(target, (CAMERA_FOLLOW_SPEED * time.delta_seconds()).min(1.0));
+
+    camera_transform.translation.x = new_position.x;
+    camera_transform.translation.y = new_position.y;
+}
+
 fn reset_camera(mut camera_query: Query<&mut Transform, With<Orthographic2DCamera>>) {
     let mut camera_transform = camera_query.single_mut();
     camera_transform.translation.x = 0.0;
@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+
+use crate::resources::dungeon::current_room::CurrentRoom;
+use crate::resources::dungeon::room::Room;
+use crate::resources::dungeon::rooms::Rooms;
+use crate::resources::dungeon::Dungeon;
+use crate::resources::monster::monster_spawn_controller::MonsterSpawnController;
+
+pub fn current_room_handle_system(
+    mut current_room: ResMut<CurrentRoom>,
+    dungeon: Res<Dungeon>,
+    rooms: Res<Rooms>,
+) {
+    let current_floor = &dungeon.current_floor;
+    let current_position = current_floor.current_position;
+
+    if current_room.position == current_position && current_room.map_name == current_floor.map_name
+    {
+        return;
+    }
+
+    current_room.map_name = current_floor.map_name.clone();
+    current_room.position = current_position;
+    current_room.room = rooms.get_room(current_floor.get_room_id(current_position));
+}
+
+pub fn set_spawn_area(monster_spawn_controller: &mut MonsterSpawnController, room: &Room) {
+    let spawn_area_start = room.get_tile_translation(2.5, 2.0);
+    let spawn_area_end = room.get_tile_translation(
+        room.get_height() as f32 - 3.0,
+        room.get_width() as f32 - 3.0,
+    );
+
+    monster_spawn_controller.spawn_area_start_x = spawn_area_start.x;
+    monster_spawn_controller.spawn_area_start_y = spawn_area_start.y;
+    monster_spawn_controller.spawn_area_end_x = spawn_area_end.x;
+    monster_spawn_controller.spawn_area_end_y = spawn_area_end.y;
+}
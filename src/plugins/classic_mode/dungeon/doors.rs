@@ -3,31 +3,19 @@ use bevy::prelude::*;
 use crate::config::*;
 use crate::materials::ingame::InGameMaterials;
 use crate::plugins::classic_mode::ClassicModeData;
+use crate::resources::dungeon::current_room::CurrentRoom;
 use crate::resources::dungeon::door::{Door, HorizontalDoor, VerticaltDoor};
 use crate::resources::dungeon::doors::Doors;
 use crate::resources::dungeon::Dungeon;
 use crate::resources::player::player_dungeon_stats::PlayerDungeonStats;
 
-const START_Y: f32 = 0.0 + WINDOW_HEIGHT / 2.0 - TILE_SIZE / 2.0;
-const START_X: f32 = 0.0 - WINDOW_HEIGHT * RESOLUTION / 2.0 + TILE_SIZE / 2.0;
+const LOCKED_DOOR_COLOR: Color = Color::rgb(1.0, 0.8, 0.3);
 
-pub fn doors(
-    mut commands: Commands,
-    ingame_materials: Res<InGameMaterials>,
-    mut data: ResMut<ClassicModeData>,
-) {
+pub fn doors(mut commands: Commands, mut data: ResMut<ClassicModeData>) {
     let doors = commands
         .spawn(SpriteBundle {
             ..Default::default()
         })
-        .with_children(|parent| {
-            for door in Door::iterator() {
-                match door {
-                    Door::Left | Door::Right => horizontal_door(parent, door, &ingame_materials),
-                    Door::Bottom | Door::Top => vertical_door(parent, door, &ingame_materials),
-                }
-            }
-        })
         .insert(Doors)
         .insert(Name::new("Doors"))
         .id();
@@ -35,21 +23,49 @@ pub fn doors(
     data.doors = Some(doors);
 }
 
-pub fn horizontal_door(parent: &mut ChildBuilder, door: &Door, ingame_materials: &InGameMaterials) {
+pub fn doors_handle_system(
+    mut commands: Commands,
+    doors_query: Query<Entity, With<Doors>>,
+    ingame_materials: Res<InGameMaterials>,
+    current_room: Res<CurrentRoom>,
+) {
+    if !current_room.is_changed() {
+        return;
+    }
+
+    for doors_entity in doors_query.iter() {
+        commands
+            .entity(doors_entity)
+            .despawn_descendants()
+            .with_children(|parent| {
+                for door in Door::iterator() {
+                    if let Some(translation) = current_room.room.get_door_translation(door) {
+                        match door {
+                            Door::Left | Door::Right => {
+                                horizontal_door(parent, door, &ingame_materials, translation)
+                            }
+                            Door::Bottom | Door::Top => {
+                                vertical_door(parent, door, &ingame_materials, translation)
+                            }
+                        }
+                    }
+                }
+            });
+    }
+}
+
+pub fn horizontal_door(
+    parent: &mut ChildBuilder,
+    door: &Door,
+    ingame_materials: &InGameMaterials,
+    translation: Vec2,
+) {
     let image = if *door == Door::Left {
         ingame_materials.dungeon_materials.wall_border_left.clone()
     } else {
         ingame_materials.dungeon_materials.wall_border_right.clone()
     };
 
-    let x = if *door == Door::Left {
-        START_X
-    } else {
-        START_X + 15.0 * TILE_SIZE
-    };
-
-    let y = START_Y - 4.0 * TILE_SIZE;
-
     let component_name = if *door == Door::Left {
         "Left Door"
     } else {
@@ -63,7 +79,7 @@ pub fn horizontal_door(parent: &mut ChildBuilder, door: &Door, ingame_materials:
                 ..Default::default()
             },
             transform: Transform {
-                translation: Vec3::new(x, y, 0.2),
+                translation: translation.extend(0.2),
                 ..Default::default()
             },
             texture: image,
@@ -78,15 +94,16 @@ pub fn vertical_door(
     grandparent: &mut ChildBuilder,
     door: &Door,
     ingame_materials: &InGameMaterials,
+    translation: Vec2,
 ) {
     let left_part = ingame_materials.dungeon_materials.door_left_part.clone();
     let right_part = ingame_materials.dungeon_materials.door_right_part.clone();
     let door_closed = ingame_materials.dungeon_materials.door_closed.clone();
 
-    let left_door_part_x = -96.0;
-    let right_door_part_x = 96.0;
+    let left_door_part_x = translation.x - 96.0;
+    let right_door_part_x = translation.x + 96.0;
 
-    let y = if *door == Door::Bottom { -224.0 } else { 224.0 };
+    let y = translation.y;
     let z = if *door == Door::Bottom { 0.2 } else { 0.1 };
 
     let component_name = if *door == Door::Bottom {
@@ -128,7 +145,7 @@ pub fn vertical_door(
                         ..Default::default()
                     },
                     transform: Transform {
-                        translation: Vec3::new(0.0, y, z),
+                        translation: Vec3::new(translation.x, y, z),
                         ..Default::default()
                     },
                     texture: door_closed,
@@ -157,48 +174,35 @@ pub fn vertical_door(
 }
 
 pub fn horizontal_doors_system(
-    mut horizontal_door_query: Query<(&HorizontalDoor, &Door, &mut Visibility)>,
+    mut horizontal_door_query: Query<(&HorizontalDoor, &Door, &mut Sprite, &mut Visibility)>,
     player_dungeon_stats: Res<PlayerDungeonStats>,
+    current_room: Res<CurrentRoom>,
     dungeon: Res<Dungeon>,
 ) {
-    if player_dungeon_stats.is_changed() {
-        for (_horizontal_door, door, mut visibility) in horizontal_door_query.iter_mut() {
-            if !player_dungeon_stats.is_room_cleared {
-                *visibility = Visibility::Visible;
-            } else {
-                let current_floor = dungeon.current_floor.clone();
-                let current_position = current_floor.current_position;
+    if player_dungeon_stats.is_changed() || current_room.is_changed() {
+        let current_floor = &dungeon.current_floor;
+        let current_position = current_floor.current_position;
 
-                let total_columns = current_floor.total_columns;
-
-                let has_right_room = if current_position.column_index < total_columns - 1 {
-                    let right_room_column_index = current_position.column_index + 1;
-                    current_floor.map[current_position.row_index][right_room_column_index] != 0.0
-                } else {
-                    false
-                };
+        for (_horizontal_door, door, mut sprite, mut visibility) in horizontal_door_query.iter_mut()
+        {
+            let is_locked = match current_floor.get_neighbour(current_position, door) {
+                Some(neighbour) => current_floor.is_locked(neighbour),
+                None => false,
+            };
 
-                let has_left_room = if current_position.column_index > 0 {
-                    let left_room_column_index = current_position.column_index - 1;
-                    current_floor.map[current_position.row_index][left_room_column_index] != 0.0
-                } else {
-                    false
-                };
+            sprite.color = if is_locked {
+                LOCKED_DOOR_COLOR
+            } else {
+                Color::WHITE
+            };
 
-                *visibility = if *door == Door::Right {
-                    if has_right_room {
-                        Visibility::Hidden
-                    } else {
-                        Visibility::Inherited
-                    }
-                } else {
-                    if has_left_room {
-                        Visibility::Hidden
-                    } else {
-                        Visibility::Inherited
-                    }
-                }
-            }
+            *visibility = if !player_dungeon_stats.is_room_cleared || is_locked {
+                Visibility::Visible
+            } else if current_floor.has_neighbour(current_position, door) {
+                Visibility::Hidden
+            } else {
+                Visibility::Inherited
+            };
         }
     }
 }
@@ -206,29 +210,27 @@ pub fn horizontal_doors_system(
 pub fn vertical_doors_system(
     mut vertical_door_query: Query<(&VerticaltDoor, &Children, &mut Visibility)>,
     mut visibility_query: Query<&mut Visibility, Without<VerticaltDoor>>,
-    mut image_query: Query<(&Door, &mut Handle<Image>)>,
+    mut image_query: Query<(&Door, &mut Handle<Image>, &mut Sprite)>,
     player_dungeon_stats: Res<PlayerDungeonStats>,
     ingame_materials: Res<InGameMaterials>,
+    current_room: Res<CurrentRoom>,
     dungeon: Res<Dungeon>,
 ) {
-    if player_dungeon_stats.is_changed() {
+    if player_dungeon_stats.is_changed() || current_room.is_changed() {
         for (vertical_door, children, mut visibility) in vertical_door_query.iter_mut() {
-            let current_floor = dungeon.current_floor.clone();
+            let current_floor = &dungeon.current_floor;
             let current_position = current_floor.current_position;
-            let total_rows = current_floor.total_rows;
 
-            let has_next_room = if *vertical_door == VerticaltDoor::Top {
-                if current_position.row_index > 0 {
-                    let above_room_row_index = current_position.row_index - 1;
-                    current_floor.map[above_room_row_index][current_position.column_index] != 0.0
-                } else {
-                    false
-                }
-            } else if current_position.row_index < total_rows - 1 {
-                let below_room_row_index = current_position.row_index + 1;
-                current_floor.map[below_room_row_index][current_position.column_index] != 0.0
+            let door = if *vertical_door == VerticaltDoor::Top {
+                Door::Top
             } else {
-                false
+                Door::Bottom
+            };
+
+            let has_next_room = current_floor.has_neighbour(current_position, &door);
+            let is_locked = match current_floor.get_neighbour(current_position, &door) {
+                Some(neighbour) => current_floor.is_locked(neighbour),
+                None => false,
             };
 
             *visibility = if has_next_room {Visibility::Inherited} else {Visibility::Hidden};
@@ -243,12 +245,17 @@ pub fn vertical_doors_system(
                 for child in children.iter() {
                     let result = image_query.get_mut(*child);
                     if result.is_ok() {
-                        let (_door, mut texture) = result.unwrap();
-                        *texture = if is_room_cleared {
+                        let (_door, mut texture, mut sprite) = result.unwrap();
+                        *texture = if is_room_cleared && !is_locked {
                             ingame_materials.dungeon_materials.door_opened.clone()
                         } else {
                             ingame_materials.dungeon_materials.door_closed.clone()
-                        }
+                        };
+                        sprite.color = if is_locked {
+                            LOCKED_DOOR_COLOR
+                        } else {
+                            Color::WHITE
+                        };
                     }
                 }
             }
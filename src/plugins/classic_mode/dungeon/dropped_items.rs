@@ -0,0 +1,47 @@
+use bevy::prelude::*;
+
+use crate::materials::ingame::InGameMaterials;
+use crate::plugins::classic_mode::interactions::key;
+use crate::plugins::loot;
+use crate::plugins::weapon::inventory;
+use crate::resources::dungeon::current_room::CurrentRoom;
+use crate::resources::dungeon::dropped_item::DroppedItemType;
+use crate::resources::dungeon::Dungeon;
+use crate::resources::game_data::GameData;
+
+pub fn dropped_items_handle_system(
+    mut commands: Commands,
+    mut dungeon: ResMut<Dungeon>,
+    current_room: Res<CurrentRoom>,
+    ingame_materials: Res<InGameMaterials>,
+    game_data: Res<GameData>,
+) {
+    if !current_room.is_changed() {
+        return;
+    }
+
+    let dropped_items = match dungeon
+        .current_floor
+        .dropped_items
+        .remove(&current_room.position)
+    {
+        Some(dropped_items) => dropped_items,
+        None => return,
+    };
+
+    for dropped_item in dropped_items {
+        let position = Vec3::new(dropped_item.x, dropped_item.y, 0.0);
+        match dropped_item.item_type {
+            DroppedItemType::Key => key::spawn_key(&mut commands, &ingame_materials, position),
+            DroppedItemType::Potion(potion_type) => {
+                loot::spawn_potion(&mut commands, &ingame_materials, potion_type, position)
+            }
+            DroppedItemType::Weapon(weapon_type) => inventory::spawn_weapon_pickup(
+                &mut commands,
+                &ingame_materials,
+                game_data.get_weapon_by_type(weapon_type),
+                position,
+            ),
+        }
+    }
+}
@@ -1,22 +1,18 @@
 use bevy::prelude::*;
 
-use crate::config::*;
 use crate::materials::ingame::InGameMaterials;
-use crate::plugins::classic_mode::dungeon::{TOTAL_TILE_HEIGHT, TOTAL_TILE_WIDTH};
 use crate::plugins::classic_mode::ClassicModeData;
+use crate::resources::dungeon::current_room::CurrentRoom;
 use crate::resources::dungeon::end_point::EndPoint;
 use crate::resources::dungeon::Dungeon;
 
 pub fn end_point(
     mut commands: Commands,
     ingame_materials: Res<InGameMaterials>,
+    current_room: Res<CurrentRoom>,
     mut data: ResMut<ClassicModeData>,
 ) {
-    let start_x = 0.0 - WINDOW_HEIGHT * RESOLUTION / 2.0 + TILE_SIZE / 2.0;
-    let start_y = 0.0 + WINDOW_HEIGHT / 2.0 - TILE_SIZE / 2.0;
-
-    let center_row = TOTAL_TILE_HEIGHT / 2;
-    let center_column = TOTAL_TILE_WIDTH / 2;
+    let center = current_room.room.get_center();
 
     let end_point = commands
         .spawn(SpriteBundle {
@@ -25,11 +21,7 @@ pub fn end_point(
                 ..Default::default()
             },
             transform: Transform {
-                translation: Vec3::new(
-                    start_x + center_column as f32 * TILE_SIZE,
-                    start_y - center_row as f32 * TILE_SIZE,
-                    0.1,
-                ),
+                translation: center.extend(0.1),
                 ..Default::default()
             },
             texture: ingame_materials.dungeon_materials.ladder.clone(),
@@ -44,11 +36,18 @@ pub fn end_point(
 }
 
 pub fn end_point_handle_system(
-    mut query: Query<(&mut Visibility, &mut Handle<Image>), With<EndPoint>>,
+    mut query: Query<(&mut Visibility, &mut Handle<Image>, &mut Transform), With<EndPoint>>,
     ingame_materials: Res<InGameMaterials>,
+    current_room: Res<CurrentRoom>,
     dungeon: Res<Dungeon>,
 ) {
-    for (mut visibility, mut handle_image) in query.iter_mut() {
+    for (mut visibility, mut handle_image, mut transform) in query.iter_mut() {
+        if current_room.is_changed() {
+            let center = current_room.room.get_center();
+            transform.translation.x = center.x;
+            transform.translation.y = center.y;
+        }
+
         let current_position = dungeon.current_floor.current_position;
         let end_room_position = dungeon.current_floor.end_room_position;
 
@@ -2,57 +2,91 @@ use bevy::prelude::*;
 
 use crate::config::*;
 use crate::materials::ingame::InGameMaterials;
-use crate::plugins::classic_mode::dungeon::{TOTAL_TILE_HEIGHT, TOTAL_TILE_WIDTH};
 use crate::plugins::classic_mode::ClassicModeData;
+use crate::resources::dungeon::current_room::CurrentRoom;
 use crate::resources::dungeon::ground::Ground;
 use crate::resources::dungeon::layer::Layer;
+use crate::resources::dungeon::Dungeon;
+use crate::resources::game_data::GameData;
 
-pub fn ground(
-    mut commands: Commands,
-    ingame_materials: Res<InGameMaterials>,
-    mut data: ResMut<ClassicModeData>,
-) {
-    let start_y: f32 = 0.0 + WINDOW_HEIGHT / 2.0 - TILE_SIZE / 2.0;
-    let start_x: f32 = 0.0 - WINDOW_HEIGHT * RESOLUTION / 2.0 + TILE_SIZE / 2.0;
-
+pub fn ground(mut commands: Commands, mut data: ResMut<ClassicModeData>) {
     let ground = commands
         .spawn(SpriteBundle {
             sprite: Sprite {
                 color: Color::BLACK,
-                custom_size: Some(Vec2::new(WINDOW_HEIGHT * RESOLUTION, WINDOW_HEIGHT)),
                 ..Default::default()
             },
             ..Default::default()
         })
-        .with_children(|parent| {
-            for row_index in 0..TOTAL_TILE_HEIGHT {
-                for column_index in 0..TOTAL_TILE_WIDTH {
-                    if row_index >= 1 && column_index > 0 && column_index < 15 {
-                        let x = start_x + column_index as f32 * TILE_SIZE;
-                        let y = start_y - row_index as f32 * TILE_SIZE;
+        .insert(Name::new("Ground"))
+        .insert(Ground)
+        .id();
+
+    data.ground = Some(ground);
+}
+
+pub fn ground_handle_system(
+    mut commands: Commands,
+    mut ground_query: Query<(Entity, &mut Sprite), With<Ground>>,
+    ingame_materials: Res<InGameMaterials>,
+    current_room: Res<CurrentRoom>,
+    dungeon: Res<Dungeon>,
+    game_data: Res<GameData>,
+) {
+    if !current_room.is_changed() {
+        return;
+    }
+
+    let room = &current_room.room;
+    let biome = game_data.get_biome(&dungeon.current_floor.biome);
+    let texture = ingame_materials
+        .biomes_materials
+        .get_texture(&biome.floor_sprite);
+    let color = get_biome_color(biome.tint);
+
+    let total_rows = room.get_height();
+    let total_columns = room.get_width();
+
+    for (ground_entity, mut sprite) in ground_query.iter_mut() {
+        sprite.custom_size = Some(
+            room.get_size()
+                .max(Vec2::new(WINDOW_HEIGHT * RESOLUTION, WINDOW_HEIGHT)),
+        );
+
+        commands
+            .entity(ground_entity)
+            .despawn_descendants()
+            .with_children(|parent| {
+                for row_index in 1..total_rows {
+                    for column_index in 1..total_columns - 1 {
+                        let translation =
+                            room.get_tile_translation(row_index as f32, column_index as f32);
 
                         parent
                             .spawn(SpriteBundle {
                                 sprite: Sprite {
                                     custom_size: Some(Vec2::new(TILE_SIZE, TILE_SIZE)),
+                                    color,
                                     ..Default::default()
                                 },
                                 transform: Transform {
-                                    translation: Vec3::new(x, y, 0.0),
+                                    translation: translation.extend(0.0),
                                     ..Default::default()
                                 },
-                                texture: ingame_materials.dungeon_materials.floor.clone(),
+                                texture: texture.clone(),
                                 ..Default::default()
                             })
                             .insert(Layer)
                             .insert(Name::new("Layer"));
                     }
                 }
-            }
-        })
-        .insert(Name::new("Ground"))
-        .insert(Ground)
-        .id();
+            });
+    }
+}
 
-    data.ground = Some(ground);
+pub fn get_biome_color(tint: Option<[f32; 3]>) -> Color {
+    match tint {
+        Some([red, green, blue]) => Color::rgb(red, green, blue),
+        None => Color::WHITE,
+    }
 }
@@ -0,0 +1,117 @@
+use bevy::prelude::*;
+use std::time::Duration;
+
+use crate::components::hazard::HazardComponent;
+use crate::config::*;
+use crate::plugins::classic_mode::ClassicModeData;
+use crate::resources::dungeon::current_room::CurrentRoom;
+use crate::resources::dungeon::hazard_type::HazardType;
+use crate::resources::dungeon::hazards::Hazards;
+use crate::resources::dungeon::room::Room;
+use crate::resources::dungeon::Dungeon;
+use crate::resources::game_data::GameData;
+
+pub const SPIKE_TRAP_INTERVAL: f32 = 1.5;
+pub const ARROW_TURRET_INTERVAL: f32 = 2.5;
+
+pub const SPIKE_TRAP_ACTIVE_COLOR: Color = Color::rgb(0.85, 0.85, 0.9);
+pub const SPIKE_TRAP_INACTIVE_COLOR: Color = Color::rgba(0.4, 0.4, 0.45, 0.6);
+pub const ARROW_TURRET_COLOR: Color = Color::rgb(0.35, 0.25, 0.15);
+pub const MUD_COLOR: Color = Color::rgba(0.4, 0.25, 0.1, 0.7);
+pub const POISON_COLOR: Color = Color::rgba(0.3, 0.8, 0.2, 0.6);
+
+pub fn hazards(mut commands: Commands, mut data: ResMut<ClassicModeData>) {
+    let hazards = commands
+        .spawn(SpatialBundle {
+            visibility: Visibility::Hidden,
+            ..Default::default()
+        })
+        .insert(Hazards)
+        .insert(Name::new("Hazards"))
+        .id();
+
+    data.hazards = Some(hazards);
+}
+
+fn hazard(
+    parent: &mut ChildBuilder,
+    room: &Room,
+    row_index: usize,
+    column_index: usize,
+    hazard_type: HazardType,
+) {
+    let translation = room.get_tile_translation(row_index as f32, column_index as f32);
+
+    let (size, color, interval) = match hazard_type {
+        HazardType::SpikeTrap => (
+            TILE_SIZE * 0.8,
+            SPIKE_TRAP_INACTIVE_COLOR,
+            SPIKE_TRAP_INTERVAL,
+        ),
+        HazardType::ArrowTurret => (TILE_SIZE * 0.6, ARROW_TURRET_COLOR, ARROW_TURRET_INTERVAL),
+        HazardType::Mud => (TILE_SIZE, MUD_COLOR, 0.0),
+        HazardType::Poison => (TILE_SIZE, POISON_COLOR, 0.0),
+    };
+
+    let component_name = format!("{:?} {}:{}", hazard_type, row_index, column_index);
+
+    parent
+        .spawn(SpriteBundle {
+            sprite: Sprite {
+                custom_size: Some(Vec2::new(size, size)),
+                color,
+                ..Default::default()
+            },
+            transform: Transform {
+                translation: translation.extend(0.05),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .insert(HazardComponent {
+            hazard_type,
+            timer: Timer::new(Duration::from_secs_f32(interval), TimerMode::Repeating),
+            is_active: hazard_type != HazardType::SpikeTrap,
+            width: size,
+            height: size,
+        })
+        .insert(Name::new(component_name));
+}
+
+pub fn hazards_handle_system(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut Visibility), With<Hazards>>,
+    current_room: Res<CurrentRoom>,
+    dungeon: Res<Dungeon>,
+    game_data: Res<GameData>,
+) {
+    let current_floor = &dungeon.current_floor;
+
+    for (hazards_entity, mut visibility) in query.iter_mut() {
+        if current_room.is_changed() {
+            let room = &current_room.room;
+            let biome = game_data.get_biome(&current_floor.biome);
+
+            commands
+                .entity(hazards_entity)
+                .despawn_descendants()
+                .with_children(|parent| {
+                    for (row_index, row) in room.tilemap.iter().enumerate() {
+                        for (column_index, column) in row.iter().enumerate() {
+                            if let Some(hazard_type) = HazardType::from_value(*column) {
+                                if biome.hazards.contains(&hazard_type) {
+                                    hazard(parent, room, row_index, column_index, hazard_type);
+                                }
+                            }
+                        }
+                    }
+                });
+        }
+
+        *visibility = if current_floor.is_combat_room(current_floor.current_position) {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+}
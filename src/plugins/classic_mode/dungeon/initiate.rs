@@ -1,20 +1,37 @@
 use bevy::prelude::*;
 
-use crate::config::*;
+use crate::plugins::classic_mode::dungeon::current_room::set_spawn_area;
 use crate::plugins::classic_mode::ClassicModeData;
+use crate::resources::dungeon::current_room::CurrentRoom;
+use crate::resources::dungeon::rooms::Rooms;
 use crate::resources::dungeon::Dungeon;
+use crate::resources::game_data::GameData;
 use crate::resources::game_mode::GameMode;
+use crate::resources::mods::ModList;
 use crate::resources::monster::monster_spawn_controller::MonsterSpawnController;
 use crate::resources::player::player_dungeon_stats::PlayerDungeonStats;
+use crate::resources::playtest::Playtest;
 use crate::scenes::SceneState;
 
-pub fn initiate_classic_mode(mut commands: Commands, mut state: ResMut<NextState<SceneState>>) {
-    let dungeon = Dungeon::new();
+pub fn initiate_classic_mode(
+    mod_list: Res<ModList>,
+    game_data: Res<GameData>,
+    rooms: Res<Rooms>,
+    playtest: Res<Playtest>,
+    mut commands: Commands,
+    mut state: ResMut<NextState<SceneState>>,
+) {
+    let dungeon = if playtest.is_active() {
+        Dungeon::playtest(&playtest, &game_data)
+    } else {
+        Dungeon::new(&mod_list, &game_data)
+    };
 
     let player_dungeon_stats = PlayerDungeonStats {
         current_room_position: dungeon.current_floor.current_position,
-        is_room_cleared: true,
+        is_room_cleared: !playtest.is_active(),
         current_floor_index: 0,
+        keys: 0,
     };
 
     let classic_mode_data = ClassicModeData {
@@ -22,32 +39,38 @@ pub fn initiate_classic_mode(mut commands: Commands, mut state: ResMut<NextState
         walls: None,
         ground: None,
         end_point: None,
+        shop_point: None,
+        treasure_point: None,
+        shrine_point: None,
+        hazards: None,
+        props: None,
     };
 
-    let start_x: f32 = 0.0 - WINDOW_HEIGHT * RESOLUTION / 2.0 + TILE_SIZE / 2.0;
-    let start_y: f32 = 0.0 + WINDOW_HEIGHT / 2.0 - TILE_SIZE / 2.0;
-
-    let spawn_area_start_x = start_x + TILE_SIZE * 2.0;
-    let spawn_area_start_y = start_y - TILE_SIZE * 2.5;
-    let spawn_area_end_x = start_x + 13.0 * TILE_SIZE;
-    let spawn_area_end_y = start_y - 6.0 * TILE_SIZE;
+    let current_position = dungeon.current_floor.current_position;
+    let current_room = CurrentRoom {
+        map_name: dungeon.current_floor.map_name.clone(),
+        position: current_position,
+        room: rooms.get_room(dungeon.current_floor.get_room_id(current_position)),
+    };
 
-    let monster_spawn_controller = MonsterSpawnController {
+    let mut monster_spawn_controller = MonsterSpawnController {
         game_mode: GameMode::ClassicMode,
         max_avalible_monsters: 4,
         require_monster: 5,
         alive_monsters: 0,
         killed_monsters: 0,
-        spawn_area_start_x,
-        spawn_area_start_y,
-        spawn_area_end_x,
-        spawn_area_end_y,
+        spawn_area_start_x: 0.0,
+        spawn_area_start_y: 0.0,
+        spawn_area_end_x: 0.0,
+        spawn_area_end_y: 0.0,
     };
+    set_spawn_area(&mut monster_spawn_controller, &current_room.room);
 
     commands.insert_resource(dungeon);
     commands.insert_resource(player_dungeon_stats);
     commands.insert_resource(classic_mode_data);
     commands.insert_resource(monster_spawn_controller);
+    commands.insert_resource(current_room);
 
     state
         .set(SceneState::InGameClassicMode);
@@ -1,8 +1,12 @@
+pub mod current_room;
 pub mod doors;
+pub mod dropped_items;
 pub mod end_point;
 pub mod ground;
+pub mod hazards;
 pub mod initiate;
+pub mod props;
+pub mod shop_point;
+pub mod shrine_point;
+pub mod treasure_point;
 pub mod walls;
-
-pub const TOTAL_TILE_WIDTH: usize = 16;
-pub const TOTAL_TILE_HEIGHT: usize = 9;
@@ -0,0 +1,171 @@
+use bevy::prelude::*;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::time::Duration;
+
+use crate::components::prop::PropComponent;
+use crate::materials::ingame::InGameMaterials;
+use crate::plugins::classic_mode::ClassicModeData;
+use crate::resources::dungeon::current_room::CurrentRoom;
+use crate::resources::dungeon::props::Props;
+use crate::resources::dungeon::room::Room;
+use crate::resources::dungeon::Dungeon;
+use crate::resources::game_data::GameData;
+use crate::resources::prop::prop_placement::PropPlacement;
+use crate::resources::prop::Prop;
+
+const MAX_PROCEDURAL_PROPS: usize = 3;
+const PROP_HURT_DURATION: f32 = 0.3;
+
+pub fn props(mut commands: Commands, mut data: ResMut<ClassicModeData>) {
+    let props = commands
+        .spawn(SpatialBundle::default())
+        .insert(Props)
+        .insert(Name::new("Props"))
+        .id();
+
+    data.props = Some(props);
+}
+
+pub fn props_handle_system(
+    mut commands: Commands,
+    props_query: Query<Entity, With<Props>>,
+    mut dungeon: ResMut<Dungeon>,
+    current_room: Res<CurrentRoom>,
+    game_data: Res<GameData>,
+    ingame_materials: Res<InGameMaterials>,
+) {
+    if !current_room.is_changed() {
+        return;
+    }
+
+    let current_position = current_room.position;
+    let room = &current_room.room;
+
+    for props_entity in props_query.iter() {
+        commands.entity(props_entity).despawn_descendants();
+
+        if !dungeon.current_floor.is_combat_room(current_position) {
+            continue;
+        }
+
+        let placements = dungeon
+            .current_floor
+            .props
+            .entry(current_position)
+            .or_insert_with(|| generate_placements(room, &game_data))
+            .clone();
+
+        commands.entity(props_entity).with_children(|parent| {
+            for placement in placements.iter().filter(|placement| !placement.is_broken) {
+                prop(
+                    parent,
+                    room,
+                    placement,
+                    game_data.get_prop(&placement.name),
+                    &ingame_materials,
+                );
+            }
+        });
+    }
+}
+
+fn generate_placements(room: &Room, game_data: &GameData) -> Vec<PropPlacement> {
+    let mut placements: Vec<PropPlacement> = Vec::new();
+    let mut free_tiles: Vec<(usize, usize)> = Vec::new();
+
+    for (row_index, row) in room.tilemap.iter().enumerate() {
+        for (column_index, column) in row.iter().enumerate() {
+            if let Some(prop) = game_data.get_prop_by_tile(*column) {
+                placements.push(PropPlacement {
+                    name: prop.name,
+                    row_index,
+                    column_index,
+                    is_broken: false,
+                });
+            } else if *column == 0 && !room.is_doorway(row_index, column_index) {
+                free_tiles.push((row_index, column_index));
+            }
+        }
+    }
+
+    let spawnable_props: Vec<Prop> = game_data
+        .get_props()
+        .into_iter()
+        .filter(|prop| prop.spawn_weight > 0.0)
+        .collect();
+
+    if spawnable_props.is_empty() {
+        return placements;
+    }
+
+    let mut rng = rand::thread_rng();
+    free_tiles.shuffle(&mut rng);
+
+    let total_procedural_props = rng.gen_range(0..=MAX_PROCEDURAL_PROPS);
+    for (row_index, column_index) in free_tiles.into_iter().take(total_procedural_props) {
+        let prop = spawnable_props
+            .choose_weighted(&mut rng, |prop| prop.spawn_weight)
+            .unwrap();
+
+        placements.push(PropPlacement {
+            name: prop.name.clone(),
+            row_index,
+            column_index,
+            is_broken: false,
+        });
+    }
+
+    placements
+}
+
+fn prop(
+    parent: &mut ChildBuilder,
+    room: &Room,
+    placement: &PropPlacement,
+    prop: Prop,
+    ingame_materials: &InGameMaterials,
+) {
+    let translation =
+        room.get_tile_translation(placement.row_index as f32, placement.column_index as f32);
+
+    let color = match prop.color {
+        Some([red, green, blue]) => Color::rgb(red, green, blue),
+        None => Color::WHITE,
+    };
+
+    let component_name = format!(
+        "{} {}:{}",
+        prop.name, placement.row_index, placement.column_index
+    );
+
+    let mut hurt_duration =
+        Timer::new(Duration::from_secs_f32(PROP_HURT_DURATION), TimerMode::Once);
+    hurt_duration.tick(Duration::from_secs_f32(PROP_HURT_DURATION));
+
+    parent
+        .spawn(SpriteBundle {
+            texture: ingame_materials.props_materials.get_texture(&prop.sprite),
+            sprite: Sprite {
+                custom_size: Some(Vec2::new(prop.width, prop.height)),
+                color,
+                ..Default::default()
+            },
+            transform: Transform {
+                translation: translation.extend(0.12),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .insert(PropComponent {
+            name: prop.name,
+            current_health_points: prop.health_points,
+            width: prop.width,
+            height: prop.height,
+            color,
+            row_index: placement.row_index,
+            column_index: placement.column_index,
+            hurt_duration,
+        })
+        .insert(Name::new(component_name));
+}
@@ -0,0 +1,61 @@
+use bevy::prelude::*;
+
+use crate::materials::ingame::InGameMaterials;
+use crate::plugins::classic_mode::ClassicModeData;
+use crate::resources::dungeon::current_room::CurrentRoom;
+use crate::resources::dungeon::shop_point::ShopPoint;
+use crate::resources::dungeon::Dungeon;
+
+pub fn shop_point(
+    mut commands: Commands,
+    ingame_materials: Res<InGameMaterials>,
+    current_room: Res<CurrentRoom>,
+    mut data: ResMut<ClassicModeData>,
+) {
+    let center = current_room.room.get_center();
+
+    let shop_point = commands
+        .spawn(SpriteBundle {
+            sprite: Sprite {
+                custom_size: Some(Vec2::new(64.0, 64.0)),
+                ..Default::default()
+            },
+            transform: Transform {
+                translation: center.extend(0.1),
+                ..Default::default()
+            },
+            texture: ingame_materials.shop_materials.shop.clone(),
+            visibility: Visibility::Hidden,
+            ..Default::default()
+        })
+        .insert(ShopPoint {
+            is_player_inside: false,
+        })
+        .insert(Name::new("ShopPoint"))
+        .id();
+
+    data.shop_point = Some(shop_point);
+}
+
+pub fn shop_point_handle_system(
+    mut query: Query<(&mut Visibility, &mut Transform), With<ShopPoint>>,
+    current_room: Res<CurrentRoom>,
+    dungeon: Res<Dungeon>,
+) {
+    for (mut visibility, mut transform) in query.iter_mut() {
+        if current_room.is_changed() {
+            let center = current_room.room.get_center();
+            transform.translation.x = center.x;
+            transform.translation.y = center.y;
+        }
+
+        let current_position = dungeon.current_floor.current_position;
+        let shop_room_position = dungeon.current_floor.shop_room_position;
+
+        if shop_room_position == Some(current_position) {
+            *visibility = Visibility::Visible;
+        } else {
+            *visibility = Visibility::Hidden;
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use bevy::prelude::*;
+
+use crate::materials::ingame::InGameMaterials;
+use crate::plugins::classic_mode::ClassicModeData;
+use crate::resources::dungeon::current_room::CurrentRoom;
+use crate::resources::dungeon::room_type::RoomType;
+use crate::resources::dungeon::shrine_point::ShrinePoint;
+use crate::resources::dungeon::Dungeon;
+
+const SHRINE_COLOR: Color = Color::rgb(0.7, 0.4, 1.0);
+
+pub fn shrine_point(
+    mut commands: Commands,
+    ingame_materials: Res<InGameMaterials>,
+    current_room: Res<CurrentRoom>,
+    mut data: ResMut<ClassicModeData>,
+) {
+    let center = current_room.room.get_center();
+
+    let shrine_point = commands
+        .spawn(SpriteBundle {
+            sprite: Sprite {
+                custom_size: Some(Vec2::new(64.0, 64.0)),
+                color: SHRINE_COLOR,
+                ..Default::default()
+            },
+            transform: Transform {
+                translation: center.extend(0.1),
+                ..Default::default()
+            },
+            texture: ingame_materials.relics_materials.relic.clone(),
+            visibility: Visibility::Hidden,
+            ..Default::default()
+        })
+        .insert(ShrinePoint)
+        .insert(Name::new("ShrinePoint"))
+        .id();
+
+    data.shrine_point = Some(shrine_point);
+}
+
+pub fn shrine_point_handle_system(
+    mut query: Query<(&mut Visibility, &mut Transform), With<ShrinePoint>>,
+    current_room: Res<CurrentRoom>,
+    dungeon: Res<Dungeon>,
+) {
+    for (mut visibility, mut transform) in query.iter_mut() {
+        if current_room.is_changed() {
+            let center = current_room.room.get_center();
+            transform.translation.x = center.x;
+            transform.translation.y = center.y;
+        }
+
+        let current_floor = &dungeon.current_floor;
+        let current_position = current_floor.current_position;
+
+        if current_floor.get_room_type(current_position) == RoomType::Shrine
+            && !current_floor.looted_positions.contains(&current_position)
+        {
+            *visibility = Visibility::Visible;
+        } else {
+            *visibility = Visibility::Hidden;
+        }
+    }
+}
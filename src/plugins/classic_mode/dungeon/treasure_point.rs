@@ -0,0 +1,62 @@
+use bevy::prelude::*;
+
+use crate::materials::ingame::InGameMaterials;
+use crate::plugins::classic_mode::ClassicModeData;
+use crate::resources::dungeon::current_room::CurrentRoom;
+use crate::resources::dungeon::room_type::RoomType;
+use crate::resources::dungeon::treasure_point::TreasurePoint;
+use crate::resources::dungeon::Dungeon;
+
+pub fn treasure_point(
+    mut commands: Commands,
+    ingame_materials: Res<InGameMaterials>,
+    current_room: Res<CurrentRoom>,
+    mut data: ResMut<ClassicModeData>,
+) {
+    let center = current_room.room.get_center();
+
+    let treasure_point = commands
+        .spawn(SpriteBundle {
+            sprite: Sprite {
+                custom_size: Some(Vec2::new(50.0, 50.0)),
+                ..Default::default()
+            },
+            transform: Transform {
+                translation: center.extend(0.1),
+                ..Default::default()
+            },
+            texture: ingame_materials.dungeon_materials.treasure.clone(),
+            visibility: Visibility::Hidden,
+            ..Default::default()
+        })
+        .insert(TreasurePoint)
+        .insert(Name::new("TreasurePoint"))
+        .id();
+
+    data.treasure_point = Some(treasure_point);
+}
+
+pub fn treasure_point_handle_system(
+    mut query: Query<(&mut Visibility, &mut Transform), With<TreasurePoint>>,
+    current_room: Res<CurrentRoom>,
+    dungeon: Res<Dungeon>,
+) {
+    for (mut visibility, mut transform) in query.iter_mut() {
+        if current_room.is_changed() {
+            let center = current_room.room.get_center();
+            transform.translation.x = center.x;
+            transform.translation.y = center.y;
+        }
+
+        let current_floor = &dungeon.current_floor;
+        let current_position = current_floor.current_position;
+
+        if current_floor.get_room_type(current_position) == RoomType::Treasure
+            && !current_floor.looted_positions.contains(&current_position)
+        {
+            *visibility = Visibility::Visible;
+        } else {
+            *visibility = Visibility::Hidden;
+        }
+    }
+}
@@ -2,46 +2,26 @@ use bevy::prelude::*;
 
 use crate::config::*;
 use crate::materials::ingame::InGameMaterials;
-use crate::plugins::classic_mode::dungeon::TOTAL_TILE_HEIGHT;
+use crate::plugins::classic_mode::dungeon::ground::get_biome_color;
 use crate::plugins::classic_mode::ClassicModeData;
+use crate::resources::biome::Biome;
 use crate::resources::dungeon::block_type::BlockType;
-use crate::resources::dungeon::rooms::Rooms;
+use crate::resources::dungeon::current_room::CurrentRoom;
+use crate::resources::dungeon::door::Door;
+use crate::resources::dungeon::hazard_type::HazardType;
+use crate::resources::dungeon::room::Room;
 use crate::resources::dungeon::wall::Wall;
 use crate::resources::dungeon::wall_type::WallType;
 use crate::resources::dungeon::walls::Walls;
 use crate::resources::dungeon::Dungeon;
+use crate::resources::game_data::GameData;
 use crate::resources::player::player_dungeon_stats::PlayerDungeonStats;
 
-const START_Y: f32 = 0.0 + WINDOW_HEIGHT / 2.0 - TILE_SIZE / 2.0;
-const START_X: f32 = 0.0 - WINDOW_HEIGHT * RESOLUTION / 2.0 + TILE_SIZE / 2.0;
-
-pub fn walls(
-    mut commands: Commands,
-    dungeon: Res<Dungeon>,
-    rooms: Res<Rooms>,
-    ingame_materials: Res<InGameMaterials>,
-    mut data: ResMut<ClassicModeData>,
-) {
-    let current_floor = dungeon.current_floor.clone();
-    let current_position = current_floor.current_position;
-
-    let room_id = current_floor.map[current_position.row_index][current_position.column_index];
-
-    let room = rooms.get_room(if room_id == 1.0 { 1.0 } else { 1.0 });
-
+pub fn walls(mut commands: Commands, mut data: ResMut<ClassicModeData>) {
     let walls = commands
         .spawn(SpriteBundle {
             ..Default::default()
         })
-        .with_children(|parent| {
-            for (row_index, row) in room.tilemap.iter().enumerate() {
-                for (column_index, column) in row.iter().enumerate() {
-                    if *column != 0 {
-                        wall(parent, row_index, column_index, *column, &ingame_materials);
-                    }
-                }
-            }
-        })
         .insert(Walls)
         .insert(Name::new("Walls"))
         .id();
@@ -49,12 +29,56 @@ pub fn walls(
     data.walls = Some(walls);
 }
 
+pub fn walls_handle_system(
+    mut commands: Commands,
+    walls_query: Query<Entity, With<Walls>>,
+    ingame_materials: Res<InGameMaterials>,
+    current_room: Res<CurrentRoom>,
+    dungeon: Res<Dungeon>,
+    game_data: Res<GameData>,
+) {
+    if !current_room.is_changed() {
+        return;
+    }
+
+    let room = &current_room.room;
+    let biome = game_data.get_biome(&dungeon.current_floor.biome);
+
+    for walls_entity in walls_query.iter() {
+        commands
+            .entity(walls_entity)
+            .despawn_descendants()
+            .with_children(|parent| {
+                for (row_index, row) in room.tilemap.iter().enumerate() {
+                    for (column_index, column) in row.iter().enumerate() {
+                        if *column != 0
+                            && HazardType::from_value(*column).is_none()
+                            && game_data.get_prop_by_tile(*column).is_none()
+                        {
+                            wall(
+                                parent,
+                                row_index,
+                                column_index,
+                                *column,
+                                &ingame_materials,
+                                &biome,
+                                room,
+                            );
+                        }
+                    }
+                }
+            });
+    }
+}
+
 fn wall(
     parent: &mut ChildBuilder,
     row_index: usize,
     column_index: usize,
     value: i32,
     ingame_materials: &InGameMaterials,
+    biome: &Biome,
+    room: &Room,
 ) {
     let block_type = match value.abs() {
         1 => {
@@ -69,26 +93,9 @@ fn wall(
         _ => BlockType::None,
     };
 
-    let x = START_X + column_index as f32 * TILE_SIZE;
-    let y = START_Y - row_index as f32 * TILE_SIZE;
-
-    let image = match value {
-        -1 | 1 => ingame_materials.dungeon_materials.wall.clone(),
-        -2 | 2 => ingame_materials.dungeon_materials.wall_border_mid.clone(),
-        3 => ingame_materials
-            .dungeon_materials
-            .wall_border_corner_top_left
-            .clone(),
-        4 => ingame_materials
-            .dungeon_materials
-            .wall_border_corner_top_right
-            .clone(),
-        5 => ingame_materials.dungeon_materials.wall_left.clone(),
-        6 => ingame_materials.dungeon_materials.wall_right.clone(),
-        -7 | 7 => ingame_materials.dungeon_materials.wall_border_left.clone(),
-        -8 | 8 => ingame_materials.dungeon_materials.wall_border_right.clone(),
-        _ => panic!("Unknow room value: {}", value),
-    };
+    let translation = room.get_tile_translation(row_index as f32, column_index as f32);
+
+    let image = get_wall_image(value, ingame_materials, biome);
 
     // let component_name = if value < 0 {
     // "TemporaryWall" +
@@ -110,10 +117,11 @@ fn wall(
         .spawn(SpriteBundle {
             sprite: Sprite {
                 custom_size: Some(Vec2::new(TILE_SIZE, TILE_SIZE)),
+                color: get_biome_color(biome.tint),
                 ..Default::default()
             },
             transform: Transform {
-                translation: Vec3::new(x, y, z),
+                translation: translation.extend(z),
                 ..Default::default()
             },
             texture: image,
@@ -133,66 +141,78 @@ fn wall(
         .insert(Name::new(component_name));
 }
 
+pub fn get_wall_image(
+    value: i32,
+    ingame_materials: &InGameMaterials,
+    biome: &Biome,
+) -> Handle<Image> {
+    let biomes_materials = &ingame_materials.biomes_materials;
+    let dungeon_materials = &ingame_materials.dungeon_materials;
+
+    if let Some(border_sprites) = &biome.border_sprites {
+        let sprite = match value {
+            -1 | 1 => &biome.wall_sprite,
+            -2 | 2 => &border_sprites.border_mid,
+            3 => &border_sprites.border_corner_top_left,
+            4 => &border_sprites.border_corner_top_right,
+            5 => &border_sprites.wall_left,
+            6 => &border_sprites.wall_right,
+            -7 | 7 => &border_sprites.border_left,
+            -8 | 8 => &border_sprites.border_right,
+            _ => panic!("Unknow room value: {}", value),
+        };
+        return biomes_materials.get_texture(sprite);
+    }
+
+    match value {
+        -1 | 1 => biomes_materials.get_texture(&biome.wall_sprite),
+        -2 | 2 => dungeon_materials.wall_border_mid.clone(),
+        3 => dungeon_materials.wall_border_corner_top_left.clone(),
+        4 => dungeon_materials.wall_border_corner_top_right.clone(),
+        5 => dungeon_materials.wall_left.clone(),
+        6 => dungeon_materials.wall_right.clone(),
+        -7 | 7 => dungeon_materials.wall_border_left.clone(),
+        -8 | 8 => dungeon_materials.wall_border_right.clone(),
+        _ => panic!("Unknow room value: {}", value),
+    }
+}
+
 pub fn temporary_walls_system(
     mut wall_query: Query<(&Wall, &mut Visibility)>,
     player_dungeon_stats: Res<PlayerDungeonStats>,
+    current_room: Res<CurrentRoom>,
     dungeon: Res<Dungeon>,
 ) {
-    if player_dungeon_stats.is_changed() {
-        let current_floor = dungeon.current_floor.clone();
+    if player_dungeon_stats.is_changed() || current_room.is_changed() {
+        let current_floor = &dungeon.current_floor;
         let current_position = current_floor.current_position;
 
-        let total_rows = current_floor.total_rows;
-        let total_columns = current_floor.total_columns;
-
-        let total_room_rows = TOTAL_TILE_HEIGHT;
-
-        let has_above_room = if current_position.row_index > 0 {
-            let above_room_row_index = current_position.row_index - 1;
-            current_floor.map[above_room_row_index][current_position.column_index] != 0.0
-        } else {
-            false
-        };
-
-        let has_below_room = if current_position.row_index < total_rows - 1 {
-            let below_room_row_index = current_position.row_index + 1;
-            current_floor.map[below_room_row_index][current_position.column_index] != 0.0
-        } else {
-            false
-        };
-
-        let has_left_room = if current_position.column_index > 0 {
-            let left_room_row_index = current_position.column_index - 1;
-            current_floor.map[current_position.row_index][left_room_row_index] != 0.0
-        } else {
-            false
-        };
-
-        let has_right_room = if current_position.column_index < total_columns - 1 {
-            let right_room_row_index = current_position.column_index + 1;
-            current_floor.map[current_position.row_index][right_room_row_index] != 0.0
-        } else {
-            false
-        };
-
         for (wall, mut visibility) in wall_query.iter_mut() {
-            if wall.wall_type == WallType::Temporary {
-                if wall.row_index == 0 || wall.row_index == 1 {
-                    *visibility = if has_above_room {Visibility::Hidden} else {Visibility::Inherited};
-                }
-
-                if wall.row_index == total_room_rows - 1 || wall.row_index == total_room_rows - 2 {
-                    *visibility = if has_below_room {Visibility::Hidden} else {Visibility::Inherited};
-                }
-
-                if wall.value == -8 {
-                    *visibility = if has_right_room {Visibility::Hidden} else {Visibility::Inherited};
-                }
-
-                if wall.value == -7 {
-                    *visibility = if has_left_room {Visibility::Hidden} else {Visibility::Inherited};
-                }
+            if let Some(door) = get_wall_side(wall, &current_room.room) {
+                *visibility = if current_floor.has_neighbour(current_position, &door) {
+                    Visibility::Hidden
+                } else {
+                    Visibility::Inherited
+                };
             }
         }
     }
 }
+
+pub fn get_wall_side(wall: &Wall, room: &Room) -> Option<Door> {
+    let total_room_rows = room.get_height();
+
+    if wall.wall_type != WallType::Temporary {
+        None
+    } else if wall.value == -8 {
+        Some(Door::Right)
+    } else if wall.value == -7 {
+        Some(Door::Left)
+    } else if wall.row_index == 0 || wall.row_index == 1 {
+        Some(Door::Top)
+    } else if wall.row_index == total_room_rows - 1 || wall.row_index == total_room_rows - 2 {
+        Some(Door::Bottom)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+
+use crate::plugins::player::relic::RelicTriggerEvent;
+use crate::resources::dungeon::challenge_room::{ChallengeCondition, ChallengeRoom};
+use crate::resources::relic::relic_trigger::RelicTrigger;
+
+pub fn challenge_handle_system(
+    challenge_room: Option<ResMut<ChallengeRoom>>,
+    mut relic_trigger_events: EventReader<RelicTriggerEvent>,
+    time: Res<Time>,
+) {
+    let is_hit = relic_trigger_events
+        .read()
+        .any(|relic_trigger_event| relic_trigger_event.0 == RelicTrigger::OnHitTaken);
+
+    let mut challenge_room = match challenge_room {
+        Some(challenge_room) => challenge_room,
+        None => return,
+    };
+
+    if challenge_room.is_completed || challenge_room.is_failed {
+        return;
+    }
+
+    match challenge_room.condition {
+        ChallengeCondition::TimeLimit => {
+            challenge_room.timer.tick(time.delta());
+            if challenge_room.timer.finished() {
+                challenge_room.is_failed = true;
+            }
+        }
+        ChallengeCondition::NoHit => {
+            if is_hit {
+                challenge_room.is_failed = true;
+            }
+        }
+    }
+}
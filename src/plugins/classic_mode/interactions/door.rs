@@ -1,35 +1,197 @@
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use bevy::sprite::collide_aabb::collide;
 
+use crate::components::gold::GoldComponent;
+use crate::components::hazard_arrow::HazardArrowComponent;
+use crate::components::key::KeyComponent;
 use crate::components::player::PlayerComponent;
 use crate::components::potion::PotionComponent;
+use crate::components::relic_pickup::RelicPickupComponent;
+use crate::components::weapon_pickup::WeaponPickupComponent;
+use crate::config::*;
+use crate::plugins::classic_mode::dungeon::current_room::set_spawn_area;
+use crate::resources::dungeon::challenge_room::ChallengeRoom;
 use crate::resources::dungeon::door::{Door, HorizontalDoor, VerticaltDoor};
+use crate::resources::dungeon::dropped_item::{DroppedItem, DroppedItemType};
 use crate::resources::dungeon::position::Position;
+use crate::resources::dungeon::room::Room;
+use crate::resources::dungeon::room_type::RoomType;
+use crate::resources::dungeon::rooms::Rooms;
 use crate::resources::dungeon::Dungeon;
 use crate::resources::monster::monster_spawn_controller::MonsterSpawnController;
 use crate::resources::player::player_dungeon_stats::PlayerDungeonStats;
 use crate::resources::profile::Profile;
 
+const DOOR_ENTRY_OFFSET: f32 = TILE_SIZE * 1.5;
+
+type PickupsFilter = Or<(
+    With<RelicPickupComponent>,
+    With<GoldComponent>,
+    With<HazardArrowComponent>,
+)>;
+
+pub type DroppedItemsQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static Transform,
+        Option<&'static PotionComponent>,
+        Option<&'static WeaponPickupComponent>,
+    ),
+    Or<(
+        With<KeyComponent>,
+        With<PotionComponent>,
+        With<WeaponPickupComponent>,
+    )>,
+>;
+
+type HorizontalDoorQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static Door,
+        &'static Transform,
+        &'static Sprite,
+        &'static Visibility,
+    ),
+    (With<HorizontalDoor>, Without<PlayerComponent>),
+>;
+
+#[derive(SystemParam)]
+pub struct RoomTransition<'w, 's> {
+    monster_spawn_controller: ResMut<'w, MonsterSpawnController>,
+    player_dungeon_stats: ResMut<'w, PlayerDungeonStats>,
+    dungeon: ResMut<'w, Dungeon>,
+    rooms: Res<'w, Rooms>,
+    profile: ResMut<'w, Profile>,
+    pickups_query: Query<'w, 's, Entity, PickupsFilter>,
+    dropped_items_query: DroppedItemsQuery<'w, 's>,
+    commands: Commands<'w, 's>,
+}
+
+impl RoomTransition<'_, '_> {
+    fn enter_room(
+        &mut self,
+        new_position: Position,
+        entry_door: Door,
+        require_monster: i8,
+        player_transform: &mut Transform,
+    ) -> bool {
+        if self.dungeon.current_floor.is_locked(new_position) {
+            if self.player_dungeon_stats.keys == 0 {
+                return false;
+            }
+            self.player_dungeon_stats.keys -= 1;
+            self.dungeon
+                .current_floor
+                .unlocked_positions
+                .insert(new_position);
+        }
+
+        let previous_position = self.dungeon.current_floor.current_position;
+        let mut dropped_items: Vec<DroppedItem> = Vec::new();
+        for (item_entity, item_transform, potion, weapon_pickup) in self.dropped_items_query.iter()
+        {
+            let item_type = match (potion, weapon_pickup) {
+                (Some(potion), _) => DroppedItemType::Potion(potion.potion_type),
+                (None, Some(weapon_pickup)) => {
+                    DroppedItemType::Weapon(weapon_pickup.weapon.name.clone())
+                }
+                (None, None) => DroppedItemType::Key,
+            };
+            dropped_items.push(DroppedItem {
+                item_type,
+                x: item_transform.translation.x,
+                y: item_transform.translation.y,
+            });
+            self.commands.entity(item_entity).despawn_recursive();
+        }
+        if !dropped_items.is_empty() {
+            self.dungeon
+                .current_floor
+                .dropped_items
+                .insert(previous_position, dropped_items);
+        }
+
+        self.player_dungeon_stats.current_room_position = new_position;
+        self.dungeon.current_floor.current_position = new_position;
+
+        let room = self
+            .rooms
+            .get_room(self.dungeon.current_floor.get_room_id(new_position));
+        set_spawn_area(&mut self.monster_spawn_controller, &room);
+        self.commands.remove_resource::<ChallengeRoom>();
+
+        let room_type = self.dungeon.current_floor.get_room_type(new_position);
+
+        match self
+            .dungeon
+            .current_floor
+            .cleared_positions
+            .get(&new_position)
+        {
+            Some(total_entered_time) => {
+                self.player_dungeon_stats.is_room_cleared = true;
+                let total_entered_time = total_entered_time + 1;
+                self.dungeon
+                    .current_floor
+                    .cleared_positions
+                    .insert(new_position, total_entered_time);
+            }
+            None if room_type == RoomType::Shop
+                || room_type == RoomType::Treasure
+                || room_type == RoomType::Shrine =>
+            {
+                self.player_dungeon_stats.is_room_cleared = true;
+                self.dungeon
+                    .current_floor
+                    .cleared_positions
+                    .insert(new_position, 1);
+            }
+            None => {
+                self.player_dungeon_stats.is_room_cleared = false;
+                self.monster_spawn_controller.require_monster = require_monster;
+                self.monster_spawn_controller.killed_monsters = 0;
+                self.monster_spawn_controller.alive_monsters = 0;
+                self.dungeon
+                    .current_floor
+                    .cleared_positions
+                    .insert(new_position, 1);
+                self.profile.total_cleared_rooms += 1;
+
+                if room_type == RoomType::Challenge {
+                    self.commands
+                        .insert_resource(ChallengeRoom::new(require_monster));
+                }
+            }
+        }
+
+        let entry_translation = get_entry_translation(&room, &entry_door);
+        player_transform.translation.x = entry_translation.x;
+        player_transform.translation.y = entry_translation.y;
+
+        for pickup_entity in self.pickups_query.iter() {
+            self.commands.entity(pickup_entity).despawn_recursive();
+        }
+
+        true
+    }
+}
+
 pub fn horizontal_door_interaction_handle(
     mut player_query: Query<(&mut Transform, &TextureAtlasSprite), With<PlayerComponent>>,
-    mut door_query: Query<
-        (&Door, &Transform, &Sprite, &Visibility),
-        (With<HorizontalDoor>, Without<PlayerComponent>),
-    >,
-    mut monster_spawn_controller: ResMut<MonsterSpawnController>,
-    mut player_dungeon_stats: ResMut<PlayerDungeonStats>,
-    potion_query: Query<Entity, With<PotionComponent>>,
-    mut dungeon: ResMut<Dungeon>,
-    mut profile: ResMut<Profile>,
-    mut commands: Commands,
+    mut door_query: HorizontalDoorQuery,
+    mut room_transition: RoomTransition,
 ) {
     let (mut player_transform, player_sprite) = player_query.single_mut();
     let player_translation = player_transform.translation;
     let player_size = player_sprite.custom_size.unwrap();
 
-    if player_dungeon_stats.is_room_cleared {
-        let current_position = player_dungeon_stats.current_room_position;
-        let cleared_positions = dungeon.current_floor.cleared_positions.clone();
+    if room_transition.player_dungeon_stats.is_room_cleared {
+        let current_position = room_transition.player_dungeon_stats.current_room_position;
+        let require_monster = 4 + room_transition.player_dungeon_stats.current_floor_index as i8;
 
         for (door, door_transform, door_sprite, visibility) in door_query.iter_mut() {
             if visibility == Visibility::Inherited {
@@ -50,37 +212,19 @@ pub fn horizontal_door_interaction_handle(
                     },
                 };
 
-                player_dungeon_stats.current_room_position = new_position;
-                dungeon.current_floor.current_position = new_position;
-
-                if cleared_positions.contains_key(&new_position) {
-                    player_dungeon_stats.is_room_cleared = true;
-                    let total_entered_time = cleared_positions.get(&new_position).unwrap().clone();
-                    dungeon
-                        .current_floor
-                        .cleared_positions
-                        .insert(new_position, total_entered_time + 1);
+                let entry_door = if *door == Door::Left {
+                    Door::Right
                 } else {
-                    player_dungeon_stats.is_room_cleared = false;
-                    monster_spawn_controller.require_monster =
-                        4 + player_dungeon_stats.current_floor_index as i8;
-                    monster_spawn_controller.killed_monsters = 0;
-                    monster_spawn_controller.alive_monsters = 0;
-                    dungeon
-                        .current_floor
-                        .cleared_positions
-                        .insert(new_position, 1);
-                    profile.total_cleared_rooms += 1;
-                }
-
-                if *door == Door::Left {
-                    player_transform.translation.x = (player_transform.translation.x * -1.0) - 15.0;
-                } else if *door == Door::Right {
-                    player_transform.translation.x = (player_transform.translation.x * -1.0) + 15.0;
-                }
+                    Door::Left
+                };
 
-                for potion_entity in potion_query.iter() {
-                    commands.entity(potion_entity).despawn_recursive();
+                if room_transition.enter_room(
+                    new_position,
+                    entry_door,
+                    require_monster,
+                    &mut player_transform,
+                ) {
+                    break;
                 }
             }
         }
@@ -91,20 +235,15 @@ pub fn vertical_door_interaction_handle(
     mut player_query: Query<(&mut Transform, &TextureAtlasSprite), With<PlayerComponent>>,
     mut vertical_door_query: Query<(&Visibility, &Children), With<VerticaltDoor>>,
     mut door_query: Query<(&Door, &Transform), Without<PlayerComponent>>,
-    mut monster_spawn_controller: ResMut<MonsterSpawnController>,
-    mut player_dungeon_stats: ResMut<PlayerDungeonStats>,
-    potion_query: Query<Entity, With<PotionComponent>>,
-    mut dungeon: ResMut<Dungeon>,
-    mut profile: ResMut<Profile>,
-    mut commands: Commands,
+    mut room_transition: RoomTransition,
 ) {
     let (mut player_transform, player_spirte) = player_query.single_mut();
     let player_translation = player_transform.translation;
     let player_size = player_spirte.custom_size.unwrap().clone();
 
-    if player_dungeon_stats.is_room_cleared {
-        let current_position = player_dungeon_stats.current_room_position;
-        let cleared_positions = dungeon.current_floor.cleared_positions.clone();
+    if room_transition.player_dungeon_stats.is_room_cleared {
+        let current_position = room_transition.player_dungeon_stats.current_room_position;
+        let require_monster = 6 + room_transition.player_dungeon_stats.current_floor_index as i8;
 
         for (visibility, children) in vertical_door_query.iter_mut() {
             if visibility == Visibility::Hidden {
@@ -132,38 +271,19 @@ pub fn vertical_door_interaction_handle(
                             column_index: current_position.column_index,
                         };
 
-                        player_dungeon_stats.current_room_position = new_position;
-                        dungeon.current_floor.current_position = new_position;
-
-                        if cleared_positions.contains_key(&new_position) {
-                            player_dungeon_stats.is_room_cleared = true;
-                            let cleared_position = cleared_positions.get(&new_position);
-                            let total_entered_time = cleared_position.unwrap();
-                            dungeon
-                                .current_floor
-                                .cleared_positions
-                                .insert(new_position, total_entered_time + 1);
+                        let entry_door = if *door == Door::Top {
+                            Door::Bottom
                         } else {
-                            player_dungeon_stats.is_room_cleared = false;
-                            monster_spawn_controller.require_monster =
-                                6 + player_dungeon_stats.current_floor_index as i8;
-                            monster_spawn_controller.killed_monsters = 0;
-                            monster_spawn_controller.alive_monsters = 0;
-                            dungeon
-                                .current_floor
-                                .cleared_positions
-                                .insert(new_position, 1);
-                            profile.total_cleared_rooms += 1;
-                        }
-
-                        if *door == Door::Top {
-                            player_transform.translation.y = -130.0;
-                        } else {
-                            player_transform.translation.y = 130.0;
-                        }
+                            Door::Top
+                        };
 
-                        for potion_entity in potion_query.iter() {
-                            commands.entity(potion_entity).despawn_recursive();
+                        if room_transition.enter_room(
+                            new_position,
+                            entry_door,
+                            require_monster,
+                            &mut player_transform,
+                        ) {
+                            return;
                         }
                     }
                 }
@@ -171,3 +291,17 @@ pub fn vertical_door_interaction_handle(
         }
     }
 }
+
+fn get_entry_translation(room: &Room, entry_door: &Door) -> Vec2 {
+    let offset = match entry_door {
+        Door::Left => Vec2::new(DOOR_ENTRY_OFFSET, 0.0),
+        Door::Right => Vec2::new(-DOOR_ENTRY_OFFSET, 0.0),
+        Door::Top => Vec2::new(0.0, -DOOR_ENTRY_OFFSET),
+        Door::Bottom => Vec2::new(0.0, DOOR_ENTRY_OFFSET),
+    };
+
+    match room.get_door_translation(entry_door) {
+        Some(translation) => translation + offset,
+        None => room.get_center(),
+    }
+}
@@ -0,0 +1,359 @@
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy::sprite::collide_aabb::collide;
+use std::f32::consts::FRAC_PI_2;
+use std::time::Duration;
+
+use crate::components::hazard::HazardComponent;
+use crate::components::hazard_arrow::HazardArrowComponent;
+use crate::components::invinsible_cooldown::InvisibleCooldownComponent;
+use crate::components::monster::MonsterComponent;
+use crate::components::monster_list_effects::MonsterListEffectsComponent;
+use crate::components::player::PlayerComponent;
+use crate::components::player_animation::PlayerAnimation;
+use crate::components::player_list_effects::PlayerListEffectsComponent;
+use crate::components::prop::PropComponent;
+use crate::materials::ingame::InGameMaterials;
+use crate::plugins::classic_mode::dungeon::hazards::{
+    SPIKE_TRAP_ACTIVE_COLOR, SPIKE_TRAP_INACTIVE_COLOR,
+};
+use crate::plugins::player::collisions::damage_player;
+use crate::plugins::player::relic::RelicTriggerEvent;
+use crate::plugins::player::{PLAYER_SIZE_HEIGHT, PLAYER_SIZE_WIDTH};
+use crate::resources::death_recap::HitSource;
+use crate::resources::dungeon::hazard_type::HazardType;
+use crate::resources::dungeon::Dungeon;
+use crate::resources::effect::effect_type::EffectType;
+use crate::resources::profile::Profile;
+
+const SPIKE_TRAP_PLAYER_DAMAGE: f32 = 0.5;
+const SPIKE_TRAP_MONSTER_DAMAGE: f32 = 2.0;
+const ARROW_PLAYER_DAMAGE: f32 = 0.5;
+const ARROW_MONSTER_DAMAGE: f32 = 2.0;
+const ARROW_SPEED: f32 = 6.0;
+const ARROW_WIDTH: f32 = 7.0 * 2.0;
+const ARROW_HEIGHT: f32 = 21.0 * 2.0;
+
+type ArrowMonstersQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static mut MonsterComponent,
+        &'static mut InvisibleCooldownComponent,
+        &'static Transform,
+    ),
+    (Without<PlayerComponent>, Without<HazardArrowComponent>),
+>;
+
+type GroundHazardMonstersQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static mut MonsterComponent,
+        &'static mut MonsterListEffectsComponent,
+        &'static mut InvisibleCooldownComponent,
+        &'static Transform,
+    ),
+    (Without<PlayerComponent>, Without<HazardComponent>),
+>;
+
+// everything besides the player that a flying arrow can hit
+#[derive(SystemParam)]
+pub struct ArrowTargets<'w, 's> {
+    monsters_query: ArrowMonstersQuery<'w, 's>,
+    props_query: Query<
+        'w,
+        's,
+        (&'static mut PropComponent, &'static Transform),
+        Without<HazardArrowComponent>,
+    >,
+}
+
+pub fn spike_traps_system(
+    mut hazards_query: Query<(&mut HazardComponent, &mut Sprite)>,
+    time: Res<Time>,
+) {
+    for (mut hazard, mut sprite) in hazards_query.iter_mut() {
+        if hazard.hazard_type != HazardType::SpikeTrap {
+            continue;
+        }
+
+        hazard.timer.tick(time.delta());
+        if hazard.timer.just_finished() {
+            hazard.is_active = !hazard.is_active;
+            sprite.color = if hazard.is_active {
+                SPIKE_TRAP_ACTIVE_COLOR
+            } else {
+                SPIKE_TRAP_INACTIVE_COLOR
+            };
+        }
+    }
+}
+
+pub fn arrow_turrets_system(
+    mut hazards_query: Query<(&mut HazardComponent, &Transform)>,
+    player_query: Query<&Transform, With<PlayerComponent>>,
+    ingame_materials: Res<InGameMaterials>,
+    dungeon: Res<Dungeon>,
+    time: Res<Time>,
+    mut commands: Commands,
+) {
+    let current_floor = &dungeon.current_floor;
+    if !current_floor.is_combat_room(current_floor.current_position) {
+        return;
+    }
+
+    let player_translation = player_query.single().translation;
+
+    for (mut hazard, hazard_transform) in hazards_query.iter_mut() {
+        if hazard.hazard_type != HazardType::ArrowTurret {
+            continue;
+        }
+
+        hazard.timer.tick(time.delta());
+        if !hazard.timer.just_finished() {
+            continue;
+        }
+
+        let start = hazard_transform.translation;
+        let mut direction = player_translation - start;
+        direction.z = 0.0;
+        let direction = direction.normalize_or_zero();
+
+        if direction == Vec3::ZERO {
+            continue;
+        }
+
+        commands
+            .spawn(SpriteBundle {
+                texture: ingame_materials.dungeon_materials.arrow.clone(),
+                sprite: Sprite {
+                    custom_size: Some(Vec2::new(ARROW_WIDTH, ARROW_HEIGHT)),
+                    ..Default::default()
+                },
+                transform: Transform {
+                    translation: Vec3::new(start.x, start.y, 0.2),
+                    rotation: Quat::from_rotation_z(direction.y.atan2(direction.x) - FRAC_PI_2),
+                    ..Default::default()
+                },
+                ..Default::default()
+            })
+            .insert(HazardArrowComponent {
+                direction,
+                duration: Timer::new(Duration::from_secs(3), TimerMode::Once),
+                width: ARROW_WIDTH,
+                height: ARROW_HEIGHT,
+            })
+            .insert(Name::new("HazardArrow"));
+    }
+}
+
+pub fn hazard_arrows_system(
+    mut arrows_query: Query<(Entity, &mut HazardArrowComponent, &mut Transform)>,
+    mut player_query: Query<
+        (
+            &mut PlayerComponent,
+            &mut PlayerAnimation,
+            &mut InvisibleCooldownComponent,
+            &Transform,
+        ),
+        Without<HazardArrowComponent>,
+    >,
+    arrow_targets: ArrowTargets,
+    mut profile: ResMut<Profile>,
+    mut relic_trigger_events: EventWriter<RelicTriggerEvent>,
+    time: Res<Time>,
+    mut commands: Commands,
+) {
+    let ArrowTargets {
+        mut monsters_query,
+        mut props_query,
+    } = arrow_targets;
+    let (mut player, mut player_animation, mut player_invincible_cooldown, player_transform) =
+        player_query.single_mut();
+    let player_size = Vec2::new(PLAYER_SIZE_WIDTH, PLAYER_SIZE_HEIGHT);
+
+    for (arrow_entity, mut arrow, mut arrow_transform) in arrows_query.iter_mut() {
+        arrow.duration.tick(time.delta());
+        if arrow.duration.finished() {
+            commands.entity(arrow_entity).despawn_recursive();
+            continue;
+        }
+
+        arrow_transform.translation += arrow.direction * ARROW_SPEED;
+        let arrow_size = Vec2::new(arrow.width, arrow.height);
+
+        if collide(
+            arrow_transform.translation,
+            arrow_size,
+            player_transform.translation,
+            player_size,
+        )
+        .is_some()
+        {
+            damage_player(
+                &mut player,
+                &mut player_animation,
+                &mut player_invincible_cooldown,
+                &mut profile,
+                &mut relic_trigger_events,
+                HitSource::Hazard(HazardType::ArrowTurret),
+                ARROW_PLAYER_DAMAGE,
+            );
+            commands.entity(arrow_entity).despawn_recursive();
+            continue;
+        }
+
+        let mut is_hit = false;
+
+        for (mut monster, mut invincible_cooldown, monster_transform) in monsters_query.iter_mut() {
+            let monster_size = Vec2::new(monster.width, monster.height);
+
+            if collide(
+                arrow_transform.translation,
+                arrow_size,
+                monster_transform.translation,
+                monster_size,
+            )
+            .is_some()
+            {
+                hurt_monster(&mut monster, &mut invincible_cooldown, ARROW_MONSTER_DAMAGE);
+                commands.entity(arrow_entity).despawn_recursive();
+                is_hit = true;
+                break;
+            }
+        }
+
+        if is_hit {
+            continue;
+        }
+
+        for (mut prop, prop_transform) in props_query.iter_mut() {
+            let prop_size = Vec2::new(prop.width, prop.height);
+
+            if collide(
+                arrow_transform.translation,
+                arrow_size,
+                prop_transform.translation,
+                prop_size,
+            )
+            .is_some()
+            {
+                prop.hurt(ARROW_MONSTER_DAMAGE);
+                commands.entity(arrow_entity).despawn_recursive();
+                break;
+            }
+        }
+    }
+}
+
+pub fn ground_hazards_system(
+    hazards_query: Query<(&HazardComponent, &Transform)>,
+    mut player_query: Query<
+        (
+            &mut PlayerComponent,
+            &mut PlayerAnimation,
+            &mut PlayerListEffectsComponent,
+            &mut InvisibleCooldownComponent,
+            &Transform,
+        ),
+        Without<HazardComponent>,
+    >,
+    mut monsters_query: GroundHazardMonstersQuery,
+    dungeon: Res<Dungeon>,
+    mut profile: ResMut<Profile>,
+    mut relic_trigger_events: EventWriter<RelicTriggerEvent>,
+) {
+    let current_floor = &dungeon.current_floor;
+    if !current_floor.is_combat_room(current_floor.current_position) {
+        return;
+    }
+
+    let (
+        mut player,
+        mut player_animation,
+        mut player_list_effects,
+        mut player_invincible_cooldown,
+        player_transform,
+    ) = player_query.single_mut();
+    let player_size = Vec2::new(PLAYER_SIZE_WIDTH, PLAYER_SIZE_HEIGHT);
+
+    for (hazard, hazard_transform) in hazards_query.iter() {
+        if !hazard.is_active || hazard.hazard_type == HazardType::ArrowTurret {
+            continue;
+        }
+
+        let hazard_size = Vec2::new(hazard.width, hazard.height);
+        let hazard_position = hazard_transform.translation;
+
+        if collide(
+            player_transform.translation,
+            player_size,
+            hazard_position,
+            hazard_size,
+        )
+        .is_some()
+        {
+            match hazard.hazard_type {
+                HazardType::SpikeTrap => {
+                    damage_player(
+                        &mut player,
+                        &mut player_animation,
+                        &mut player_invincible_cooldown,
+                        &mut profile,
+                        &mut relic_trigger_events,
+                        HitSource::Hazard(HazardType::SpikeTrap),
+                        SPIKE_TRAP_PLAYER_DAMAGE,
+                    );
+                }
+                HazardType::Mud => player_list_effects.activate(&EffectType::Slow.into()),
+                HazardType::Poison => player_list_effects.activate(&EffectType::Poison.into()),
+                HazardType::ArrowTurret => {}
+            }
+        }
+
+        for (mut monster, mut monster_list_effects, mut invincible_cooldown, monster_transform) in
+            monsters_query.iter_mut()
+        {
+            let monster_size = Vec2::new(monster.width, monster.height);
+
+            if collide(
+                monster_transform.translation,
+                monster_size,
+                hazard_position,
+                hazard_size,
+            )
+            .is_some()
+            {
+                match hazard.hazard_type {
+                    HazardType::SpikeTrap => hurt_monster(
+                        &mut monster,
+                        &mut invincible_cooldown,
+                        SPIKE_TRAP_MONSTER_DAMAGE,
+                    ),
+                    HazardType::Mud => monster_list_effects.activate(EffectType::Slow),
+                    HazardType::Poison => monster_list_effects.activate(EffectType::Poison),
+                    HazardType::ArrowTurret => {}
+                }
+            }
+        }
+    }
+}
+
+fn hurt_monster(
+    monster: &mut MonsterComponent,
+    invincible_cooldown: &mut InvisibleCooldownComponent,
+    damage: f32,
+) {
+    if !invincible_cooldown.hurt_duration.finished() {
+        return;
+    }
+
+    invincible_cooldown.hurt_duration = Timer::new(Duration::from_secs_f32(0.3), TimerMode::Once);
+
+    monster.current_health_points = if damage > monster.current_health_points {
+        0.0
+    } else {
+        monster.current_health_points - damage
+    };
+}
@@ -0,0 +1,60 @@
+use bevy::prelude::*;
+use bevy::sprite::collide_aabb::collide;
+
+use crate::components::key::KeyComponent;
+use crate::components::player::PlayerComponent;
+use crate::materials::ingame::InGameMaterials;
+use crate::plugins::player::{PLAYER_SIZE_HEIGHT, PLAYER_SIZE_WIDTH};
+use crate::resources::player::player_dungeon_stats::PlayerDungeonStats;
+
+const KEY_SIZE: f32 = 16.0 * 1.5;
+const KEY_COLOR: Color = Color::rgb(1.0, 0.85, 0.2);
+
+pub fn spawn_key(commands: &mut Commands, ingame_materials: &InGameMaterials, position: Vec3) {
+    commands
+        .spawn(SpriteBundle {
+            texture: ingame_materials.relics_materials.relic.clone(),
+            sprite: Sprite {
+                custom_size: Some(Vec2::new(KEY_SIZE, KEY_SIZE)),
+                color: KEY_COLOR,
+                ..Default::default()
+            },
+            transform: Transform {
+                translation: Vec3::new(position.x, position.y, 0.15),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .insert(KeyComponent {
+            width: KEY_SIZE,
+            height: KEY_SIZE,
+        })
+        .insert(Name::new("Key"));
+}
+
+pub fn key_collision_system(
+    player_query: Query<&Transform, With<PlayerComponent>>,
+    keys_query: Query<(Entity, &KeyComponent, &Transform), Without<PlayerComponent>>,
+    mut player_dungeon_stats: ResMut<PlayerDungeonStats>,
+    mut commands: Commands,
+) {
+    let player_transform = player_query.single();
+    let player_size = Vec2::new(PLAYER_SIZE_WIDTH, PLAYER_SIZE_HEIGHT);
+    let player_position = player_transform.translation;
+
+    for (key_entity, key, key_transform) in keys_query.iter() {
+        let key_size = Vec2::new(key.width, key.height);
+
+        if collide(
+            player_position,
+            player_size,
+            key_transform.translation,
+            key_size,
+        )
+        .is_some()
+        {
+            player_dungeon_stats.keys += 1;
+            commands.entity(key_entity).despawn_recursive();
+        }
+    }
+}
@@ -1,3 +1,11 @@
+pub mod challenge;
 pub mod door;
 pub mod end_point;
+pub mod hazards;
+pub mod key;
+pub mod props;
+pub mod secret_room;
+pub mod shop;
+pub mod shrine;
+pub mod treasure;
 pub mod unlock_room;
\ No newline at end of file
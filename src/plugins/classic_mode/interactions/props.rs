@@ -0,0 +1,83 @@
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::components::player_relics::PlayerRelicsComponent;
+use crate::components::prop::PropComponent;
+use crate::materials::ingame::InGameMaterials;
+use crate::plugins::classic_mode::interactions::key;
+use crate::plugins::loot;
+use crate::resources::dungeon::Dungeon;
+use crate::resources::game_data::GameData;
+use crate::resources::prop::prop_loot::PropLootItem;
+
+const PROP_HURT_COLOR: Color = Color::rgb(1.0, 0.6, 0.6);
+const LOOT_SPREAD: f32 = 24.0;
+
+pub fn props_break_system(
+    mut props_query: Query<(Entity, &mut PropComponent, &mut Sprite, &Transform)>,
+    player_relics_query: Query<&PlayerRelicsComponent>,
+    ingame_materials: Res<InGameMaterials>,
+    game_data: Res<GameData>,
+    mut dungeon: ResMut<Dungeon>,
+    time: Res<Time>,
+    mut commands: Commands,
+) {
+    for (prop_entity, mut prop, mut sprite, transform) in props_query.iter_mut() {
+        prop.hurt_duration.tick(time.delta());
+        sprite.color = if prop.hurt_duration.finished() {
+            prop.color
+        } else {
+            PROP_HURT_COLOR
+        };
+
+        if prop.current_health_points > 0.0 {
+            continue;
+        }
+
+        commands.entity(prop_entity).despawn_recursive();
+
+        let current_position = dungeon.current_floor.current_position;
+        if let Some(placements) = dungeon.current_floor.props.get_mut(&current_position) {
+            for placement in placements.iter_mut() {
+                if placement.row_index == prop.row_index
+                    && placement.column_index == prop.column_index
+                {
+                    placement.is_broken = true;
+                }
+            }
+        }
+
+        let mut rng = rand::thread_rng();
+        let position = transform.translation;
+
+        for (index, loot) in game_data.get_prop(&prop.name).loot_table.iter().enumerate() {
+            if rng.gen_range(0.0..1.0) >= loot.chance {
+                continue;
+            }
+
+            let offset = Vec3::new((index as f32 - 1.0) * LOOT_SPREAD, -LOOT_SPREAD, 0.0);
+
+            match loot.item.clone() {
+                PropLootItem::Gold(value) => {
+                    loot::spawn_gold(&mut commands, &ingame_materials, value, position + offset)
+                }
+                PropLootItem::Potion(potion_type) => loot::spawn_potion(
+                    &mut commands,
+                    &ingame_materials,
+                    potion_type,
+                    position + offset,
+                ),
+                PropLootItem::Key => {
+                    key::spawn_key(&mut commands, &ingame_materials, position + offset)
+                }
+                PropLootItem::Relic => loot::spawn_random_relic(
+                    &mut commands,
+                    &ingame_materials,
+                    &game_data,
+                    player_relics_query.single(),
+                    position + offset,
+                ),
+            }
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use bevy::prelude::*;
+use bevy::sprite::collide_aabb::collide;
+
+use crate::components::bullet::BulletComponent;
+use crate::components::weapon::WeaponComponent;
+use crate::components::weapon_shoot_attack::WeaponShootAttackComponent;
+use crate::components::weapon_swing_attack::WeaponSwingAttackComponent;
+use crate::config::*;
+use crate::plugins::classic_mode::dungeon::walls::get_wall_side;
+use crate::resources::dungeon::current_room::CurrentRoom;
+use crate::resources::dungeon::wall::Wall;
+use crate::resources::dungeon::Dungeon;
+use crate::resources::player::player_dungeon_stats::PlayerDungeonStats;
+use crate::resources::weapon::attack_type::AttackType;
+
+type BulletsFilter = (With<BulletComponent>, Without<Wall>);
+
+pub fn secret_walls_system(
+    wall_query: Query<(&Wall, &Transform, &Visibility)>,
+    weapon_query: Query<
+        (
+            &WeaponComponent,
+            &WeaponShootAttackComponent,
+            &WeaponSwingAttackComponent,
+            &Transform,
+        ),
+        Without<Wall>,
+    >,
+    bullets_query: Query<(Entity, &Transform), BulletsFilter>,
+    current_room: Res<CurrentRoom>,
+    mut dungeon: ResMut<Dungeon>,
+    mut player_dungeon_stats: ResMut<PlayerDungeonStats>,
+    mut commands: Commands,
+) {
+    let (weapon, weapon_shoot_attack, weapon_swing_attack, weapon_transform) =
+        weapon_query.single();
+    let current_position = dungeon.current_floor.current_position;
+    let wall_size = Vec2::new(TILE_SIZE, TILE_SIZE);
+
+    let bullet_size = Vec2::new(
+        weapon_shoot_attack.bullet_information.width * weapon_shoot_attack.bullet_information.scale,
+        weapon_shoot_attack.bullet_information.height
+            * weapon_shoot_attack.bullet_information.scale,
+    );
+    let weapon_size = Vec2::new(
+        weapon.size_width * weapon.scale,
+        weapon.size_height * weapon.scale,
+    );
+
+    for (wall, wall_transform, visibility) in wall_query.iter() {
+        if *visibility == Visibility::Hidden {
+            continue;
+        }
+
+        let secret_position = match get_wall_side(wall, &current_room.room) {
+            Some(door) => match dungeon.current_floor.get_neighbour(current_position, &door) {
+                Some(neighbour) if dungeon.current_floor.is_secret(neighbour) => neighbour,
+                _ => continue,
+            },
+            None => continue,
+        };
+
+        let wall_position = wall_transform.translation;
+
+        let mut is_hit = weapon.attack_type == AttackType::Swing
+            && !weapon_swing_attack.attack_duration.finished()
+            && collide(
+                weapon_transform.translation,
+                weapon_size,
+                wall_position,
+                wall_size,
+            )
+            .is_some();
+
+        for (bullet_entity, bullet_transform) in bullets_query.iter() {
+            if collide(
+                bullet_transform.translation,
+                bullet_size,
+                wall_position,
+                wall_size,
+            )
+            .is_some()
+            {
+                commands.entity(bullet_entity).despawn_recursive();
+                is_hit = true;
+            }
+        }
+
+        if is_hit {
+            dungeon
+                .current_floor
+                .revealed_positions
+                .insert(secret_position);
+            player_dungeon_stats.set_changed();
+        }
+    }
+}
@@ -0,0 +1,473 @@
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy::sprite::collide_aabb::collide;
+
+use crate::config::*;
+use crate::components::dash::DashComponent;
+use crate::components::player::PlayerComponent;
+use crate::components::player_list_effects::PlayerListEffectsComponent;
+use crate::components::player_relics::PlayerRelicsComponent;
+use crate::components::potion_belt::PotionBeltComponent;
+use crate::components::skill::SkillComponent;
+use crate::components::weapon::WeaponComponent;
+use crate::materials::font::FontMaterials;
+use crate::materials::menu_box::MenuBoxMaterials;
+use crate::materials::scenes::ScenesMaterials;
+use crate::plugins::loot;
+use crate::resources::dictionary::Dictionary;
+use crate::scenes::reward_scene;
+use crate::resources::dungeon::shop_point::ShopPoint;
+use crate::resources::dungeon::Dungeon;
+use crate::resources::game_data::{GameData, PauseSceneData};
+use crate::resources::glossary::Glossary;
+use crate::resources::potion::potion_type::PotionType;
+use crate::resources::profile::Profile;
+use crate::resources::shop::shop_item_type::ShopItemType;
+use crate::resources::shop::ShopItem;
+use crate::resources::upgrade::upgrade_controller::UpgradeController;
+use crate::resources::upgrade::upgrade_type::UpgradeType;
+
+const BOX_TILE_SIZE: f32 = 60.0;
+const BOX_WIDTH_TILES: f32 = 8.0;
+const BOX_HEIGHT_TILES: f32 = 7.0;
+
+const BOX_ARRAY: [[i8; 8]; 7] = [
+    [0, 1, 1, 1, 1, 1, 1, 2],
+    [3, 4, 4, 4, 4, 4, 4, 5],
+    [3, 4, 4, 4, 4, 4, 4, 5],
+    [3, 4, 4, 4, 4, 4, 4, 5],
+    [3, 4, 4, 4, 4, 4, 4, 5],
+    [3, 4, 4, 4, 4, 4, 4, 5],
+    [6, 7, 7, 7, 7, 7, 7, 8],
+];
+
+const BUTTON_WIDTH: f32 = 300.0;
+const BUTTON_HEIGHT: f32 = 30.0;
+
+#[derive(Component, Clone)]
+pub enum ShopSceneButton {
+    Item(ShopItem),
+    Leave,
+}
+
+#[derive(Component)]
+pub struct ShopGoldTextComponent;
+
+#[derive(Resource)]
+pub struct ShopSceneFlag;
+
+#[derive(SystemParam)]
+pub struct ShopText<'w> {
+    font_materials: Res<'w, FontMaterials>,
+    dictionary: Res<'w, Dictionary>,
+}
+
+#[derive(SystemParam)]
+pub struct ShopCheckout<'w> {
+    upgrade_controller: Res<'w, UpgradeController>,
+    game_data: Res<'w, GameData>,
+    profile: ResMut<'w, Profile>,
+}
+
+#[derive(SystemParam)]
+pub struct ShopExit<'w> {
+    shop_scene_data: Res<'w, PauseSceneData>,
+    scenes_materials: Res<'w, ScenesMaterials>,
+}
+
+pub fn shop_interaction_handle_system(
+    scenes_materials: Res<ScenesMaterials>,
+    shop_text: ShopText,
+    game_data: Res<GameData>,
+    player_query: Query<(&Transform, &TextureAtlasSprite), With<PlayerComponent>>,
+    mut shop_point_query: Query<(&mut ShopPoint, &Transform, &Sprite), Without<PlayerComponent>>,
+    dungeon: Res<Dungeon>,
+    mut commands: Commands,
+) {
+    let current_position = dungeon.current_floor.current_position;
+    if dungeon.current_floor.shop_room_position != Some(current_position) {
+        return;
+    }
+
+    let (player_transform, player_sprite) = player_query.single();
+    let (mut shop_point, shop_point_transform, shop_point_sprite) = shop_point_query.single_mut();
+
+    let p_translation = player_transform.translation;
+    let p_size = player_sprite.custom_size.unwrap();
+    let sp_translation = shop_point_transform.translation;
+    let sp_size = shop_point_sprite.custom_size.unwrap();
+
+    if collide(p_translation, p_size, sp_translation, sp_size).is_none() {
+        shop_point.is_player_inside = false;
+        return;
+    }
+
+    if shop_point.is_player_inside {
+        return;
+    }
+    shop_point.is_player_inside = true;
+
+    let ShopText {
+        font_materials,
+        dictionary,
+    } = shop_text;
+
+    let user_interface_root = commands
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                position_type: PositionType::Absolute,
+                ..Default::default()
+            },
+            background_color: BackgroundColor(Color::NONE),
+            ..Default::default()
+        })
+        .with_children(|parent| {
+            menu_box(parent, &scenes_materials.menu_box_materials);
+            texts(parent, &font_materials, &dictionary);
+            buttons(parent, &font_materials, &dictionary, game_data.get_shop_items());
+        })
+        .insert(Name::new("ShopUI"))
+        .id();
+
+    commands.insert_resource(PauseSceneData {
+        user_interface_root,
+    });
+    commands.insert_resource(ShopSceneFlag);
+}
+
+fn menu_box(root: &mut ChildBuilder, menu_box_materials: &MenuBoxMaterials) {
+    let start_left = (WINDOW_HEIGHT * RESOLUTION - BOX_TILE_SIZE * BOX_WIDTH_TILES) / 2.0;
+    let start_top = (WINDOW_HEIGHT - BOX_TILE_SIZE * BOX_HEIGHT_TILES) / 2.0;
+
+    root.spawn(NodeBundle {
+        ..Default::default()
+    })
+    .with_children(|parent| {
+        for (row_index, row) in BOX_ARRAY.iter().enumerate() {
+            for (column_index, value) in row.iter().enumerate() {
+                let image: Handle<Image> = match value {
+                    0 => menu_box_materials.top_right.clone(),
+                    1 => menu_box_materials.top_center.clone(),
+                    2 => menu_box_materials.top_left.clone(),
+                    3 => menu_box_materials.mid_right.clone(),
+                    4 => menu_box_materials.mid_center.clone(),
+                    5 => menu_box_materials.mid_left.clone(),
+                    6 => menu_box_materials.bottom_right.clone(),
+                    7 => menu_box_materials.bottom_center.clone(),
+                    8 => menu_box_materials.bottom_left.clone(),
+                    _ => panic!("Unknown resources"),
+                };
+
+                parent.spawn(ImageBundle {
+                    image: UiImage::new(image),
+                    style: Style {
+                        position_type: PositionType::Absolute,
+                        left: Val::Px(start_left + BOX_TILE_SIZE * column_index as f32),
+                        top: Val::Px(start_top + BOX_TILE_SIZE * row_index as f32),
+                        bottom: Val::Auto,
+                        right: Val::Auto,
+                        width: Val::Px(BOX_TILE_SIZE),
+                        height: Val::Px(BOX_TILE_SIZE),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                });
+            }
+        }
+    })
+    .insert(Name::new("MenuBox"));
+}
+
+fn texts(root: &mut ChildBuilder, font_materials: &FontMaterials, dictionary: &Dictionary) {
+    let font = font_materials.get_font(dictionary.get_current_language());
+    let glossary = dictionary.get_glossary();
+
+    let left_position = WINDOW_HEIGHT * RESOLUTION / 2.0 - BUTTON_WIDTH / 2.0;
+
+    root.spawn(NodeBundle {
+        style: Style {
+            position_type: PositionType::Absolute,
+            left: Val::Px(left_position),
+            top: Val::Px(95.0),
+            width: Val::Px(BUTTON_WIDTH),
+            justify_content: JustifyContent::Center,
+            ..Default::default()
+        },
+        ..Default::default()
+    })
+    .with_children(|parent| {
+        parent.spawn(TextBundle {
+            text: Text::from_section(
+                glossary.shop_text.shop.clone(),
+                TextStyle {
+                    font: font.clone(),
+                    font_size: 40.0,
+                    color: Color::BLACK,
+                },
+            )
+            .with_alignment(TextAlignment::Center),
+            ..Default::default()
+        });
+    })
+    .insert(Name::new("ShopTitle"));
+
+    root.spawn(NodeBundle {
+        style: Style {
+            position_type: PositionType::Absolute,
+            left: Val::Px(left_position),
+            top: Val::Px(135.0),
+            width: Val::Px(BUTTON_WIDTH),
+            justify_content: JustifyContent::Center,
+            ..Default::default()
+        },
+        ..Default::default()
+    })
+    .with_children(|parent| {
+        parent
+            .spawn(TextBundle {
+                text: Text::from_section(
+                    "",
+                    TextStyle {
+                        font: font.clone(),
+                        font_size: 25.0,
+                        color: Color::DARK_GRAY,
+                    },
+                )
+                .with_alignment(TextAlignment::Center),
+                ..Default::default()
+            })
+            .insert(ShopGoldTextComponent);
+    })
+    .insert(Name::new("ShopGold"));
+}
+
+fn buttons(
+    root: &mut ChildBuilder,
+    font_materials: &FontMaterials,
+    dictionary: &Dictionary,
+    shop_items: Vec<ShopItem>,
+) {
+    let font = font_materials.get_font(dictionary.get_current_language());
+    let glossary = dictionary.get_glossary();
+
+    let left_position = WINDOW_HEIGHT * RESOLUTION / 2.0 - BUTTON_WIDTH / 2.0;
+
+    let mut shop_buttons: Vec<ShopSceneButton> = shop_items
+        .into_iter()
+        .map(ShopSceneButton::Item)
+        .collect();
+    shop_buttons.push(ShopSceneButton::Leave);
+
+    root.spawn(NodeBundle {
+        ..Default::default()
+    })
+    .with_children(|grandparent| {
+        let total_buttons = shop_buttons.len();
+
+        for (index, button) in shop_buttons.into_iter().enumerate() {
+            let value = match button {
+                ShopSceneButton::Item(ref shop_item) => {
+                    format!("{} - {}", item_name(&glossary, &shop_item.item), shop_item.price)
+                }
+                ShopSceneButton::Leave => glossary.shop_text.leave.clone(),
+            };
+
+            let top_position = if index == total_buttons - 1 {
+                440.0
+            } else {
+                175.0 + index as f32 * 32.0
+            };
+
+            grandparent
+                .spawn(ButtonBundle {
+                    style: Style {
+                        left: Val::Px(left_position),
+                        top: Val::Px(top_position),
+                        right: Val::Auto,
+                        bottom: Val::Auto,
+                        width: Val::Px(BUTTON_WIDTH),
+                        height: Val::Px(BUTTON_HEIGHT),
+                        justify_content: JustifyContent::Center,
+                        position_type: PositionType::Absolute,
+                        ..Default::default()
+                    },
+                    background_color: BackgroundColor(Color::NONE),
+                    ..Default::default()
+                })
+                .with_children(|parent| {
+                    parent.spawn(TextBundle {
+                        text: Text::from_section(
+                            value.clone(),
+                            TextStyle {
+                                font: font.clone(),
+                                font_size: 25.0,
+                                color: Color::GRAY,
+                            },
+                        )
+                        .with_alignment(TextAlignment::Center),
+                        ..Default::default()
+                    });
+                })
+                .insert(Name::new(value))
+                .insert(button);
+        }
+    })
+    .insert(Name::new("ShopItems"));
+}
+
+fn item_name(glossary: &Glossary, item: &ShopItemType) -> String {
+    match item {
+        ShopItemType::Potion(potion_type) => match potion_type {
+            PotionType::Heal => glossary.shop_text.heal_potion.clone(),
+            PotionType::SpeedUp => glossary.shop_text.speed_up_potion.clone(),
+            PotionType::EvasionUp => glossary.shop_text.evasion_up_potion.clone(),
+            PotionType::Focus => glossary.shop_text.focus_potion.clone(),
+        },
+        ShopItemType::Upgrade(upgrade_type) => {
+            let upgrade = glossary.ingame_text.upgrade.clone();
+            match upgrade_type {
+                UpgradeType::Weapon => format!("{} {}", upgrade, glossary.ingame_text.weapon),
+                UpgradeType::Stats => format!("{} {}", upgrade, glossary.ingame_text.stats),
+                UpgradeType::Skill => format!("{} {}", upgrade, glossary.ingame_text.skill),
+                UpgradeType::Effect => format!("{} {}", upgrade, glossary.ingame_text.effect),
+            }
+        }
+        ShopItemType::Relic => glossary.shop_text.relic.clone(),
+    }
+}
+
+pub fn gold_text_handle(
+    mut gold_text_query: Query<&mut Text, With<ShopGoldTextComponent>>,
+    profile: Res<Profile>,
+    dictionary: Res<Dictionary>,
+) {
+    let glossary = dictionary.get_glossary();
+    let mut text = gold_text_query.single_mut();
+    text.sections[0].value = format!("{}: {}", glossary.shop_text.gold, profile.gold);
+}
+
+pub fn button_handle_system(
+    mut button_query: Query<
+        (&Interaction, &ShopSceneButton, &Children),
+        Changed<Interaction>,
+    >,
+    mut text_query: Query<&mut Text>,
+    mut player_query: Query<(
+        &mut PlayerComponent,
+        &mut SkillComponent,
+        &mut PlayerListEffectsComponent,
+        &mut DashComponent,
+        &mut PlayerRelicsComponent,
+        &mut PotionBeltComponent,
+    )>,
+    weapon_query: Query<&WeaponComponent>,
+    shop_checkout: ShopCheckout,
+    shop_exit: ShopExit,
+    mut commands: Commands,
+) {
+    let ShopCheckout {
+        upgrade_controller,
+        game_data,
+        mut profile,
+    } = shop_checkout;
+    let ShopExit {
+        shop_scene_data,
+        scenes_materials,
+    } = shop_exit;
+
+    for (interaction, button, children) in button_query.iter_mut() {
+        let mut text = text_query.get_mut(children[0]).unwrap();
+        match *interaction {
+            Interaction::None => text.sections[0].style.color = Color::GRAY,
+            Interaction::Hovered => text.sections[0].style.color = Color::BLACK,
+            Interaction::Pressed => match button {
+                ShopSceneButton::Item(shop_item) => {
+                    if profile.gold < shop_item.price {
+                        continue;
+                    }
+
+                    let (
+                        mut player,
+                        mut player_skill,
+                        mut player_list_effects,
+                        mut player_dash,
+                        mut player_relics,
+                        mut potion_belt,
+                    ) = player_query.single_mut();
+                    let hero_class = player.class.clone();
+
+                    let is_bought = match shop_item.item {
+                        ShopItemType::Potion(potion_type) => {
+                            if potion_belt.is_full(potion_type) {
+                                false
+                            } else {
+                                potion_belt.add(potion_type);
+                                true
+                            }
+                        }
+                        ShopItemType::Upgrade(UpgradeType::Weapon) => {
+                            let weapon = weapon_query.single();
+                            let weapon_upgrades: Vec<_> = game_data
+                                .get_weapon_upgrades(hero_class, weapon.name.clone())
+                                .into_iter()
+                                .filter(|raw_weapon| {
+                                    !upgrade_controller.locked_weapons.contains(&raw_weapon.name)
+                                })
+                                .collect();
+                            if weapon_upgrades.is_empty() {
+                                false
+                            } else {
+                                close(&mut commands, &shop_scene_data);
+                                reward_scene::open_weapon_branches(
+                                    &mut commands,
+                                    &scenes_materials,
+                                    weapon_upgrades,
+                                );
+                                true
+                            }
+                        }
+                        ShopItemType::Upgrade(UpgradeType::Stats) => {
+                            let upgrade = upgrade_controller.get_stats_upgrade();
+                            player.upgrade_stats(upgrade.clone());
+                            player_dash.upgrade(upgrade);
+                            true
+                        }
+                        ShopItemType::Upgrade(UpgradeType::Effect) => {
+                            player_list_effects.upgrade(upgrade_controller.get_effect_upgrade());
+                            true
+                        }
+                        ShopItemType::Upgrade(UpgradeType::Skill) => {
+                            let skill_name = player_skill.skill.name.clone();
+                            player_skill.upgrade(upgrade_controller.get_skill_upgrade(skill_name));
+                            true
+                        }
+                        ShopItemType::Relic => {
+                            match loot::pick_random_relic(&game_data, &player_relics) {
+                                Some(relic) => {
+                                    player_relics.add(relic);
+                                    true
+                                }
+                                None => false,
+                            }
+                        }
+                    };
+
+                    if is_bought {
+                        profile.gold -= shop_item.price;
+                    }
+                }
+                ShopSceneButton::Leave => close(&mut commands, &shop_scene_data),
+            },
+        }
+    }
+}
+
+fn close(commands: &mut Commands, shop_scene_data: &PauseSceneData) {
+    commands
+        .entity(shop_scene_data.user_interface_root)
+        .despawn_recursive();
+    commands.remove_resource::<PauseSceneData>();
+    commands.remove_resource::<ShopSceneFlag>();
+}
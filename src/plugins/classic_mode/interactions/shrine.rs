@@ -0,0 +1,66 @@
+use bevy::prelude::*;
+use bevy::sprite::collide_aabb::collide;
+
+use crate::components::player::PlayerComponent;
+use crate::resources::dungeon::room_type::RoomType;
+use crate::resources::dungeon::shrine_point::ShrinePoint;
+use crate::resources::dungeon::Dungeon;
+
+pub const SHRINE_MAX_HEALTH_COST: f32 = 1.0;
+pub const SHRINE_STRENGTH_BONUS: f32 = 1.0;
+pub const SHRINE_INTELLIGENCE_BONUS: f32 = 1.0;
+
+type ShrinePointQuery<'w, 's> = Query<
+    'w,
+    's,
+    (&'static Transform, &'static Sprite),
+    (With<ShrinePoint>, Without<PlayerComponent>),
+>;
+
+pub fn shrine_interaction_handle_system(
+    mut player_query: Query<(&mut PlayerComponent, &Transform, &TextureAtlasSprite)>,
+    shrine_point_query: ShrinePointQuery,
+    mut dungeon: ResMut<Dungeon>,
+    mut keyboard_input: ResMut<Input<KeyCode>>,
+) {
+    let current_position = dungeon.current_floor.current_position;
+    if dungeon.current_floor.get_room_type(current_position) != RoomType::Shrine
+        || dungeon
+            .current_floor
+            .looted_positions
+            .contains(&current_position)
+    {
+        return;
+    }
+
+    let (mut player, player_transform, player_sprite) = player_query.single_mut();
+    let (shrine_point_transform, shrine_point_sprite) = shrine_point_query.single();
+
+    let p_translation = player_transform.translation;
+    let p_size = player_sprite.custom_size.unwrap();
+    let sp_translation = shrine_point_transform.translation;
+    let sp_size = shrine_point_sprite.custom_size.unwrap();
+
+    if collide(p_translation, p_size, sp_translation, sp_size).is_none() {
+        return;
+    }
+
+    if !keyboard_input.just_pressed(KeyCode::E)
+        || player.max_health_points <= SHRINE_MAX_HEALTH_COST
+    {
+        return;
+    }
+    keyboard_input.reset(KeyCode::E);
+
+    dungeon
+        .current_floor
+        .looted_positions
+        .insert(current_position);
+
+    player.max_health_points -= SHRINE_MAX_HEALTH_COST;
+    if player.current_health_points > player.max_health_points {
+        player.current_health_points = player.max_health_points;
+    }
+    player.strength += SHRINE_STRENGTH_BONUS;
+    player.intelligence += SHRINE_INTELLIGENCE_BONUS;
+}
@@ -0,0 +1,101 @@
+use bevy::prelude::*;
+use bevy::sprite::collide_aabb::collide;
+use rand::Rng;
+
+use crate::components::player::PlayerComponent;
+use crate::components::player_relics::PlayerRelicsComponent;
+use crate::materials::ingame::InGameMaterials;
+use crate::plugins::loot;
+use crate::resources::dungeon::room_type::RoomType;
+use crate::resources::dungeon::treasure_point::TreasurePoint;
+use crate::resources::dungeon::Dungeon;
+use crate::resources::game_data::GameData;
+use crate::resources::player::player_dungeon_stats::PlayerDungeonStats;
+
+const TREASURE_RELIC_CHANCE: f32 = 0.5;
+const LOOT_BASE_GOLD: usize = 10;
+const LOOT_GOLD_PER_FLOOR: usize = 5;
+
+type TreasurePointQuery<'w, 's> = Query<
+    'w,
+    's,
+    (&'static Transform, &'static Sprite),
+    (With<TreasurePoint>, Without<PlayerComponent>),
+>;
+
+pub fn treasure_interaction_handle_system(
+    player_query: Query<
+        (&Transform, &TextureAtlasSprite, &PlayerRelicsComponent),
+        With<PlayerComponent>,
+    >,
+    treasure_point_query: TreasurePointQuery,
+    ingame_materials: Res<InGameMaterials>,
+    game_data: Res<GameData>,
+    player_dungeon_stats: Res<PlayerDungeonStats>,
+    mut dungeon: ResMut<Dungeon>,
+    mut commands: Commands,
+) {
+    let current_position = dungeon.current_floor.current_position;
+    if dungeon.current_floor.get_room_type(current_position) != RoomType::Treasure
+        || dungeon
+            .current_floor
+            .looted_positions
+            .contains(&current_position)
+    {
+        return;
+    }
+
+    let (player_transform, player_sprite, player_relics) = player_query.single();
+    let (treasure_point_transform, treasure_point_sprite) = treasure_point_query.single();
+
+    let p_translation = player_transform.translation;
+    let p_size = player_sprite.custom_size.unwrap();
+    let tp_translation = treasure_point_transform.translation;
+    let tp_size = treasure_point_sprite.custom_size.unwrap();
+
+    if collide(p_translation, p_size, tp_translation, tp_size).is_none() {
+        return;
+    }
+
+    dungeon
+        .current_floor
+        .looted_positions
+        .insert(current_position);
+
+    spawn_room_loot(
+        &mut commands,
+        &ingame_materials,
+        &game_data,
+        player_relics,
+        player_dungeon_stats.current_floor_index,
+        tp_translation,
+        TREASURE_RELIC_CHANCE,
+    );
+}
+
+pub fn spawn_room_loot(
+    commands: &mut Commands,
+    ingame_materials: &InGameMaterials,
+    game_data: &GameData,
+    player_relics: &PlayerRelicsComponent,
+    floor_index: usize,
+    position: Vec3,
+    relic_chance: f32,
+) {
+    loot::spawn_gold(
+        commands,
+        ingame_materials,
+        LOOT_BASE_GOLD + LOOT_GOLD_PER_FLOOR * floor_index,
+        position + Vec3::new(-40.0, -40.0, 0.0),
+    );
+
+    if rand::thread_rng().gen_range(0.0..1.0) < relic_chance {
+        loot::spawn_random_relic(
+            commands,
+            ingame_materials,
+            game_data,
+            player_relics,
+            position + Vec3::new(40.0, -40.0, 0.0),
+        );
+    }
+}
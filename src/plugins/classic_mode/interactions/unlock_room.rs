@@ -1,17 +1,84 @@
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
+use rand::Rng;
 
+use crate::components::player_relics::PlayerRelicsComponent;
+use crate::materials::ingame::InGameMaterials;
+use crate::plugins::classic_mode::interactions::{key, treasure};
+use crate::plugins::player::relic::RelicTriggerEvent;
 use crate::resources::{
-    monster::monster_spawn_controller::MonsterSpawnController,
-    player::player_dungeon_stats::PlayerDungeonStats,
+    dungeon::challenge_room::ChallengeRoom, dungeon::current_room::CurrentRoom, dungeon::Dungeon,
+    game_data::GameData, monster::monster_spawn_controller::MonsterSpawnController,
+    player::player_dungeon_stats::PlayerDungeonStats, relic::relic_trigger::RelicTrigger,
 };
 
+const CHALLENGE_RELIC_CHANCE: f32 = 1.0;
+const KEY_DROP_CHANCE: f32 = 0.5;
+
+#[derive(SystemParam)]
+pub struct RoomLoot<'w, 's> {
+    player_relics_query: Query<'w, 's, &'static PlayerRelicsComponent>,
+    ingame_materials: Res<'w, InGameMaterials>,
+    game_data: Res<'w, GameData>,
+}
+
+#[derive(SystemParam)]
+pub struct ClearedRoom<'w> {
+    dungeon: Res<'w, Dungeon>,
+    current_room: Res<'w, CurrentRoom>,
+}
+
 pub fn cleared_room_check(
     monster_spawn_controller: Res<MonsterSpawnController>,
     mut player_dungeon_stats: ResMut<PlayerDungeonStats>,
+    mut relic_trigger_events: EventWriter<RelicTriggerEvent>,
+    challenge_room: Option<ResMut<ChallengeRoom>>,
+    room_loot: RoomLoot,
+    cleared_room: ClearedRoom,
+    mut commands: Commands,
 ) {
+    let RoomLoot {
+        player_relics_query,
+        ingame_materials,
+        game_data,
+    } = room_loot;
+    let ClearedRoom {
+        dungeon,
+        current_room,
+    } = cleared_room;
+
     if monster_spawn_controller.require_monster == monster_spawn_controller.killed_monsters {
         if !player_dungeon_stats.is_room_cleared {
             player_dungeon_stats.is_room_cleared = true;
+            relic_trigger_events.send(RelicTriggerEvent(RelicTrigger::OnRoomClear));
+
+            let center = current_room.room.get_center().extend(0.0);
+
+            if dungeon.current_floor.total_locked_rooms() > player_dungeon_stats.keys
+                && rand::thread_rng().gen_range(0.0..1.0) < KEY_DROP_CHANCE
+            {
+                key::spawn_key(
+                    &mut commands,
+                    &ingame_materials,
+                    center + Vec3::new(0.0, 40.0, 0.0),
+                );
+            }
+
+            if let Some(mut challenge_room) = challenge_room {
+                if !challenge_room.is_failed && !challenge_room.is_completed {
+                    challenge_room.is_completed = true;
+
+                    treasure::spawn_room_loot(
+                        &mut commands,
+                        &ingame_materials,
+                        &game_data,
+                        player_relics_query.single(),
+                        player_dungeon_stats.current_floor_index,
+                        center,
+                        CHALLENGE_RELIC_CHANCE,
+                    );
+                }
+            }
         }
     }
 }
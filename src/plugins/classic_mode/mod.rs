@@ -1,4 +1,7 @@
 use bevy::prelude::*;
+use crate::components::hazard_arrow::HazardArrowComponent;
+use crate::components::key::KeyComponent;
+use crate::resources::dungeon::challenge_room::ChallengeRoom;
 use crate::resources::game_data::PauseSceneData;
 
 use crate::scenes::SceneState;
@@ -15,6 +18,11 @@ pub struct ClassicModeData {
     pub ground: Option<Entity>,
     pub walls: Option<Entity>,
     pub end_point: Option<Entity>,
+    pub shop_point: Option<Entity>,
+    pub treasure_point: Option<Entity>,
+    pub shrine_point: Option<Entity>,
+    pub hazards: Option<Entity>,
+    pub props: Option<Entity>,
 }
 
 impl Plugin for ClassicModePlugin {
@@ -26,30 +34,72 @@ impl Plugin for ClassicModePlugin {
             dungeon::ground::ground,
             dungeon::doors::doors,
             dungeon::walls::walls,
-            dungeon::end_point::end_point
+            dungeon::end_point::end_point,
+            dungeon::shop_point::shop_point,
+            dungeon::treasure_point::treasure_point,
+            dungeon::shrine_point::shrine_point,
+            dungeon::hazards::hazards,
+            dungeon::props::props
         ));
 
         app.add_systems(Update, (
-            dungeon::doors::horizontal_doors_system,
-            dungeon::doors::vertical_doors_system,
-            dungeon::walls::temporary_walls_system,
+            dungeon::current_room::current_room_handle_system,
+            (
+                dungeon::ground::ground_handle_system,
+                dungeon::walls::walls_handle_system,
+                dungeon::doors::doors_handle_system,
+                dungeon::hazards::hazards_handle_system,
+                dungeon::props::props_handle_system,
+                dungeon::dropped_items::dropped_items_handle_system
+            ),
+            apply_deferred,
+            (
+                dungeon::walls::temporary_walls_system,
+                dungeon::doors::horizontal_doors_system,
+                dungeon::doors::vertical_doors_system
+            )
+        ).chain().run_if(in_state(SceneState::InGameClassicMode).and_then(not(resource_exists::<PauseSceneData>()))));
+
+        app.add_systems(Update, (
             dungeon::end_point::end_point_handle_system,
+            dungeon::shop_point::shop_point_handle_system,
+            dungeon::treasure_point::treasure_point_handle_system,
+            dungeon::shrine_point::shrine_point_handle_system,
             interactions::door::horizontal_door_interaction_handle,
             interactions::door::vertical_door_interaction_handle,
             interactions::end_point::end_point_interaction_handle_system,
+            interactions::shop::shop_interaction_handle_system,
+            interactions::treasure::treasure_interaction_handle_system,
+            interactions::shrine::shrine_interaction_handle_system.before(crate::plugins::input::feature::pick_up_weapon),
+            interactions::challenge::challenge_handle_system,
             interactions::unlock_room::cleared_room_check
         ).run_if(in_state(SceneState::InGameClassicMode).and_then(not(resource_exists::<PauseSceneData>()))));
 
         app.add_systems(Update, (
-            interactions::end_point::cooldown_handle,
-            interactions::end_point::collect_reward
-        ).run_if(in_state(SceneState::InGameClassicMode).and_then(resource_exists::<interactions::end_point::RewardSceneFlag>())));
+            interactions::hazards::spike_traps_system,
+            interactions::hazards::arrow_turrets_system,
+            interactions::hazards::hazard_arrows_system,
+            interactions::hazards::ground_hazards_system,
+            interactions::props::props_break_system,
+            interactions::key::key_collision_system,
+            interactions::secret_room::secret_walls_system
+        ).run_if(in_state(SceneState::InGameClassicMode).and_then(not(resource_exists::<PauseSceneData>()))));
+
+        app.add_systems(Update, (
+            interactions::shop::button_handle_system,
+            interactions::shop::gold_text_handle
+        ).run_if(in_state(SceneState::InGameClassicMode).and_then(resource_exists::<interactions::shop::ShopSceneFlag>())));
 
         app.add_systems(OnExit(SceneState::InGameClassicMode), clean_up_classic_mode);
     }
 }
 
-fn clean_up_classic_mode(mut commands: Commands, classic_mode_data: Res<ClassicModeData>) {
+fn clean_up_classic_mode(
+    mut commands: Commands,
+    classic_mode_data: Res<ClassicModeData>,
+    hazard_arrows_query: Query<Entity, With<HazardArrowComponent>>,
+    keys_query: Query<Entity, With<KeyComponent>>,
+) {
     commands
         .entity(classic_mode_data.doors.unwrap())
         .despawn_recursive();
@@ -65,4 +115,34 @@ fn clean_up_classic_mode(mut commands: Commands, classic_mode_data: Res<ClassicM
     commands
         .entity(classic_mode_data.ground.unwrap())
         .despawn_recursive();
+
+    commands
+        .entity(classic_mode_data.shop_point.unwrap())
+        .despawn_recursive();
+
+    commands
+        .entity(classic_mode_data.treasure_point.unwrap())
+        .despawn_recursive();
+
+    commands
+        .entity(classic_mode_data.shrine_point.unwrap())
+        .despawn_recursive();
+
+    commands
+        .entity(classic_mode_data.hazards.unwrap())
+        .despawn_recursive();
+
+    commands
+        .entity(classic_mode_data.props.unwrap())
+        .despawn_recursive();
+
+    for hazard_arrow_entity in hazard_arrows_query.iter() {
+        commands.entity(hazard_arrow_entity).despawn_recursive();
+    }
+
+    for key_entity in keys_query.iter() {
+        commands.entity(key_entity).despawn_recursive();
+    }
+
+    commands.remove_resource::<ChallengeRoom>();
 }
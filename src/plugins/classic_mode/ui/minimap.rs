@@ -0,0 +1,392 @@
+use bevy::prelude::*;
+
+use crate::config::*;
+use crate::materials::font::FontMaterials;
+use crate::resources::dictionary::Dictionary;
+use crate::resources::dungeon::floor::Floor;
+use crate::resources::dungeon::position::Position;
+use crate::resources::dungeon::room_type::RoomType;
+use crate::resources::dungeon::Dungeon;
+use crate::resources::player::player_dungeon_stats::PlayerDungeonStats;
+
+const MINIMAP_SIZE: f32 = 100.0;
+const MINIMAP_MAX_CELL_SIZE: f32 = 16.0;
+const MINIMAP_TOP: f32 = 45.0;
+
+const FULL_MAP_SIZE: f32 = 380.0;
+const FULL_MAP_MAX_CELL_SIZE: f32 = 60.0;
+const FULL_MAP_LEGEND_WIDTH: f32 = 200.0;
+
+const CELL_GAP_RATIO: f32 = 0.15;
+
+const CLEARED_ROOM_COLOR: Color = Color::rgb(0.7, 0.7, 0.7);
+const VISITED_ROOM_COLOR: Color = Color::rgb(0.6, 0.2, 0.2);
+const UNKNOWN_ROOM_COLOR: Color = Color::rgba(0.3, 0.3, 0.3, 0.8);
+const EXIT_ROOM_COLOR: Color = Color::rgb(0.2, 0.6, 0.9);
+const SHOP_ROOM_COLOR: Color = Color::rgb(0.9, 0.75, 0.2);
+const TREASURE_ROOM_COLOR: Color = Color::rgb(0.3, 0.75, 0.3);
+const SHRINE_ROOM_COLOR: Color = Color::rgb(0.7, 0.4, 1.0);
+const CHALLENGE_ROOM_COLOR: Color = Color::rgb(0.95, 0.45, 0.1);
+const LOCKED_ROOM_COLOR: Color = Color::rgb(0.55, 0.45, 0.1);
+const CURRENT_ROOM_BORDER_COLOR: Color = Color::WHITE;
+
+#[derive(Clone, Copy, PartialEq)]
+enum RoomState {
+    Hidden,
+    Unknown,
+    Visited,
+    Cleared,
+    Exit,
+    Shop,
+    Treasure,
+    Shrine,
+    Challenge,
+    Locked,
+}
+
+impl RoomState {
+    fn get_color(&self) -> Color {
+        match self {
+            RoomState::Hidden => Color::NONE,
+            RoomState::Unknown => UNKNOWN_ROOM_COLOR,
+            RoomState::Visited => VISITED_ROOM_COLOR,
+            RoomState::Cleared => CLEARED_ROOM_COLOR,
+            RoomState::Exit => EXIT_ROOM_COLOR,
+            RoomState::Shop => SHOP_ROOM_COLOR,
+            RoomState::Treasure => TREASURE_ROOM_COLOR,
+            RoomState::Shrine => SHRINE_ROOM_COLOR,
+            RoomState::Challenge => CHALLENGE_ROOM_COLOR,
+            RoomState::Locked => LOCKED_ROOM_COLOR,
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct MinimapComponent;
+
+#[derive(Component)]
+pub struct FullMapComponent;
+
+#[derive(Component)]
+pub struct FullMapGridComponent;
+
+pub fn minimap(root: &mut ChildBuilder) {
+    root.spawn(NodeBundle {
+        style: Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(MINIMAP_TOP),
+            right: Val::Px(10.0),
+            width: Val::Px(MINIMAP_SIZE),
+            height: Val::Px(MINIMAP_SIZE),
+            ..Default::default()
+        },
+        background_color: BackgroundColor(Color::rgba(0.0, 0.0, 0.0, 0.4)),
+        ..Default::default()
+    })
+    .insert(Name::new("Minimap"))
+    .insert(MinimapComponent);
+}
+
+pub fn full_map(root: &mut ChildBuilder, font_materials: &FontMaterials, dictionary: &Dictionary) {
+    let font = font_materials.get_font(dictionary.get_current_language());
+    let glossary = dictionary.get_glossary();
+
+    let legend = [
+        (glossary.ingame_text.current_room.clone(), Color::NONE),
+        (
+            glossary.ingame_text.cleared_room.clone(),
+            CLEARED_ROOM_COLOR,
+        ),
+        (
+            glossary.ingame_text.visited_room.clone(),
+            VISITED_ROOM_COLOR,
+        ),
+        (
+            glossary.ingame_text.unknown_room.clone(),
+            UNKNOWN_ROOM_COLOR,
+        ),
+        (glossary.ingame_text.exit_room.clone(), EXIT_ROOM_COLOR),
+        (glossary.ingame_text.shop_room.clone(), SHOP_ROOM_COLOR),
+        (
+            glossary.ingame_text.treasure_room.clone(),
+            TREASURE_ROOM_COLOR,
+        ),
+        (glossary.ingame_text.shrine_room.clone(), SHRINE_ROOM_COLOR),
+        (
+            glossary.ingame_text.challenge_room.clone(),
+            CHALLENGE_ROOM_COLOR,
+        ),
+        (glossary.ingame_text.locked_room.clone(), LOCKED_ROOM_COLOR),
+    ];
+
+    root.spawn(NodeBundle {
+        style: Style {
+            position_type: PositionType::Absolute,
+            left: Val::Px(0.0),
+            top: Val::Px(0.0),
+            width: Val::Px(WINDOW_HEIGHT * RESOLUTION),
+            height: Val::Px(WINDOW_HEIGHT),
+            ..Default::default()
+        },
+        background_color: BackgroundColor(Color::rgba(0.0, 0.0, 0.0, 0.75)),
+        visibility: Visibility::Hidden,
+        ..Default::default()
+    })
+    .with_children(|parent| {
+        parent.spawn(TextBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(40.0),
+                left: Val::Px(WINDOW_HEIGHT * RESOLUTION / 2.0 - 50.0),
+                ..Default::default()
+            },
+            text: Text::from_section(
+                glossary.ingame_text.map.clone(),
+                TextStyle {
+                    font: font.clone(),
+                    font_size: 50.0,
+                    color: Color::WHITE,
+                },
+            ),
+            ..Default::default()
+        });
+
+        parent
+            .spawn(NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px((WINDOW_HEIGHT - FULL_MAP_SIZE) / 2.0 + 20.0),
+                    left: Val::Px(
+                        (WINDOW_HEIGHT * RESOLUTION - FULL_MAP_SIZE - FULL_MAP_LEGEND_WIDTH) / 2.0,
+                    ),
+                    width: Val::Px(FULL_MAP_SIZE),
+                    height: Val::Px(FULL_MAP_SIZE),
+                    ..Default::default()
+                },
+                ..Default::default()
+            })
+            .insert(Name::new("FullMapGrid"))
+            .insert(FullMapGridComponent);
+
+        for (index, (label, color)) in legend.into_iter().enumerate() {
+            let top = (WINDOW_HEIGHT - FULL_MAP_SIZE) / 2.0 + 40.0 + 35.0 * index as f32;
+            let left =
+                (WINDOW_HEIGHT * RESOLUTION + FULL_MAP_SIZE - FULL_MAP_LEGEND_WIDTH) / 2.0 + 40.0;
+
+            parent.spawn(NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(top + 4.0),
+                    left: Val::Px(left),
+                    width: Val::Px(18.0),
+                    height: Val::Px(18.0),
+                    border: UiRect::all(Val::Px(if index == 0 { 3.0 } else { 0.0 })),
+                    ..Default::default()
+                },
+                background_color: BackgroundColor(color),
+                border_color: BorderColor(CURRENT_ROOM_BORDER_COLOR),
+                ..Default::default()
+            });
+
+            parent.spawn(TextBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(top),
+                    left: Val::Px(left + 30.0),
+                    ..Default::default()
+                },
+                text: Text::from_section(
+                    label,
+                    TextStyle {
+                        font: font.clone(),
+                        font_size: 25.0,
+                        color: Color::WHITE,
+                    },
+                ),
+                ..Default::default()
+            });
+        }
+    })
+    .insert(Name::new("FullMap"))
+    .insert(FullMapComponent);
+}
+
+pub fn toggle_full_map(
+    mut full_map_query: Query<&mut Visibility, With<FullMapComponent>>,
+    mut keyboard_input: ResMut<Input<KeyCode>>,
+) {
+    if keyboard_input.just_pressed(KeyCode::Tab) {
+        let mut visibility = full_map_query.single_mut();
+        *visibility = match *visibility {
+            Visibility::Hidden => Visibility::Visible,
+            _ => Visibility::Hidden,
+        };
+        keyboard_input.reset(KeyCode::Tab);
+    }
+}
+
+pub fn map_handle(
+    minimap_query: Query<Entity, With<MinimapComponent>>,
+    full_map_grid_query: Query<Entity, With<FullMapGridComponent>>,
+    dungeon: Res<Dungeon>,
+    player_dungeon_stats: Res<PlayerDungeonStats>,
+    mut commands: Commands,
+) {
+    if !dungeon.is_changed() && !player_dungeon_stats.is_changed() {
+        return;
+    }
+
+    let floor = &dungeon.current_floor;
+    let is_room_cleared = player_dungeon_stats.is_room_cleared;
+
+    let minimap_entity = minimap_query.single();
+    commands
+        .entity(minimap_entity)
+        .despawn_descendants()
+        .with_children(|parent| {
+            map_cells(
+                parent,
+                floor,
+                is_room_cleared,
+                MINIMAP_SIZE,
+                MINIMAP_MAX_CELL_SIZE,
+            );
+        });
+
+    let full_map_grid_entity = full_map_grid_query.single();
+    commands
+        .entity(full_map_grid_entity)
+        .despawn_descendants()
+        .with_children(|parent| {
+            map_cells(
+                parent,
+                floor,
+                is_room_cleared,
+                FULL_MAP_SIZE,
+                FULL_MAP_MAX_CELL_SIZE,
+            );
+        });
+}
+
+fn map_cells(
+    root: &mut ChildBuilder,
+    floor: &Floor,
+    is_room_cleared: bool,
+    size: f32,
+    max_cell_size: f32,
+) {
+    let cell_size = max_cell_size.min(size / floor.total_rows.max(floor.total_columns) as f32);
+    let gap = cell_size * CELL_GAP_RATIO;
+    let start_left = (size - cell_size * floor.total_columns as f32) / 2.0;
+    let start_top = (size - cell_size * floor.total_rows as f32) / 2.0;
+
+    for row_index in 0..floor.total_rows {
+        for column_index in 0..floor.total_columns {
+            let position = Position {
+                row_index,
+                column_index,
+            };
+
+            let room_state = get_room_state(floor, position, is_room_cleared);
+            if room_state == RoomState::Hidden {
+                continue;
+            }
+
+            let is_current_room = position == floor.current_position;
+
+            root.spawn(NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(start_left + cell_size * column_index as f32 + gap / 2.0),
+                    top: Val::Px(start_top + cell_size * row_index as f32 + gap / 2.0),
+                    width: Val::Px(cell_size - gap),
+                    height: Val::Px(cell_size - gap),
+                    border: UiRect::all(Val::Px(if is_current_room {
+                        (cell_size * 0.15).max(2.0)
+                    } else {
+                        0.0
+                    })),
+                    ..Default::default()
+                },
+                background_color: BackgroundColor(room_state.get_color()),
+                border_color: BorderColor(CURRENT_ROOM_BORDER_COLOR),
+                ..Default::default()
+            });
+        }
+    }
+}
+
+fn get_room_state(floor: &Floor, position: Position, is_room_cleared: bool) -> RoomState {
+    if !is_room(floor, position) {
+        return RoomState::Hidden;
+    }
+
+    let is_visited = floor.cleared_positions.contains_key(&position);
+    let is_known = is_visited
+        || get_neighbours(floor, position)
+            .iter()
+            .any(|neighbour| floor.cleared_positions.contains_key(neighbour));
+
+    if !is_known {
+        return RoomState::Hidden;
+    }
+
+    if position == floor.end_room_position {
+        return RoomState::Exit;
+    }
+
+    if floor.is_locked(position) {
+        return RoomState::Locked;
+    }
+
+    match floor.get_room_type(position) {
+        RoomType::Shop => RoomState::Shop,
+        RoomType::Treasure => RoomState::Treasure,
+        RoomType::Shrine => RoomState::Shrine,
+        RoomType::Challenge => RoomState::Challenge,
+        RoomType::Normal => {
+            if !is_visited {
+                RoomState::Unknown
+            } else if position == floor.current_position && !is_room_cleared {
+                RoomState::Visited
+            } else {
+                RoomState::Cleared
+            }
+        }
+    }
+}
+
+fn is_room(floor: &Floor, position: Position) -> bool {
+    floor.has_room(position)
+}
+
+fn get_neighbours(floor: &Floor, position: Position) -> Vec<Position> {
+    let mut neighbours = Vec::new();
+
+    if position.row_index > 0 {
+        neighbours.push(Position {
+            row_index: position.row_index - 1,
+            column_index: position.column_index,
+        });
+    }
+    if position.row_index + 1 < floor.total_rows {
+        neighbours.push(Position {
+            row_index: position.row_index + 1,
+            column_index: position.column_index,
+        });
+    }
+    if position.column_index > 0 {
+        neighbours.push(Position {
+            row_index: position.row_index,
+            column_index: position.column_index - 1,
+        });
+    }
+    if position.column_index + 1 < floor.total_columns {
+        neighbours.push(Position {
+            row_index: position.row_index,
+            column_index: position.column_index + 1,
+        });
+    }
+
+    neighbours
+}
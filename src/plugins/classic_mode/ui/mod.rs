@@ -3,10 +3,18 @@ use std::time::Duration;
 
 use crate::materials::font::FontMaterials;
 use crate::resources::dictionary::Dictionary;
+use crate::plugins::classic_mode::interactions::shrine::{
+    SHRINE_INTELLIGENCE_BONUS, SHRINE_MAX_HEALTH_COST, SHRINE_STRENGTH_BONUS,
+};
+use crate::resources::dungeon::challenge_room::{ChallengeCondition, ChallengeRoom};
+use crate::resources::dungeon::room_type::RoomType;
+use crate::resources::dungeon::Dungeon;
 use crate::resources::player::player_dungeon_stats::PlayerDungeonStats;
 use crate::resources::game_data::PauseSceneData;
 use crate::scenes::SceneState;
 
+mod minimap;
+
 pub struct ClassicModeUIPlugin;
 
 #[derive(Component)]
@@ -17,6 +25,15 @@ pub struct CenterTextComponent {
 #[derive(Component)]
 struct FloorTextComponent;
 
+#[derive(Component)]
+struct ChallengeTextComponent;
+
+#[derive(Component)]
+struct KeysTextComponent;
+
+#[derive(Component)]
+struct ShrineTextComponent;
+
 #[derive(Resource)]
 struct ClassicModeUIData {
     pub user_interface_root: Entity,
@@ -28,7 +45,12 @@ impl Plugin for ClassicModeUIPlugin {
 
         app.add_systems(Update, (
             center_text_handle_system,
-            top_right_conner_text_handle_system
+            top_right_conner_text_handle_system,
+            challenge_text_handle_system,
+            shrine_text_handle_system,
+            keys_text_handle_system,
+            minimap::toggle_full_map,
+            minimap::map_handle,
         ).run_if(in_state(SceneState::InGameClassicMode).and_then(not(resource_exists::<PauseSceneData>()))));
 
         app.add_systems(OnExit(SceneState::InGameClassicMode), cleanup);
@@ -53,6 +75,11 @@ fn setup(mut commands: Commands, font_materials: Res<FontMaterials>, dictionary:
         .with_children(|parent| {
             center_text(parent, &font_materials, &dictionary);
             floor_text(parent, &font_materials, &dictionary);
+            challenge_text(parent, &font_materials, &dictionary);
+            shrine_text(parent, &font_materials, &dictionary);
+            keys_text(parent, &font_materials, &dictionary);
+            minimap::minimap(parent);
+            minimap::full_map(parent, &font_materials, &dictionary);
         })
         .insert(Name::new("ClassicModeUI"))
         .id();
@@ -159,3 +186,152 @@ fn top_right_conner_text_handle_system(
         text.sections[0].value = (player_dungeon_stats.current_floor_index + 1).to_string();
     }
 }
+
+fn challenge_text(root: &mut ChildBuilder, font_materials: &FontMaterials, dictionary: &Dictionary) {
+    let font = font_materials.get_font(dictionary.get_current_language());
+    root.spawn(TextBundle {
+        style: Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(10.0),
+            ..Default::default()
+        },
+        text: Text::from_section(
+            "",
+            TextStyle {
+                font: font.clone(),
+                font_size: 30.0,
+                color: Color::WHITE,
+            }
+        ).with_alignment(
+            TextAlignment::Center
+        ),
+        visibility: Visibility::Hidden,
+        ..Default::default()
+    })
+    .insert(ChallengeTextComponent)
+    .insert(Name::new("ChallengeText"));
+}
+
+fn challenge_text_handle_system(
+    mut text_query: Query<(&mut Text, &mut Visibility), With<ChallengeTextComponent>>,
+    challenge_room: Option<Res<ChallengeRoom>>,
+    dictionary: Res<Dictionary>,
+) {
+    let (mut text, mut visibility) = text_query.single_mut();
+
+    match challenge_room {
+        Some(challenge_room) => {
+            let glossary = dictionary.get_glossary();
+
+            text.sections[0].value = if challenge_room.is_completed {
+                glossary.ingame_text.challenge_completed.clone()
+            } else if challenge_room.is_failed {
+                glossary.ingame_text.challenge_failed.clone()
+            } else {
+                match challenge_room.condition {
+                    ChallengeCondition::TimeLimit => format!(
+                        "{} {}s",
+                        glossary.ingame_text.challenge_time_limit.clone(),
+                        challenge_room.timer.remaining_secs().ceil()
+                    ),
+                    ChallengeCondition::NoHit => glossary.ingame_text.challenge_no_hit.clone(),
+                }
+            };
+            *visibility = Visibility::Visible;
+        }
+        None => *visibility = Visibility::Hidden,
+    }
+}
+
+fn shrine_text(root: &mut ChildBuilder, font_materials: &FontMaterials, dictionary: &Dictionary) {
+    let font = font_materials.get_font(dictionary.get_current_language());
+    let glossary = dictionary.get_glossary();
+
+    let value = format!(
+        "{}: -{} {}, +{} {}, +{} {}",
+        glossary.ingame_text.shrine_offer,
+        SHRINE_MAX_HEALTH_COST,
+        glossary.upgrade_text.max_health,
+        SHRINE_STRENGTH_BONUS,
+        glossary.ingame_text.strength,
+        SHRINE_INTELLIGENCE_BONUS,
+        glossary.ingame_text.intelligence
+    );
+
+    root.spawn(TextBundle {
+        style: Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(10.0),
+            ..Default::default()
+        },
+        text: Text::from_section(
+            value,
+            TextStyle {
+                font: font.clone(),
+                font_size: 30.0,
+                color: Color::WHITE,
+            }
+        ).with_alignment(
+            TextAlignment::Center
+        ),
+        visibility: Visibility::Hidden,
+        ..Default::default()
+    })
+    .insert(ShrineTextComponent)
+    .insert(Name::new("ShrineText"));
+}
+
+fn shrine_text_handle_system(
+    mut text_query: Query<&mut Visibility, With<ShrineTextComponent>>,
+    dungeon: Res<Dungeon>,
+) {
+    let mut visibility = text_query.single_mut();
+    let current_floor = &dungeon.current_floor;
+    let current_position = current_floor.current_position;
+
+    *visibility = if current_floor.get_room_type(current_position) == RoomType::Shrine
+        && !current_floor.looted_positions.contains(&current_position)
+    {
+        Visibility::Visible
+    } else {
+        Visibility::Hidden
+    };
+}
+
+fn keys_text(root: &mut ChildBuilder, font_materials: &FontMaterials, dictionary: &Dictionary) {
+    let font = font_materials.get_font(dictionary.get_current_language());
+    let glossary = dictionary.get_glossary();
+
+    root.spawn(TextBundle {
+        style: Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(150.0),
+            right: Val::Px(10.0),
+            ..Default::default()
+        },
+        text: Text::from_section(
+            format!("{}: 0", glossary.ingame_text.keys),
+            TextStyle {
+                font: font.clone(),
+                font_size: 25.0,
+                color: Color::WHITE,
+            }
+        ),
+        ..Default::default()
+    })
+    .insert(KeysTextComponent)
+    .insert(Name::new("KeysText"));
+}
+
+fn keys_text_handle_system(
+    mut text_query: Query<&mut Text, With<KeysTextComponent>>,
+    player_dungeon_stats: Res<PlayerDungeonStats>,
+    dictionary: Res<Dictionary>,
+) {
+    if player_dungeon_stats.is_changed() {
+        let mut text = text_query.single_mut();
+        let glossary = dictionary.get_glossary();
+
+        text.sections[0].value = format!("{}: {}", glossary.ingame_text.keys, player_dungeon_stats.keys);
+    }
+}
@@ -4,7 +4,7 @@ use crate::components::player::PlayerComponent;
 use crate::components::skill::SkillComponent;
 use crate::resources::dungeon::Dungeon;
 use crate::resources::player::player_dungeon_stats::PlayerDungeonStats;
-use crate::resources::skill::skill_type::SkillType;
+use crate::resources::skill::skill_trigger::SkillTrigger;
 
 pub fn unlock_room_cheat(
     mut player_dungeon_stats: ResMut<PlayerDungeonStats>,
@@ -28,7 +28,7 @@ pub fn knight_skill_cheat(
 ) {
     if keyboard_input.pressed(KeyCode::M) {
         let mut player_skill = player_skill_query.single_mut();
-        if player_skill.skill.name == SkillType::Armor {
+        if player_skill.skill.trigger == SkillTrigger::KillCounter {
             player_skill.monster_counter += 1;
         }
         keyboard_input.reset(KeyCode::M);
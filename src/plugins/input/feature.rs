@@ -1,66 +1,52 @@
 use bevy::prelude::*;
+use bevy::sprite::collide_aabb::collide;
 use rand::Rng;
 use std::time::Duration;
 
-use crate::components::invinsible_cooldown::InvisibleCooldownComponent;
-use crate::components::monster::MonsterComponent;
-use crate::components::monster_list_effects::MonsterListEffectsComponent;
 use crate::components::player::PlayerComponent;
 use crate::components::player_animation::PlayerAnimation;
 use crate::components::player_list_effects::PlayerListEffectsComponent;
+use crate::components::potion_belt::{PotionBeltComponent, POTION_BELT_SLOTS};
 use crate::components::skill::SkillComponent;
 use crate::components::weapon::WeaponComponent;
+use crate::components::weapon_inventory::WeaponInventoryComponent;
+use crate::components::weapon_pickup::WeaponPickupComponent;
 use crate::components::weapon_shoot_attack::WeaponShootAttackComponent;
 use crate::components::weapon_swing_attack::WeaponSwingAttackComponent;
+use crate::materials::ingame::InGameMaterials;
+use crate::plugins::player::collisions;
+use crate::plugins::player::skill;
+use crate::plugins::player::skill::SkillTargetsQuery;
+use crate::plugins::player::{PLAYER_SIZE_HEIGHT, PLAYER_SIZE_WIDTH};
+use crate::plugins::weapon::inventory;
 use crate::resources::animation_state::AnimationState;
-use crate::resources::effect::effect_type::EffectType;
-use crate::resources::skill::skill_type::SkillType;
+use crate::resources::game_data::GameData;
+use crate::resources::potion::potion_type::PotionType;
+use crate::resources::skill::skill_trigger::SkillTrigger;
 use crate::resources::weapon::attack_type::AttackType;
-use crate::resources::weapon::weapon_type::WeaponType;
 
 pub fn use_skill(
-    mut player_query: Query<(&mut PlayerComponent, &mut SkillComponent)>,
-    mut monsters_query: Query<(&mut MonsterComponent, &mut InvisibleCooldownComponent, &mut MonsterListEffectsComponent)>,
+    mut player_query: Query<(&mut PlayerComponent, &mut SkillComponent, &Transform)>,
+    mut monsters_query: SkillTargetsQuery,
     mut keyboard_input: ResMut<Input<KeyCode>>,
+    game_data: Res<GameData>,
 ) {
     if keyboard_input.pressed(KeyCode::Space) {
-        let (mut player, mut player_skill) = player_query.single_mut();
+        let (mut player, mut player_skill, player_transform) = player_query.single_mut();
 
-        if player_skill.cooldown.finished() {
-            let skill = player_skill.skill.clone();
+        if player_skill.cooldown.finished() && player_skill.skill.trigger == SkillTrigger::Manual {
+            let activated = skill::activate(
+                &mut player,
+                &mut player_skill,
+                player_transform.translation,
+                &mut monsters_query,
+                &game_data,
+            );
 
-            match player_skill.skill.name {
-                SkillType::Thunderstorm => {
-                    for (mut monster, mut invincible_cooldown, mut monster_list_effects) in monsters_query.iter_mut()
-                    {
-                        let damage = player.intelligence;
-                        monster.current_health_points = if monster.current_health_points < damage { 0.0 } 
-                        else { monster.current_health_points - damage };
-
-                        invincible_cooldown.hurt_duration = Timer::new(Duration::from_secs_f32(0.2), TimerMode::Once);
-                        monster_list_effects.activate(EffectType::Stun);
-                    }
-                }
-                SkillType::TimeToHunt => {
-                    let duration = skill.duration.unwrap() as u64;
-                    player_skill.duration = Timer::new(Duration::from_secs(duration), TimerMode::Once);
-
-                  
-                }
-                SkillType::AnimalInstinct => {
-                    let require_health = skill.require_health_points.unwrap();
-                    if player.current_health_points > require_health {
-                        player.current_health_points -= require_health;
-
-                        let duration = skill.duration.unwrap() as u64;
-                        player_skill.duration = Timer::new(Duration::from_secs(duration), TimerMode::Once);
-                    }
-                }
-                _ => {}
+            if activated {
+                let cooldown = player_skill.skill.cooldown.expect("No skill received. Try archer :)") as u64;
+                player_skill.cooldown = Timer::new(Duration::from_secs(cooldown), TimerMode::Once);
             }
-
-            let cooldown = skill.cooldown.expect("No skill received. Try archer :)") as u64;
-            player_skill.cooldown = Timer::new(Duration::from_secs(cooldown), TimerMode::Once);
         }
         keyboard_input.reset(KeyCode::Space);
     }
@@ -90,7 +76,7 @@ pub fn use_mouse(
             }
             AttackType::Shoot => {
                 if weapon_shoot_attack.cooldown.finished() {
-                    if weapon_component.name == WeaponType::Spear
+                    if weapon_component.shoot_while_moving
                         || player_animation.animation_state == AnimationState::Idle
                     {
                         weapon_shoot_attack.spawn_bullet = true;
@@ -100,12 +86,13 @@ pub fn use_mouse(
                         );
                     }
 
-                    if weapon_component.name == WeaponType::Spear {
+                    if let Some(effect_id) = &weapon_component.effect {
                         let mut player_list_effects = player_list_effects_query.single_mut();
-                        let buff_effect = weapon_component.buff_effect.unwrap();
                         let mut rng = rand::thread_rng();
-                        if rng.gen_range(0.0..1.0) < weapon_component.trigger_chance {
-                            player_list_effects.activate(buff_effect);
+                        if player_list_effects.get(effect_id).effect_type.is_buff()
+                            && rng.gen_range(0.0..1.0) < weapon_component.trigger_chance
+                        {
+                            player_list_effects.activate(effect_id);
                         }
                     }
                 }
@@ -114,3 +101,108 @@ pub fn use_mouse(
         buttons.clear_just_pressed(MouseButton::Left);
     }
 }
+
+pub fn swap_weapon(
+    mut player_query: Query<&mut WeaponInventoryComponent>,
+    mut weapon_query: Query<(
+        &mut WeaponComponent,
+        &mut WeaponSwingAttackComponent,
+        &mut WeaponShootAttackComponent,
+    )>,
+    mut keyboard_input: ResMut<Input<KeyCode>>,
+) {
+    if keyboard_input.just_pressed(KeyCode::Q) {
+        let mut weapon_inventory = player_query.single_mut();
+
+        if weapon_inventory.weapons.len() > 1 {
+            let (mut weapon, mut swing_attack, mut shoot_attack) = weapon_query.single_mut();
+            weapon_inventory.next();
+            inventory::equip(
+                &mut weapon,
+                &mut swing_attack,
+                &mut shoot_attack,
+                &weapon_inventory.current_weapon(),
+            );
+        }
+        keyboard_input.reset(KeyCode::Q);
+    }
+}
+
+pub fn pick_up_weapon(
+    mut commands: Commands,
+    mut player_query: Query<(&mut WeaponInventoryComponent, &Transform), With<PlayerComponent>>,
+    weapon_pickups_query: Query<
+        (Entity, &WeaponPickupComponent, &Transform),
+        Without<PlayerComponent>,
+    >,
+    mut weapon_query: Query<(
+        &mut WeaponComponent,
+        &mut WeaponSwingAttackComponent,
+        &mut WeaponShootAttackComponent,
+    )>,
+    ingame_materials: Res<InGameMaterials>,
+    mut keyboard_input: ResMut<Input<KeyCode>>,
+) {
+    if keyboard_input.just_pressed(KeyCode::E) {
+        let (mut weapon_inventory, player_transform) = player_query.single_mut();
+        let player_size = Vec2::new(PLAYER_SIZE_WIDTH, PLAYER_SIZE_HEIGHT);
+        let player_position = player_transform.translation;
+
+        for (pickup_entity, weapon_pickup, pickup_transform) in weapon_pickups_query.iter() {
+            let pickup_size = Vec2::new(weapon_pickup.width, weapon_pickup.height);
+            let pickup_position = pickup_transform.translation;
+
+            if collide(player_position, player_size, pickup_position, pickup_size).is_some() {
+                if weapon_inventory.is_full() {
+                    let dropped_weapon = weapon_inventory.replace_current(weapon_pickup.weapon.clone());
+                    inventory::spawn_weapon_pickup(
+                        &mut commands,
+                        &ingame_materials,
+                        dropped_weapon,
+                        pickup_position,
+                    );
+                } else {
+                    weapon_inventory.add(weapon_pickup.weapon.clone());
+                }
+
+                let (mut weapon, mut swing_attack, mut shoot_attack) = weapon_query.single_mut();
+                inventory::equip(
+                    &mut weapon,
+                    &mut swing_attack,
+                    &mut shoot_attack,
+                    &weapon_inventory.current_weapon(),
+                );
+
+                commands.entity(pickup_entity).despawn_recursive();
+                break;
+            }
+        }
+        keyboard_input.reset(KeyCode::E);
+    }
+}
+
+const POTION_KEYS: [KeyCode; 4] = [KeyCode::Key1, KeyCode::Key2, KeyCode::Key3, KeyCode::Key4];
+
+pub fn use_potion(
+    mut player_query: Query<(
+        &mut PlayerComponent,
+        &mut PlayerListEffectsComponent,
+        &mut PotionBeltComponent,
+    )>,
+    mut keyboard_input: ResMut<Input<KeyCode>>,
+) {
+    for (index, key_code) in POTION_KEYS.iter().enumerate() {
+        if keyboard_input.just_pressed(*key_code) {
+            let (mut player, mut player_list_effects, mut potion_belt) = player_query.single_mut();
+            let potion_type = POTION_BELT_SLOTS[index];
+
+            let is_wasted = potion_type == PotionType::Heal
+                && player.current_health_points >= player.max_health_points;
+
+            if !is_wasted && potion_belt.take(potion_type) {
+                collisions::drink_potion(&mut player, &mut player_list_effects, potion_type);
+            }
+            keyboard_input.reset(*key_code);
+        }
+    }
+}
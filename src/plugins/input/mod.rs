@@ -23,8 +23,11 @@ impl Plugin for InputHandlePlugin {
             feature::use_skill,
             crate::scenes::pause_scene::pause,
             feature::use_mouse,
+            feature::swap_weapon,
+            feature::pick_up_weapon,
+            feature::use_potion,
             movement::player_movement_handle_system.after(crate::plugins::player::stats::update_stats)
-        ).run_if(in_state(SceneState::InGameClassicMode).or_else(in_state(SceneState::InGameSurvivalMode)).and_then(not(resource_exists::<PauseSceneData>())))
+        ).run_if(in_state(SceneState::InGameClassicMode).or_else(in_state(SceneState::InGameSurvivalMode)).and_then(not(resource_exists::<PauseSceneData>())).and_then(not(resource_exists::<crate::plugins::player::health::DeathSequence>())))
         );
 
         app.add_systems(Update, crate::scenes::pause_scene::button_handle_system.run_if(
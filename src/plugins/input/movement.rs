@@ -1,52 +1,115 @@
 use bevy::prelude::*;
+use std::time::Duration;
 
+use crate::components::dash::DashComponent;
+use crate::components::invinsible_cooldown::InvisibleCooldownComponent;
 use crate::components::player::PlayerComponent;
 use crate::config::*;
-use crate::plugins::player::collisions::wall_collision_check;
+use crate::components::prop::PropComponent;
+use crate::plugins::player::collisions::{prop_collision_check, wall_collision_check};
+use crate::plugins::player::{PLAYER_SIZE_HEIGHT, PLAYER_SIZE_WIDTH};
 use crate::resources::animation_state::AnimationState;
 use crate::resources::dungeon::block_type::BlockType;
 use crate::components::player_animation::PlayerAnimation;
 
+const MAX_MOVEMENT_STEP: f32 = TILE_SIZE / 4.0;
+
 pub fn player_movement_handle_system(
-    mut player_query: Query<(&PlayerComponent, &mut PlayerAnimation, &mut Transform)>,
+    mut player_query: Query<(
+        &PlayerComponent,
+        &mut PlayerAnimation,
+        &mut DashComponent,
+        &mut InvisibleCooldownComponent,
+        &mut Transform,
+    )>,
     block_type_query: Query<(&BlockType, &Transform), Without<PlayerComponent>>,
+    props_query: Query<(&PropComponent, &Transform), Without<PlayerComponent>>,
     keyboard_input: Res<Input<KeyCode>>,
     time: Res<Time>,
 ) {
-    let (player_stats, mut player_animation, mut transform) = player_query.single_mut();
+    let (player_stats, mut player_animation, mut dash, mut invincible_cooldown, mut transform) =
+        player_query.single_mut();
 
-    let mut delta = Vec3::new(0.0, 0.0, 0.0);
+    let mut direction = Vec3::new(0.0, 0.0, 0.0);
 
-    let player_position = transform.translation;
     player_animation.animation_state = AnimationState::Idle;
 
-    let player_availalbe_movement = wall_collision_check(player_position, &block_type_query);
-
     if keyboard_input.pressed(KeyCode::W) {
-        if player_availalbe_movement.can_move_up {
-            delta.y += player_stats.speed * TILE_SIZE * time.delta_seconds();
-        }
+        direction.y += 1.0;
     }
 
     if keyboard_input.pressed(KeyCode::S) {
-        if player_availalbe_movement.can_move_down {
-            delta.y -= player_stats.speed * TILE_SIZE * time.delta_seconds();
-        }
+        direction.y -= 1.0;
     }
 
     if keyboard_input.pressed(KeyCode::A) {
-        if player_availalbe_movement.can_move_left {
-            delta.x -= player_stats.speed * TILE_SIZE * time.delta_seconds();
-        }
+        direction.x -= 1.0;
     }
 
     if keyboard_input.pressed(KeyCode::D) {
-        if player_availalbe_movement.can_move_right {
-            delta.x += player_stats.speed * TILE_SIZE * time.delta_seconds();
+        direction.x += 1.0;
+    }
+
+    if keyboard_input.just_pressed(KeyCode::ShiftLeft) && dash.cooldown.finished() {
+        if direction == Vec3::ZERO {
+            direction.x = if transform.rotation == Quat::default() { 1.0 } else { -1.0 };
         }
+
+        dash.start(direction);
+        let dash_invincible_duration = Duration::from_secs_f32(dash.dash.invincible_duration);
+        if invincible_cooldown.duration.remaining() < dash_invincible_duration {
+            invincible_cooldown.duration = Timer::new(dash_invincible_duration, TimerMode::Once);
+        }
+    }
+
+    let distance = if dash.duration.finished() {
+        player_stats.speed * TILE_SIZE * time.delta_seconds()
+    } else {
+        direction = dash.direction;
+        player_stats.speed * dash.dash.speed_multiplier * TILE_SIZE * time.delta_seconds()
+    };
+
+    // A dash covers several tiles per frame, walls are checked on every step so it can't skip one
+    let steps = (distance / MAX_MOVEMENT_STEP).ceil().max(1.0);
+    let step_distance = distance / steps;
+    let start_position = transform.translation;
+
+    for _ in 0..steps as usize {
+        let player_position = transform.translation;
+        let player_availalbe_movement = wall_collision_check(player_position, &block_type_query);
+        let mut step = Vec3::new(0.0, 0.0, 0.0);
+
+        if direction.y > 0.0 && player_availalbe_movement.can_move_up {
+            step.y += step_distance * direction.y.abs();
+        }
+
+        if direction.y < 0.0 && player_availalbe_movement.can_move_down {
+            step.y -= step_distance * direction.y.abs();
+        }
+
+        if direction.x < 0.0 && player_availalbe_movement.can_move_left {
+            step.x -= step_distance * direction.x.abs();
+        }
+
+        if direction.x > 0.0 && player_availalbe_movement.can_move_right {
+            step.x += step_distance * direction.x.abs();
+        }
+
+        let step = prop_collision_check(
+            player_position,
+            Vec2::new(PLAYER_SIZE_WIDTH, PLAYER_SIZE_HEIGHT),
+            step,
+            &props_query,
+        );
+
+        if step == Vec3::ZERO {
+            break;
+        }
+
+        transform.translation += step;
     }
 
-    transform.translation += delta;
+    let delta = transform.translation - start_position;
 
     if delta.x < 0.0 {
         transform.rotation = Quat::from_rotation_y(std::f32::consts::PI);
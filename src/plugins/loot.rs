@@ -0,0 +1,105 @@
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::components::gold::GoldComponent;
+use crate::components::player_relics::PlayerRelicsComponent;
+use crate::components::potion::PotionComponent;
+use crate::materials::ingame::InGameMaterials;
+use crate::plugins::player::relic;
+use crate::resources::game_data::GameData;
+use crate::resources::potion::potion_type::PotionType;
+use crate::resources::relic::Relic;
+
+const GOLD_SIZE: f32 = 16.0 * 1.5;
+const POTION_SIZE: f32 = 16.0 * 2.0;
+
+pub fn spawn_gold(
+    commands: &mut Commands,
+    ingame_materials: &InGameMaterials,
+    value: usize,
+    position: Vec3,
+) {
+    commands
+        .spawn(SpriteBundle {
+            texture: ingame_materials.shop_materials.coin.clone(),
+            sprite: Sprite {
+                custom_size: Some(Vec2::new(GOLD_SIZE, GOLD_SIZE)),
+                ..Default::default()
+            },
+            transform: Transform {
+                translation: Vec3::new(position.x, position.y, 0.15),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .insert(GoldComponent {
+            value,
+            width: GOLD_SIZE,
+            height: GOLD_SIZE,
+        })
+        .insert(Name::new("Gold"));
+}
+
+pub fn spawn_potion(
+    commands: &mut Commands,
+    ingame_materials: &InGameMaterials,
+    potion_type: PotionType,
+    position: Vec3,
+) {
+    let component_name = match potion_type {
+        PotionType::SpeedUp => "SpeedUpPotion",
+        PotionType::Heal => "HealPotion",
+        PotionType::EvasionUp => "EvasionUpPotion",
+        PotionType::Focus => "FocusPotion",
+    };
+
+    commands
+        .spawn(SpriteBundle {
+            texture: ingame_materials.potions_materials.get_texture(potion_type),
+            sprite: Sprite {
+                custom_size: Some(Vec2::new(POTION_SIZE, POTION_SIZE)),
+                ..Default::default()
+            },
+            transform: Transform {
+                translation: Vec3::new(position.x, position.y, 0.15),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .insert(PotionComponent {
+            potion_type,
+            width: POTION_SIZE,
+            height: POTION_SIZE,
+        })
+        .insert(Name::new(component_name));
+}
+
+pub fn spawn_random_relic(
+    commands: &mut Commands,
+    ingame_materials: &InGameMaterials,
+    game_data: &GameData,
+    player_relics: &PlayerRelicsComponent,
+    position: Vec3,
+) {
+    if let Some(relic) = pick_random_relic(game_data, player_relics) {
+        relic::spawn_relic_pickup(commands, ingame_materials, relic, position);
+    }
+}
+
+pub fn pick_random_relic(
+    game_data: &GameData,
+    player_relics: &PlayerRelicsComponent,
+) -> Option<Relic> {
+    let available_relics: Vec<_> = game_data
+        .get_relics()
+        .into_iter()
+        .filter(|relic| !player_relics.contains(&relic.name))
+        .collect();
+
+    if available_relics.is_empty() {
+        None
+    } else {
+        let mut rng = rand::thread_rng();
+        Some(available_relics[rng.gen_range(0..available_relics.len())].clone())
+    }
+}
@@ -2,6 +2,7 @@ pub mod camera;
 pub mod classic_mode;
 pub mod debug;
 pub mod input;
+pub mod loot;
 pub mod monster;
 pub mod music;
 pub mod player;
@@ -1,16 +1,27 @@
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use rand::Rng;
 
 use crate::{
-    components::{monster::MonsterComponent, potion::PotionComponent, skill::SkillComponent},
+    components::{
+        monster::MonsterComponent, player::PlayerComponent,
+        player_experience::PlayerExperienceComponent, player_relics::PlayerRelicsComponent,
+        skill::SkillComponent, weapon_inventory::WeaponInventoryComponent,
+    },
     materials::ingame::InGameMaterials,
+    plugins::{loot, player::relic::RelicTriggerEvent, weapon::inventory},
     resources::{
-        monster::monster_spawn_controller::MonsterSpawnController,
+        game_data::GameData, monster::monster_spawn_controller::MonsterSpawnController,
         player::player_dungeon_stats::PlayerDungeonStats, potion::potion_type::PotionType,
-        profile::Profile, skill::skill_type::SkillType,
+        profile::Profile, relic::relic_trigger::RelicTrigger, skill::skill_trigger::SkillTrigger,
+        upgrade::upgrade_controller::UpgradeController,
     },
 };
 
+const WEAPON_DROP_CHANCE: f32 = 0.05;
+const RELIC_DROP_CHANCE: f32 = 0.03;
+const GOLD_DROP_CHANCE: f32 = 0.5;
+
 pub fn cleanup_monsters(
     mut monsters_query: Query<Entity, With<MonsterComponent>>,
     mut commands: Commands,
@@ -20,23 +31,54 @@ pub fn cleanup_monsters(
     }
 }
 
+#[derive(SystemParam)]
+pub struct KillingPlayer<'w, 's> {
+    skill_query: Query<'w, 's, &'static mut SkillComponent>,
+    experience_query: Query<'w, 's, &'static mut PlayerExperienceComponent>,
+    player_query: Query<
+        'w,
+        's,
+        (
+            &'static PlayerComponent,
+            &'static WeaponInventoryComponent,
+            &'static PlayerRelicsComponent,
+        ),
+    >,
+}
+
+#[derive(SystemParam)]
+pub struct MonsterDrops<'w> {
+    ingame_materials: Res<'w, InGameMaterials>,
+    game_data: Res<'w, GameData>,
+    upgrade_controller: Res<'w, UpgradeController>,
+}
+
 pub fn cleanup_killed_monsters(
     mut monster_spawn_controller: ResMut<MonsterSpawnController>,
     mut monsters_query: Query<(Entity, &Transform, &MonsterComponent)>,
-    mut player_skill_query: Query<&mut SkillComponent>,
-    ingame_materials: Res<InGameMaterials>,
+    mut killing_player: KillingPlayer,
+    monster_drops: MonsterDrops,
     mut profile: ResMut<Profile>,
+    mut relic_trigger_events: EventWriter<RelicTriggerEvent>,
     mut commands: Commands,
 ) {
-    let mut player_skill = player_skill_query.single_mut();
+    let ingame_materials = &monster_drops.ingame_materials;
+    let game_data = &monster_drops.game_data;
+    let upgrade_controller = &monster_drops.upgrade_controller;
+    let mut player_skill = killing_player.skill_query.single_mut();
+    let mut player_experience = killing_player.experience_query.single_mut();
+    let (player, weapon_inventory, player_relics) = killing_player.player_query.single();
     for (monster_entity, monster_transform, monster) in monsters_query.iter_mut() {
         if monster.current_health_points == 0.0 {
-            if player_skill.skill.name == SkillType::Armor {
+            if player_skill.skill.trigger == SkillTrigger::KillCounter {
                 player_skill.monster_counter += 1;
             }
             monster_spawn_controller.killed_monsters += 1;
             monster_spawn_controller.alive_monsters -= 1;
             profile.total_killed_monsters += 1;
+            player_experience.add(monster.experience);
+            profile.level = player_experience.level;
+            relic_trigger_events.send(RelicTriggerEvent(RelicTrigger::OnKill));
             commands.entity(monster_entity).despawn_recursive();
 
             let mut rng = rand::thread_rng();
@@ -52,42 +94,64 @@ pub fn cleanup_killed_monsters(
                     _ => PotionType::Focus,
                 };
 
-                let texture = match potion_type {
-                    PotionType::SpeedUp => ingame_materials.potions_materials.speed_up.clone(),
-                    PotionType::Heal => ingame_materials.potions_materials.heal.clone(),
-                    PotionType::EvasionUp => ingame_materials.potions_materials.evasion_up.clone(),
-                    PotionType::Focus => ingame_materials.potions_materials.focus.clone(),
-                };
+                loot::spawn_potion(
+                    &mut commands,
+                    ingame_materials,
+                    potion_type,
+                    monster_transform.translation,
+                );
+            }
 
-                let component_name = match potion_type {
-                    PotionType::SpeedUp => "SpeedUpPotion",
-                    PotionType::Heal => "HealPotion",
-                    PotionType::EvasionUp => "EvasionUpPotion",
-                    PotionType::Focus => "FocusPotion",
-                };
+            if monster.gold > 0 && rng.gen_range(0.0..1.0) < GOLD_DROP_CHANCE {
+                loot::spawn_gold(
+                    &mut commands,
+                    ingame_materials,
+                    monster.gold,
+                    monster_transform.translation + Vec3::new(0.0, -30.0, 0.0),
+                );
+            }
 
-                let x = monster_transform.translation.x;
-                let y = monster_transform.translation.y;
-
-                commands
-                    .spawn(SpriteBundle {
-                        texture,
-                        sprite: Sprite {
-                            custom_size: Some(Vec2::new(16.0 * 2.0, 16.0 * 2.0)),
-                            ..Default::default()
-                        },
-                        transform: Transform {
-                            translation: Vec3::new(x, y, 0.15),
-                            ..Default::default()
-                        },
-                        ..Default::default()
-                    })
-                    .insert(PotionComponent {
-                        potion_type,
-                        width: 16.0 * 2.0,
-                        height: 16.0 * 2.0,
+            if rng.gen_range(0.0..1.0) < WEAPON_DROP_CHANCE {
+                let max_level = weapon_inventory
+                    .weapons
+                    .iter()
+                    .map(|weapon| weapon.level)
+                    .max()
+                    .unwrap_or(1);
+
+                let droppable_weapons: Vec<_> = game_data
+                    .get_weapons(player.class.clone())
+                    .into_iter()
+                    .filter(|raw_weapon| {
+                        raw_weapon.level <= max_level + 1
+                            && !upgrade_controller.locked_weapons.contains(&raw_weapon.name)
+                            && !weapon_inventory
+                                .weapons
+                                .iter()
+                                .any(|weapon| weapon.name == raw_weapon.name)
                     })
-                    .insert(Name::new(component_name));
+                    .collect();
+
+                if !droppable_weapons.is_empty() {
+                    let weapon =
+                        droppable_weapons[rng.gen_range(0..droppable_weapons.len())].clone();
+                    inventory::spawn_weapon_pickup(
+                        &mut commands,
+                        ingame_materials,
+                        weapon,
+                        monster_transform.translation + Vec3::new(30.0, 0.0, 0.0),
+                    );
+                }
+            }
+
+            if rng.gen_range(0.0..1.0) < RELIC_DROP_CHANCE {
+                loot::spawn_random_relic(
+                    &mut commands,
+                    ingame_materials,
+                    game_data,
+                    player_relics,
+                    monster_transform.translation + Vec3::new(-30.0, 0.0, 0.0),
+                );
             }
         }
     }
@@ -1,10 +1,12 @@
 use bevy::prelude::*;
 
 use crate::{
-    components::monster_list_effects::MonsterListEffectsComponent,
+    components::{monster::MonsterComponent, monster_list_effects::MonsterListEffectsComponent},
     resources::effect::effect_type::EffectType,
 };
 
+const POISON_DAMAGE_PER_SECOND: f32 = 1.0;
+
 pub fn update_effects(
     mut monsters_effects_query: Query<&mut MonsterListEffectsComponent>,
     time: Res<Time>,
@@ -30,8 +32,32 @@ pub fn update_color_of_effects(
                     texture.color = Color::ALICE_BLUE;
                 } else if *effect_type == EffectType::ReduceDamage {
                     texture.color = Color::YELLOW;
+                } else if *effect_type == EffectType::Poison {
+                    texture.color = Color::LIME_GREEN;
                 }
             }
         }
     }
 }
+
+pub fn poison_damage(
+    mut monsters_query: Query<(&mut MonsterComponent, &MonsterListEffectsComponent)>,
+    time: Res<Time>,
+) {
+    for (mut monster, monster_list_effects) in monsters_query.iter_mut() {
+        let poison_effect = monster_list_effects
+            .activated_effects
+            .get(&EffectType::Poison);
+
+        if poison_effect.unwrap().finished() {
+            continue;
+        }
+
+        let damage = POISON_DAMAGE_PER_SECOND * time.delta_seconds();
+        monster.current_health_points = if damage > monster.current_health_points {
+            0.0
+        } else {
+            monster.current_health_points - damage
+        };
+    }
+}
@@ -22,6 +22,7 @@ impl Plugin for MonsterPlugin {
             effect::update_effects,
             movement::change_direction,
             effect::update_color_of_effects,
+            effect::poison_damage.before(cleanup::cleanup_killed_monsters),
             cleanup::cleanup_killed_monsters,
             invinsible::hurt_duration_color.after(effect::update_color_of_effects)
         ).run_if(in_state(SceneState::InGameClassicMode).or_else(in_state(SceneState::InGameSurvivalMode)).and_then(not(resource_exists::<PauseSceneData>()))));
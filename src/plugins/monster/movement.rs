@@ -4,14 +4,14 @@ use crate::{
     components::{
         monster::MonsterComponent, monster_animation::MonsterAnimationComponent,
         monster_list_effects::MonsterListEffectsComponent, player::PlayerComponent,
-        skill::SkillComponent,
-    },
-    resources::{
-        animation_state::AnimationState, effect::effect_type::EffectType,
-        skill::skill_type::SkillType,
+        prop::PropComponent, skill::SkillComponent,
     },
+    plugins::player::collisions::prop_collision_check,
+    resources::{animation_state::AnimationState, effect::effect_type::EffectType},
 };
 
+const SLOW_SPEED_MULTIPLIER: f32 = 0.5;
+
 pub fn move_to_player(
     mut set: ParamSet<(
         Query<(&Transform, &SkillComponent), With<PlayerComponent>>,
@@ -22,12 +22,13 @@ pub fn move_to_player(
             &mut Transform,
         )>,
     )>,
+    props_query: Query<(&PropComponent, &Transform), Without<MonsterComponent>>,
 ) {
     let mut should_move = true;
     let mut target = Vec3::new(0.0, 0.0, 0.15);
 
     for (transform, skill_component) in set.p0().iter() {
-        if skill_component.skill.name == SkillType::TimeToHunt {
+        if skill_component.skill.freeze_monsters.unwrap_or(false) {
             if !skill_component.duration.finished() {
                 should_move = false;
             }
@@ -43,12 +44,28 @@ pub fn move_to_player(
             .activated_effects
             .get(&EffectType::Stun);
 
+        let slow_effect = monster_list_effects
+            .activated_effects
+            .get(&EffectType::Slow);
+
+        let speed = if slow_effect.unwrap().finished() {
+            monster_component.speed
+        } else {
+            monster_component.speed * SLOW_SPEED_MULTIPLIER
+        };
+
         if !stun_effect.unwrap().finished() || !should_move {
             monster_animation_component.animation_state = AnimationState::Idle;
         } else {
             monster_animation_component.animation_state = AnimationState::Moving;
             let dir = (target - transform.translation).normalize();
-            let mut new_position = transform.translation + dir * monster_component.speed * 0.2;
+            let delta = prop_collision_check(
+                transform.translation,
+                Vec2::new(monster_component.width, monster_component.height),
+                dir * speed * 0.2,
+                &props_query,
+            );
+            let mut new_position = transform.translation + delta;
             new_position.z = 0.15;
             transform.translation = new_position;
         }
@@ -11,11 +11,9 @@ use crate::{
     materials::ingame::InGameMaterials,
     resources::{
         animation_state::AnimationState,
-        dungeon::wave::Wave,
+        dungeon::{wave::Wave, Dungeon},
         game_data::GameData,
-        monster::{
-            monster_class::MonsterClass, monster_spawn_controller::MonsterSpawnController, Monster,
-        },
+        monster::{monster_spawn_controller::MonsterSpawnController, Monster},
         player::player_dungeon_stats::PlayerDungeonStats,
     },
 };
@@ -26,6 +24,7 @@ pub fn spawn_monsters_classic_mode(
     player_dungeon_stats: Res<PlayerDungeonStats>,
     ingame_materials: Res<InGameMaterials>,
     game_data: Res<GameData>,
+    dungeon: Res<Dungeon>,
     mut commands: Commands,
 ) {
     if player_dungeon_stats.is_room_cleared {
@@ -35,24 +34,27 @@ pub fn spawn_monsters_classic_mode(
         let require_monsters = monster_spawn_controller.require_monster;
         let killed_monsters = monster_spawn_controller.killed_monsters;
 
-        let monsters_max_level = {
-            if player_dungeon_stats.current_floor_index < 2 {
-                1
-            } else if player_dungeon_stats.current_floor_index < 4 {
-                2
-            } else {
-                3
-            }
-        };
+        let monsters_max_level = Monster::get_max_level(player_dungeon_stats.current_floor_index);
 
-        let raw_monsters = game_data.get_monsters();
+        let raw_monsters: Vec<Monster> = game_data
+            .get_monsters()
+            .into_iter()
+            .filter(|raw_monster| raw_monster.level <= monsters_max_level)
+            .collect();
+        let biome = game_data.get_biome(&dungeon.current_floor.biome);
 
-        let raw_selected_monsters: Vec<Monster> = raw_monsters
+        let biome_monsters: Vec<Monster> = raw_monsters
             .iter()
-            .filter(|raw_monster| raw_monster.level <= monsters_max_level)
+            .filter(|raw_monster| biome.monsters.contains(&raw_monster.class))
             .cloned()
             .collect();
 
+        let raw_selected_monsters = if biome_monsters.is_empty() {
+            raw_monsters
+        } else {
+            biome_monsters
+        };
+
         let start_x = monster_spawn_controller.spawn_area_start_x;
         let start_y = monster_spawn_controller.spawn_area_start_y;
         let end_x = monster_spawn_controller.spawn_area_end_x;
@@ -96,10 +98,12 @@ pub fn spawn_monsters_classic_mode(
                         current_health_points: raw_monster.health_points,
                         max_health_points: raw_monster.health_points,
                         damage: raw_monster.damage,
+                        gold: raw_monster.gold,
+                        experience: raw_monster.experience,
                         speed: raw_monster.speed,
                         level: raw_monster.level,
                         class: raw_monster.class.clone(),
-                        trigger_effect: raw_monster.trigger_effect,
+                        trigger_effect: raw_monster.trigger_effect.clone(),
                         trigger_chance: raw_monster.trigger_chance.unwrap_or(0.0),
                         skill: raw_monster.skill.clone(),
                         width: raw_monster.origin_width * 3.5,
@@ -107,10 +111,7 @@ pub fn spawn_monsters_classic_mode(
                     })
                     .insert(MonsterListEffectsComponent::new())
                     .insert(MonsterAnimationComponent {
-                        total_tiles: match raw_monster.class {
-                            MonsterClass::Zombie | MonsterClass::Swampy => 4,
-                            _ => 8,
-                        },
+                        total_tiles: raw_monster.frames,
                         animation_state: AnimationState::Idle,
                         animation_timer: Timer::from_seconds(0.1, TimerMode::Repeating),
                     })
@@ -202,20 +203,19 @@ pub fn spawn_monsters_survival_mode(
                         current_health_points: raw_monster.health_points,
                         max_health_points: raw_monster.health_points,
                         damage: raw_monster.damage,
+                        gold: raw_monster.gold,
+                        experience: raw_monster.experience,
                         speed: raw_monster.speed,
                         level: raw_monster.level,
                         class: raw_monster.class.clone(),
-                        trigger_effect: raw_monster.trigger_effect,
+                        trigger_effect: raw_monster.trigger_effect.clone(),
                         trigger_chance: raw_monster.trigger_chance.unwrap_or(0.0),
                         skill: raw_monster.skill.clone(),
                         width: raw_monster.origin_width * 3.5,
                         height: raw_monster.origin_height * 3.5,
                     })
                     .insert(MonsterAnimationComponent {
-                        total_tiles: match raw_monster.class {
-                            MonsterClass::Zombie | MonsterClass::Swampy => 4,
-                            _ => 8,
-                        },
+                        total_tiles: raw_monster.frames,
                         animation_state: AnimationState::Idle,
                         animation_timer: Timer::from_seconds(0.1, TimerMode::Repeating),
                     })
@@ -237,17 +237,12 @@ pub fn spawn_monsters_survival_mode(
 fn get_texture(monster: &Monster, ingame_materials: &InGameMaterials) -> TextureAtlas {
     let monster_tileset = ingame_materials
         .monsters_materials
-        .get_texture(monster.class.clone());
-
-    let columns = match monster.class {
-        MonsterClass::Zombie | MonsterClass::Swampy => 4,
-        _ => 8,
-    };
+        .get_texture(&monster.sprite);
 
     TextureAtlas::from_grid(
         monster_tileset,
         Vec2::new(monster.origin_width, monster.origin_height),
-        columns,
+        monster.frames as usize,
         1,
         None,
         None
@@ -1,54 +1,82 @@
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use bevy_kira_audio::prelude::{AudioSource, Audio, AudioControl};
 
+use crate::resources::dungeon::Dungeon;
+use crate::resources::game_data::GameData;
+use crate::resources::mods::ModList;
 use crate::resources::setting::Setting;
 use crate::scenes::SceneState;
 
+const DEFAULT_BACKGROUND_MUSIC: &str = "sounds/background_music.ogg";
+
 // Our type for the custom audio channel
 
 #[derive(Resource)]
 pub struct BackgroundAudioChannel {
-    background_music: Handle<AudioSource>,
-    loop_started: bool,
+    current_music: Option<String>,
     volume: f32,
 }
 
-pub fn background_audio_channel_setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+pub fn background_audio_channel_setup(mut commands: Commands) {
     let background_audio_channel = BackgroundAudioChannel {
-        background_music: asset_server.load("sounds/background_music.ogg"),
-        loop_started: false,
+        current_music: None,
         volume: 0.2,
     };
 
     commands.insert_resource(background_audio_channel);
 }
 
+#[derive(SystemParam)]
+pub struct BiomeMusic<'w> {
+    dungeon: Option<Res<'w, Dungeon>>,
+    game_data: Option<Res<'w, GameData>>,
+}
+
+impl BiomeMusic<'_> {
+    fn get_music(&self) -> Option<String> {
+        match (&self.dungeon, &self.game_data) {
+            (Some(dungeon), Some(game_data)) => {
+                Some(game_data.get_biome(&dungeon.current_floor.biome).music)
+            }
+            _ => None,
+        }
+    }
+}
+
 pub fn play_background_music(
     mut background_audio_channel: ResMut<BackgroundAudioChannel>,
     state: Res<State<SceneState>>,
     setting: Res<Setting>,
     audio: Res<Audio>,
+    asset_server: Res<AssetServer>,
+    mod_list: Res<ModList>,
+    biome_music: BiomeMusic,
 ) {
-    if setting.get_enable_music() {
-        if !background_audio_channel.loop_started {
-            background_audio_channel.loop_started = true;
-            audio.set_volume(
-                background_audio_channel.volume as f64,
-            );
-            audio.play(
-                background_audio_channel.background_music.clone(),
-            ).looped();
-        } else {
-            match state.get() {
-                SceneState::InGameClassicMode | SceneState::InGameSurvivalMode => {
-                    background_audio_channel.loop_started = false;
-                    audio.stop();
-                }
-                _ => (),
-            }
+    let music = if !setting.get_enable_music() {
+        None
+    } else {
+        match state.get() {
+            SceneState::InGameClassicMode => biome_music.get_music(),
+            SceneState::InGameSurvivalMode => None,
+            _ => Some(DEFAULT_BACKGROUND_MUSIC.to_string()),
         }
-    } else if background_audio_channel.loop_started {
-        background_audio_channel.loop_started = false;
+    };
+
+    if music == background_audio_channel.current_music {
+        return;
+    }
+
+    if background_audio_channel.current_music.is_some() {
         audio.stop();
     }
+
+    if let Some(path) = music.clone() {
+        let background_music: Handle<AudioSource> =
+            asset_server.load(mod_list.resolve_sprite(&path));
+        audio.set_volume(background_audio_channel.volume as f64);
+        audio.play(background_music).looped();
+    }
+
+    background_audio_channel.current_music = music;
 }
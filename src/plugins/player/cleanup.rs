@@ -1,6 +1,7 @@
 use bevy::prelude::*;
 
 use crate::{
+    components::gold::GoldComponent,
     plugins::player::PlayerEntity,
     resources::{dungeon::wave::Wave, profile::Profile},
 };
@@ -12,3 +13,9 @@ pub fn cleanup_player(mut commands: Commands, player_entity: Res<PlayerEntity>)
 pub fn save_cleared_waves(wave: Res<Wave>, mut profile: ResMut<Profile>) {
     profile.total_cleared_waves = wave.wave_number - 1;
 }
+
+pub fn cleanup_gold(mut commands: Commands, gold_query: Query<Entity, With<GoldComponent>>) {
+    for gold_entity in gold_query.iter() {
+        commands.entity(gold_entity).despawn_recursive();
+    }
+}
@@ -1,21 +1,29 @@
+use bevy::ecs::query::ReadOnlyWorldQuery;
 use bevy::prelude::*;
 use bevy::sprite::collide_aabb::collide;
 use rand::Rng;
 use std::time::Duration;
 
+use crate::components::gold::GoldComponent;
 use crate::components::invinsible_cooldown::InvisibleCooldownComponent;
 use crate::components::monster::MonsterComponent;
 use crate::components::player::PlayerComponent;
 use crate::components::player_animation::PlayerAnimation;
 use crate::components::player_list_effects::PlayerListEffectsComponent;
 use crate::components::potion::PotionComponent;
+use crate::components::potion_belt::PotionBeltComponent;
+use crate::components::prop::PropComponent;
 use crate::config::*;
+use crate::plugins::player::relic::RelicTriggerEvent;
 use crate::plugins::player::{PLAYER_SIZE_HEIGHT, PLAYER_SIZE_WIDTH};
 use crate::resources::animation_state::AnimationState;
+use crate::resources::death_recap::HitSource;
 use crate::resources::dungeon::block_type::BlockType;
 use crate::resources::effect::effect_type::EffectType;
 use crate::resources::player::player_available_movement::PlayerAvailableMovement;
 use crate::resources::potion::potion_type::PotionType;
+use crate::resources::profile::Profile;
+use crate::resources::relic::relic_trigger::RelicTrigger;
 
 pub fn wall_collision_check(
     player_position: Vec3,
@@ -61,6 +69,34 @@ pub fn wall_collision_check(
     player_available_movement
 }
 
+pub fn prop_collision_check<F: ReadOnlyWorldQuery>(
+    position: Vec3,
+    size: Vec2,
+    delta: Vec3,
+    props_query: &Query<(&PropComponent, &Transform), F>,
+) -> Vec3 {
+    let mut available_delta = delta;
+
+    for (prop, prop_transform) in props_query.iter() {
+        let prop_position = prop_transform.translation;
+        let prop_size = Vec2::new(prop.width, prop.height);
+
+        let moves_towards_x = (prop_position.x - position.x) * available_delta.x > 0.0;
+        let next_x = position + Vec3::new(available_delta.x, 0.0, 0.0);
+        if moves_towards_x && collide(next_x, size, prop_position, prop_size).is_some() {
+            available_delta.x = 0.0;
+        }
+
+        let moves_towards_y = (prop_position.y - position.y) * available_delta.y > 0.0;
+        let next_y = position + Vec3::new(0.0, available_delta.y, 0.0);
+        if moves_towards_y && collide(next_y, size, prop_position, prop_size).is_some() {
+            available_delta.y = 0.0;
+        }
+    }
+
+    available_delta
+}
+
 pub fn monsters_collision_check(
     mut player_query: Query<(
         &mut PlayerComponent,
@@ -70,6 +106,8 @@ pub fn monsters_collision_check(
         &Transform,
     )>,
     monsters_query: Query<(&MonsterComponent, &Transform), Without<PlayerComponent>>,
+    mut profile: ResMut<Profile>,
+    mut relic_trigger_events: EventWriter<RelicTriggerEvent>,
 ) {
     let (
         mut player,
@@ -81,40 +119,58 @@ pub fn monsters_collision_check(
     let player_size = Vec2::new(PLAYER_SIZE_WIDTH, PLAYER_SIZE_HEIGHT);
     let player_position = player_transform.translation;
 
-    if !invincible_cooldown.duration.finished() {
-        return;
-    }
-
     for (monster_component, transform) in monsters_query.iter() {
         let monster_size = Vec2::new(monster_component.width, monster_component.height);
         let monster_position = transform.translation;
         if collide(player_position, player_size, monster_position, monster_size).is_some() {
-            let damage = monster_component.damage;
-
-            player.current_health_points = if damage > player.current_health_points {
-                0.0
-            } else {
-                player.current_health_points - damage
-            };
-
-            let debuff_effect = monster_component.trigger_effect;
-            let trigger_chance = monster_component.trigger_chance;
+            let is_damaged = damage_player(
+                &mut player,
+                &mut player_animation,
+                &mut invincible_cooldown,
+                &mut profile,
+                &mut relic_trigger_events,
+                HitSource::Monster(monster_component.class.clone()),
+                monster_component.damage,
+            );
 
-            if debuff_effect != None && trigger_chance != 0.0 {
+            if let Some(effect_id) = &monster_component.trigger_effect {
                 let mut rng = rand::thread_rng();
-                if rng.gen_range(0.0..1.0) < trigger_chance {
-                    player_list_effects.activate(debuff_effect.unwrap());
+                if is_damaged && rng.gen_range(0.0..1.0) < monster_component.trigger_chance {
+                    player_list_effects.activate(effect_id);
                 }
             }
-
-            invincible_cooldown.duration = Timer::new(Duration::from_secs_f32(2.0), TimerMode::Once);
-            invincible_cooldown.hurt_duration = Timer::new(Duration::from_secs_f32(0.3), TimerMode::Once);
-            player_animation.animation_state = AnimationState::Hit;
             break;
         }
     }
 }
 
+pub fn damage_player(
+    player: &mut PlayerComponent,
+    player_animation: &mut PlayerAnimation,
+    invincible_cooldown: &mut InvisibleCooldownComponent,
+    profile: &mut Profile,
+    relic_trigger_events: &mut EventWriter<RelicTriggerEvent>,
+    source: HitSource,
+    damage: f32,
+) -> bool {
+    if !invincible_cooldown.duration.finished() || player.current_health_points == 0.0 {
+        return false;
+    }
+
+    player.current_health_points = if damage > player.current_health_points {
+        0.0
+    } else {
+        player.current_health_points - damage
+    };
+
+    profile.death_recap.record_hit(source, damage);
+    relic_trigger_events.send(RelicTriggerEvent(RelicTrigger::OnHitTaken));
+
+    invincible_cooldown.duration = Timer::new(Duration::from_secs_f32(2.0), TimerMode::Once);
+    invincible_cooldown.hurt_duration = Timer::new(Duration::from_secs_f32(0.3), TimerMode::Once);
+    player_animation.animation_state = AnimationState::Hit;
+    true
+}
 
 pub fn monsters_collision_check_survival(
     player_query: Query<(
@@ -125,21 +181,21 @@ pub fn monsters_collision_check_survival(
         &Transform,
     )>,
     monsters_query: Query<(&MonsterComponent, &Transform), Without<PlayerComponent>>,
+    profile: ResMut<Profile>,
+    relic_trigger_events: EventWriter<RelicTriggerEvent>,
 ) {
     monsters_collision_check(
         player_query,
-        monsters_query
+        monsters_query,
+        profile,
+        relic_trigger_events
     );
 }
 
 pub fn potions_collision(
     mut commands: Commands,
     mut player_query: Query<
-        (
-            &mut PlayerComponent,
-            &mut PlayerListEffectsComponent,
-            &Transform,
-        ),
+        (&mut PotionBeltComponent, &Transform),
         (With<PlayerComponent>, Without<PotionComponent>),
     >,
     potions_query: Query<
@@ -147,7 +203,7 @@ pub fn potions_collision(
         (With<PotionComponent>, Without<PlayerComponent>),
     >,
 ) {
-    let (mut player, mut player_list_effects, player_transform) = player_query.single_mut();
+    let (mut potion_belt, player_transform) = player_query.single_mut();
     let player_size = Vec2::new(PLAYER_SIZE_WIDTH, PLAYER_SIZE_HEIGHT);
     let player_position = player_transform.translation;
 
@@ -156,23 +212,56 @@ pub fn potions_collision(
         let potion_position = potion_transform.translation;
 
         if collide(player_position, player_size, potion_position, potion_size).is_some() {
-            match potion.potion_type {
-                PotionType::Heal => {
-                    player.current_health_points =
-                        if player.current_health_points >= player.max_health_points - 1.0 {
-                            player.max_health_points
-                        } else if player.current_health_points < player.max_health_points {
-                            player.current_health_points + 1.0
-                        } else {
-                            player.current_health_points
-                        }
-                }
-                PotionType::SpeedUp => player_list_effects.activate(EffectType::SpeedUp),
-                PotionType::EvasionUp => player_list_effects.activate(EffectType::EvasionUp),
-                PotionType::Focus => player_list_effects.activate(EffectType::Focus),
+            if potion_belt.is_full(potion.potion_type) {
+                continue;
             }
 
+            potion_belt.add(potion.potion_type);
             commands.entity(potion_entity).despawn_recursive();
         }
     }
 }
+
+pub fn drink_potion(
+    player: &mut PlayerComponent,
+    player_list_effects: &mut PlayerListEffectsComponent,
+    potion_type: PotionType,
+) {
+    match potion_type {
+        PotionType::Heal => {
+            player.current_health_points =
+                if player.current_health_points >= player.max_health_points - 1.0 {
+                    player.max_health_points
+                } else if player.current_health_points < player.max_health_points {
+                    player.current_health_points + 1.0
+                } else {
+                    player.current_health_points
+                }
+        }
+        PotionType::SpeedUp => player_list_effects.activate(&EffectType::SpeedUp.into()),
+        PotionType::EvasionUp => player_list_effects.activate(&EffectType::EvasionUp.into()),
+        PotionType::Focus => player_list_effects.activate(&EffectType::Focus.into()),
+    }
+}
+
+pub fn gold_collision(
+    mut commands: Commands,
+    player_query: Query<&Transform, With<PlayerComponent>>,
+    gold_query: Query<(Entity, &GoldComponent, &Transform), Without<PlayerComponent>>,
+    mut profile: ResMut<Profile>,
+) {
+    let player_transform = player_query.single();
+    let player_size = Vec2::new(PLAYER_SIZE_WIDTH, PLAYER_SIZE_HEIGHT);
+    let player_position = player_transform.translation;
+
+    for (gold_entity, gold, gold_transform) in gold_query.iter() {
+        let gold_size = Vec2::new(gold.width, gold.height);
+        let gold_position = gold_transform.translation;
+
+        if collide(player_position, player_size, gold_position, gold_size).is_some() {
+            profile.gold += gold.value;
+            profile.total_gold += gold.value;
+            commands.entity(gold_entity).despawn_recursive();
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use bevy::prelude::*;
+
+use crate::components::dash::DashComponent;
+
+pub fn dash_cooldown(time: Res<Time>, mut dash_query: Query<&mut DashComponent>) {
+    let mut dash = dash_query.single_mut();
+    if !dash.cooldown.finished() {
+        dash.cooldown.tick(time.delta());
+    }
+
+    if !dash.duration.finished() {
+        dash.duration.tick(time.delta());
+    }
+}
@@ -1,6 +1,8 @@
 use bevy::prelude::*;
 
+use crate::components::player::PlayerComponent;
 use crate::components::player_list_effects::PlayerListEffectsComponent;
+use crate::resources::effect::effect_type::EffectType;
 
 pub fn update_effects(
     mut player_list_effects_query: Query<&mut PlayerListEffectsComponent>,
@@ -13,3 +15,31 @@ pub fn update_effects(
         }
     }
 }
+
+pub fn poison_damage(
+    mut player_query: Query<(&mut PlayerComponent, &PlayerListEffectsComponent)>,
+    time: Res<Time>,
+) {
+    let (mut player, player_list_effects) = player_query.single_mut();
+
+    let poisons: Vec<_> = player_list_effects
+        .activated_effects
+        .iter()
+        .filter(|(_, duration)| !duration.finished())
+        .map(|(effect_id, _)| player_list_effects.get(effect_id))
+        .filter(|information| information.effect_type == EffectType::Poison)
+        .collect();
+
+    if poisons.is_empty() || player.current_health_points == 0.0 {
+        return;
+    }
+
+    let damage_per_second: f32 = poisons.iter().map(|information| information.bonus).sum();
+    let damage = damage_per_second * time.delta_seconds();
+
+    player.current_health_points = if damage > player.current_health_points {
+        0.0
+    } else {
+        player.current_health_points - damage
+    };
+}
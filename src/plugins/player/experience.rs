@@ -0,0 +1,17 @@
+use bevy::prelude::*;
+
+use crate::components::player_experience::PlayerExperienceComponent;
+use crate::scenes::reward_scene::PendingRewards;
+
+pub fn level_up_handle(
+    mut player_experience_query: Query<&mut PlayerExperienceComponent>,
+    mut pending_rewards: ResMut<PendingRewards>,
+) {
+    let mut player_experience = player_experience_query.single_mut();
+    if player_experience.pending_level_ups == 0 {
+        return;
+    }
+
+    pending_rewards.count += player_experience.pending_level_ups;
+    player_experience.pending_level_ups = 0;
+}
@@ -1,11 +1,129 @@
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
+use std::f32::consts::FRAC_PI_2;
+use std::time::Duration;
 
 use crate::components::player::PlayerComponent;
+use crate::components::player_list_effects::PlayerListEffectsComponent;
+use crate::resources::dungeon::wave::Wave;
+use crate::resources::dungeon::Dungeon;
+use crate::resources::game_mode::GameMode;
+use crate::resources::player::player_dungeon_stats::PlayerDungeonStats;
 use crate::resources::profile::Profile;
 
-pub fn end_run_check(mut player_query: Query<&mut PlayerComponent>, mut profile: ResMut<Profile>) {
-    let player = player_query.single_mut();
-    if player.current_health_points == 0.0 {
+const DEATH_SEQUENCE_DURATION: f32 = 2.5;
+const DEATH_SLOW_MOTION_SPEED: f32 = 0.25;
+
+#[derive(Resource)]
+pub struct DeathSequence {
+    timer: Timer,
+    fade_overlay: Entity,
+}
+
+// where the run was when the player died, only the resources of the current game mode exist
+#[derive(SystemParam)]
+pub struct RunProgress<'w> {
+    dungeon: Option<Res<'w, Dungeon>>,
+    player_dungeon_stats: Option<Res<'w, PlayerDungeonStats>>,
+    wave: Option<Res<'w, Wave>>,
+}
+
+pub fn end_run_check(
+    mut commands: Commands,
+    player_query: Query<(&PlayerComponent, &PlayerListEffectsComponent)>,
+    death_sequence: Option<Res<DeathSequence>>,
+    run_progress: RunProgress,
+    mut profile: ResMut<Profile>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+) {
+    let (player, player_list_effects) = player_query.single();
+    if player.current_health_points > 0.0 || death_sequence.is_some() {
+        return;
+    }
+
+    profile.death_recap.is_player_dead = true;
+    profile.death_recap.active_effects = player_list_effects
+        .activated_effects
+        .keys()
+        .cloned()
+        .collect();
+
+    match profile.game_mode {
+        GameMode::ClassicMode => {
+            let dungeon = run_progress.dungeon.unwrap();
+            let player_dungeon_stats = run_progress.player_dungeon_stats.unwrap();
+            profile.death_recap.floor = Some(player_dungeon_stats.current_floor_index + 1);
+            profile.death_recap.rooms_cleared = Some(dungeon.current_floor.cleared_positions.len());
+        }
+        GameMode::SurvivalMode => {
+            profile.death_recap.wave = Some(run_progress.wave.unwrap().wave_number);
+        }
+    }
+
+    virtual_time.set_relative_speed(DEATH_SLOW_MOTION_SPEED);
+
+    let fade_overlay = commands
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                position_type: PositionType::Absolute,
+                ..Default::default()
+            },
+            background_color: BackgroundColor(Color::rgba(0.0, 0.0, 0.0, 0.0)),
+            z_index: ZIndex::Global(10),
+            ..Default::default()
+        })
+        .insert(Name::new("DeathFadeOverlay"))
+        .id();
+
+    commands.insert_resource(DeathSequence {
+        timer: Timer::new(
+            Duration::from_secs_f32(DEATH_SEQUENCE_DURATION),
+            TimerMode::Once,
+        ),
+        fade_overlay,
+    });
+}
+
+pub fn death_sequence_handle(
+    mut death_sequence: ResMut<DeathSequence>,
+    mut player_query: Query<(&mut Transform, &mut TextureAtlasSprite), With<PlayerComponent>>,
+    mut overlay_query: Query<&mut BackgroundColor>,
+    mut profile: ResMut<Profile>,
+    real_time: Res<Time<Real>>,
+) {
+    if death_sequence.timer.finished() {
+        return;
+    }
+
+    death_sequence.timer.tick(real_time.delta());
+    let progress = death_sequence.timer.percent();
+
+    let (mut transform, mut sprite) = player_query.single_mut();
+    transform.rotation = Quat::from_rotation_z(-FRAC_PI_2 * progress);
+    sprite.color = Color::rgba(1.0, 1.0, 1.0, 1.0 - progress * 0.5);
+
+    if let Ok(mut background_color) = overlay_query.get_mut(death_sequence.fade_overlay) {
+        background_color.0 = Color::rgba(0.0, 0.0, 0.0, progress);
+    }
+
+    if death_sequence.timer.finished() {
         profile.is_run_finished = true;
     }
 }
+
+pub fn cleanup_death_sequence(
+    mut commands: Commands,
+    death_sequence: Option<Res<DeathSequence>>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+) {
+    if let Some(death_sequence) = death_sequence {
+        commands
+            .entity(death_sequence.fade_overlay)
+            .despawn_recursive();
+        commands.remove_resource::<DeathSequence>();
+    }
+
+    virtual_time.set_relative_speed(1.0);
+}
@@ -2,17 +2,28 @@ use std::time::Duration;
 
 use bevy::prelude::*;
 
+use crate::components::dash::DashComponent;
 use crate::components::invinsible_cooldown::InvisibleCooldownComponent;
 use crate::components::player::PlayerComponent;
 use crate::components::player_animation::PlayerAnimation;
+use crate::components::player_experience::PlayerExperienceComponent;
 use crate::components::player_list_effects::PlayerListEffectsComponent;
+use crate::components::player_relics::PlayerRelicsComponent;
+use crate::components::potion_belt::PotionBeltComponent;
 use crate::components::skill::SkillComponent;
+use crate::components::weapon_inventory::WeaponInventoryComponent;
 use crate::materials::ingame::InGameMaterials;
 use crate::plugins::player::PlayerEntity;
 use crate::plugins::player::{PLAYER_SIZE_HEIGHT, PLAYER_SIZE_WIDTH};
 use crate::resources::game_data::GameData;
+use crate::resources::meta_progression::achievement::Unlock;
+use crate::resources::meta_progression::meta_upgrade::MetaUpgradeType;
+use crate::resources::meta_progression::MetaProgression;
+use crate::resources::mods::ModList;
+use crate::resources::potion::potion_type::PotionType;
 use crate::resources::profile::Profile;
 use crate::resources::upgrade::upgrade_controller::UpgradeController;
+use crate::scenes::reward_scene::PendingRewards;
 
 const PLAYER_ORIGIN_SIZE_WIDTH: f32 = 16.0;
 const PLAYER_ORIGIN_SIZE_HEIGHT: f32 = 28.0;
@@ -21,6 +32,8 @@ pub fn initiate_player(
     mut texture_atlases: ResMut<Assets<TextureAtlas>>,
     ingame_materials: Res<InGameMaterials>,
     game_data: Res<GameData>,
+    meta_progression: Res<MetaProgression>,
+    mod_list: Res<ModList>,
     mut commands: Commands,
     profile: Res<Profile>,
 ) {
@@ -28,12 +41,49 @@ pub fn initiate_player(
     let gender = profile.gender.clone();
 
     let skill = game_data.get_skill(class.clone());
+    let hero = game_data.get_hero(class.clone());
+    let dash = hero.dash.clone();
+    let weapon = game_data.get_weapon(class.clone());
 
-    let player = PlayerComponent::new(class.clone(), game_data.clone());
+    let mut player = PlayerComponent::new(class.clone(), game_data.clone());
+    let mut potion_belt = PotionBeltComponent::new();
+    let mut upgrade_controller = UpgradeController::new(&mod_list);
+
+    for meta_upgrade in game_data.get_meta_upgrades().iter() {
+        let level = meta_progression.get_upgrade_level(meta_upgrade.meta_upgrade_type);
+        let bonus = meta_upgrade.bonus * level as f32;
+        match meta_upgrade.meta_upgrade_type {
+            MetaUpgradeType::MaxHealth => {
+                player.max_health_points += bonus;
+                player.current_health_points += bonus;
+            }
+            MetaUpgradeType::Strength => player.strength += bonus,
+            MetaUpgradeType::Intelligence => player.intelligence += bonus,
+            MetaUpgradeType::CriticalChance => player.base_stats.critical_chance += bonus,
+            MetaUpgradeType::StartingPotion => {
+                for _ in 0..bonus as usize {
+                    if !potion_belt.is_full(PotionType::Heal) {
+                        potion_belt.add(PotionType::Heal);
+                    }
+                }
+            }
+            MetaUpgradeType::ExtraReroll => upgrade_controller.rerolls += bonus as usize,
+        }
+    }
+
+    let achievements = game_data.get_achievements();
+    upgrade_controller.locked_weapons = game_data
+        .get_weapons(class.clone())
+        .iter()
+        .map(|weapon| weapon.name.clone())
+        .filter(|weapon_type| {
+            !meta_progression.is_unlocked(&achievements, Unlock::Weapon(weapon_type.clone()))
+        })
+        .collect();
 
     let hero_tileset = ingame_materials
         .heroes_materials
-        .get_texture(class.clone(), gender);
+        .get_texture(&hero.get_variant(gender).sprite);
 
     let texture_atlas = TextureAtlas::from_grid(
         hero_tileset,
@@ -65,6 +115,11 @@ pub fn initiate_player(
             game_data.get_player_list_effects_information(),
         ))
         .insert(SkillComponent::new(skill))
+        .insert(DashComponent::new(dash))
+        .insert(WeaponInventoryComponent::new(weapon))
+        .insert(PlayerRelicsComponent::new())
+        .insert(potion_belt)
+        .insert(PlayerExperienceComponent::new())
         .insert(InvisibleCooldownComponent {
             hurt_duration: Timer::new(Duration::from_secs(0), TimerMode::Once),
             duration: Timer::new(Duration::from_secs_f32(0.5), TimerMode::Once),
@@ -72,6 +127,7 @@ pub fn initiate_player(
         .insert(Name::new("Player"))
         .id();
 
-    commands.insert_resource(UpgradeController::new());
+    commands.insert_resource(upgrade_controller);
+    commands.insert_resource(PendingRewards::default());
     commands.insert_resource(PlayerEntity { entity });
 }
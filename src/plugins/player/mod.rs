@@ -6,12 +6,15 @@ use crate::scenes::SceneState;
 mod animation;
 mod cleanup;
 pub mod collisions;
+mod dash;
 mod effect;
-mod health;
+mod experience;
+pub mod health;
 mod initiate;
 mod invisible;
 mod profile;
-mod skill;
+pub mod relic;
+pub mod skill;
 pub mod stats;
 mod ui;
 
@@ -27,6 +30,8 @@ pub const PLAYER_SIZE_HEIGHT: f32 = 28.0 * 3.5;
 
 impl Plugin for PlayerPlugin {
     fn build(&self, app: &mut App) {
+        app.add_event::<relic::RelicTriggerEvent>();
+
         app.add_systems(OnEnter(SceneState::PreClassicMode), initiate::initiate_player);
         app.add_systems(OnEnter(SceneState::PreSurvivalMode), initiate::initiate_player);
 
@@ -42,14 +47,35 @@ impl Plugin for PlayerPlugin {
             ui::hearts_handle,
             ui::skill_duration_handle,
             ui::skill_cooldown_handle,
+            ui::weapon_slots_handle,
             ui::information_texts_handle.after(stats::update_stats),
             health::end_run_check,
             profile::finish_run,
             skill::cooldown,
             skill::duration,
-            skill::knight_skill
+            skill::kill_counter_skill,
+            dash::dash_cooldown
         ).run_if(in_state(SceneState::InGameClassicMode).or_else(in_state(SceneState::InGameSurvivalMode)).and_then(not(resource_exists::<PauseSceneData>()))));
 
+        app.add_systems(Update, (
+            relic::relics_collision,
+            collisions::gold_collision,
+            ui::relics_handle,
+            ui::gold_text_handle,
+            ui::potion_slots_handle,
+            ui::experience_bar_handle,
+            experience::level_up_handle,
+            effect::poison_damage.after(effect::update_effects)
+        ).run_if(in_state(SceneState::InGameClassicMode).or_else(in_state(SceneState::InGameSurvivalMode)).and_then(not(resource_exists::<PauseSceneData>()))));
+
+        app.add_systems(Update,
+            relic::relic_trigger_handle.run_if(in_state(SceneState::InGameClassicMode).or_else(in_state(SceneState::InGameSurvivalMode)))
+        );
+
+        app.add_systems(Update,
+            health::death_sequence_handle.after(invisible::hurt_duration_color).run_if(in_state(SceneState::InGameClassicMode).or_else(in_state(SceneState::InGameSurvivalMode)).and_then(resource_exists::<health::DeathSequence>()).and_then(not(resource_exists::<PauseSceneData>())))
+        );
+
         app.add_systems(Update, (
             animation::player_animation_system,
             collisions::monsters_collision_check
@@ -62,12 +88,18 @@ impl Plugin for PlayerPlugin {
 
         app.add_systems(OnExit(SceneState::InGameClassicMode), (
             cleanup::cleanup_player,
-            ui::cleanup
+            ui::cleanup,
+            health::cleanup_death_sequence,
+            relic::cleanup_relic_pickups,
+            cleanup::cleanup_gold
         ));
         app.add_systems(OnExit(SceneState::InGameSurvivalMode), (
             cleanup::cleanup_player,
             ui::cleanup,
-            cleanup::save_cleared_waves
+            cleanup::save_cleared_waves,
+            health::cleanup_death_sequence,
+            relic::cleanup_relic_pickups,
+            cleanup::cleanup_gold
         ));
     }
 }
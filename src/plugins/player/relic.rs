@@ -0,0 +1,128 @@
+use bevy::prelude::*;
+use bevy::sprite::collide_aabb::collide;
+use rand::Rng;
+
+use crate::components::player::PlayerComponent;
+use crate::components::player_list_effects::PlayerListEffectsComponent;
+use crate::components::player_relics::PlayerRelicsComponent;
+use crate::components::relic_pickup::RelicPickupComponent;
+use crate::materials::ingame::InGameMaterials;
+use crate::plugins::player::{PLAYER_SIZE_HEIGHT, PLAYER_SIZE_WIDTH};
+use crate::resources::relic::relic_trigger::RelicTrigger;
+use crate::resources::relic::Relic;
+
+const RELIC_PICKUP_SIZE: f32 = 16.0 * 2.5;
+
+#[derive(Event)]
+pub struct RelicTriggerEvent(pub RelicTrigger);
+
+pub fn relic_trigger_handle(
+    mut relic_trigger_events: EventReader<RelicTriggerEvent>,
+    mut player_query: Query<(
+        &mut PlayerComponent,
+        &mut PlayerListEffectsComponent,
+        &PlayerRelicsComponent,
+    )>,
+) {
+    let (mut player, mut player_list_effects, player_relics) = player_query.single_mut();
+    let mut rng = rand::thread_rng();
+
+    for relic_trigger_event in relic_trigger_events.read() {
+        for relic in player_relics.relics.iter() {
+            if relic.trigger != Some(relic_trigger_event.0) {
+                continue;
+            }
+
+            if rng.gen_range(0.0..1.0) >= relic.trigger_chance.unwrap_or(1.0) {
+                continue;
+            }
+
+            if let Some(effect_id) = &relic.trigger_effect {
+                player_list_effects.activate(effect_id);
+            }
+
+            if player.current_health_points == 0.0 {
+                continue;
+            }
+
+            if let Some(heal) = relic.trigger_heal {
+                let new_health_points = player.current_health_points + heal;
+                player.current_health_points = if new_health_points > player.max_health_points {
+                    player.max_health_points
+                } else {
+                    new_health_points
+                };
+            }
+        }
+    }
+}
+
+pub fn relics_collision(
+    mut commands: Commands,
+    mut player_query: Query<(&mut PlayerRelicsComponent, &Transform)>,
+    relic_pickups_query: Query<
+        (Entity, &RelicPickupComponent, &Transform),
+        Without<PlayerRelicsComponent>,
+    >,
+) {
+    let (mut player_relics, player_transform) = player_query.single_mut();
+    let player_size = Vec2::new(PLAYER_SIZE_WIDTH, PLAYER_SIZE_HEIGHT);
+    let player_position = player_transform.translation;
+
+    for (relic_pickup_entity, relic_pickup, relic_pickup_transform) in relic_pickups_query.iter() {
+        let relic_pickup_size = Vec2::new(relic_pickup.width, relic_pickup.height);
+        let relic_pickup_position = relic_pickup_transform.translation;
+
+        if collide(
+            player_position,
+            player_size,
+            relic_pickup_position,
+            relic_pickup_size,
+        )
+        .is_some()
+        {
+            if !player_relics.contains(&relic_pickup.relic.name) {
+                player_relics.add(relic_pickup.relic.clone());
+            }
+            commands.entity(relic_pickup_entity).despawn_recursive();
+        }
+    }
+}
+
+pub fn spawn_relic_pickup(
+    commands: &mut Commands,
+    ingame_materials: &InGameMaterials,
+    relic: Relic,
+    position: Vec3,
+) {
+    let component_name = format!("{}Pickup", relic.name);
+
+    commands
+        .spawn(SpriteBundle {
+            texture: ingame_materials.relics_materials.relic.clone(),
+            sprite: Sprite {
+                custom_size: Some(Vec2::new(RELIC_PICKUP_SIZE, RELIC_PICKUP_SIZE)),
+                ..Default::default()
+            },
+            transform: Transform {
+                translation: Vec3::new(position.x, position.y, 0.15),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .insert(RelicPickupComponent {
+            relic,
+            width: RELIC_PICKUP_SIZE,
+            height: RELIC_PICKUP_SIZE,
+        })
+        .insert(Name::new(component_name));
+}
+
+pub fn cleanup_relic_pickups(
+    mut commands: Commands,
+    relic_pickups_query: Query<Entity, With<RelicPickupComponent>>,
+) {
+    for entity in relic_pickups_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
@@ -1,8 +1,27 @@
 use bevy::prelude::*;
+use std::time::Duration;
 
+use crate::components::invinsible_cooldown::InvisibleCooldownComponent;
+use crate::components::monster::MonsterComponent;
+use crate::components::monster_list_effects::MonsterListEffectsComponent;
 use crate::components::player::PlayerComponent;
 use crate::components::skill::SkillComponent;
-use crate::resources::skill::skill_type::SkillType;
+use crate::resources::game_data::GameData;
+use crate::resources::hero::power::Power;
+use crate::resources::skill::skill_target::SkillTarget;
+use crate::resources::skill::skill_trigger::SkillTrigger;
+
+pub type SkillTargetsQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static Transform,
+        &'static mut MonsterComponent,
+        &'static mut InvisibleCooldownComponent,
+        &'static mut MonsterListEffectsComponent,
+    ),
+    Without<PlayerComponent>,
+>;
 
 pub fn cooldown(time: Res<Time>, mut player_skill_query: Query<&mut SkillComponent>) {
     let mut player_skill = player_skill_query.single_mut();
@@ -18,19 +37,99 @@ pub fn duration(time: Res<Time>, mut player_skill_query: Query<&mut SkillCompone
     }
 }
 
-pub fn knight_skill(mut knight_query: Query<(&mut PlayerComponent, &mut SkillComponent)>) {
-    let (mut player, mut skill_component) = knight_query.single_mut();
-    if skill_component.skill.name == SkillType::Armor {
-        if skill_component.require_monsters == skill_component.monster_counter {
-            if player.current_health_points < player.max_health_points {
-                let new_health_points = player.current_health_points + 1.0;
-                player.current_health_points = if new_health_points > player.max_health_points {
-                    player.max_health_points
+pub fn kill_counter_skill(
+    mut player_query: Query<(&mut PlayerComponent, &mut SkillComponent, &Transform)>,
+    mut monsters_query: SkillTargetsQuery,
+    game_data: Res<GameData>,
+) {
+    let (mut player, mut player_skill, player_transform) = player_query.single_mut();
+    if player_skill.skill.trigger != SkillTrigger::KillCounter {
+        return;
+    }
+
+    if player_skill.monster_counter >= player_skill.require_monsters
+        && activate(
+            &mut player,
+            &mut player_skill,
+            player_transform.translation,
+            &mut monsters_query,
+            &game_data,
+        )
+    {
+        player_skill.monster_counter = 0;
+    }
+}
+
+pub fn activate(
+    player: &mut PlayerComponent,
+    player_skill: &mut SkillComponent,
+    player_position: Vec3,
+    monsters_query: &mut SkillTargetsQuery,
+    game_data: &GameData,
+) -> bool {
+    let skill = player_skill.skill.clone();
+
+    if skill.heal.is_some() && player.current_health_points >= player.max_health_points {
+        return false;
+    }
+
+    if let Some(require_health) = skill.require_health_points {
+        if player.current_health_points <= require_health {
+            return false;
+        }
+        player.current_health_points -= require_health;
+    }
+
+    if let Some(heal) = skill.heal {
+        let new_health_points = player.current_health_points + heal;
+        player.current_health_points = if new_health_points > player.max_health_points {
+            player.max_health_points
+        } else {
+            new_health_points
+        };
+    }
+
+    if let Some(target) = skill.target {
+        let damage = match &skill.damage {
+            Some(skill_damage) => {
+                let power = match skill_damage.power {
+                    Power::Strength => player.strength,
+                    Power::Intelligence => player.intelligence,
+                };
+                power * skill_damage.multiplier
+            }
+            None => 0.0,
+        };
+
+        for (transform, mut monster, mut invincible_cooldown, mut monster_list_effects) in
+            monsters_query.iter_mut()
+        {
+            if target == SkillTarget::MonstersInArea {
+                let area = skill.area.unwrap_or(0.0);
+                if transform.translation.truncate().distance(player_position.truncate()) > area {
+                    continue;
+                }
+            }
+
+            if damage > 0.0 {
+                monster.current_health_points = if monster.current_health_points < damage {
+                    0.0
                 } else {
-                    new_health_points
+                    monster.current_health_points - damage
                 };
-                skill_component.monster_counter = 0;
+                invincible_cooldown.hurt_duration =
+                    Timer::new(Duration::from_secs_f32(0.2), TimerMode::Once);
+            }
+
+            if let Some(effect_id) = &skill.apply_effect {
+                monster_list_effects.activate(game_data.get_effect(effect_id).effect_type);
             }
         }
     }
+
+    if let Some(duration) = skill.duration {
+        player_skill.duration = Timer::new(Duration::from_secs(duration as u64), TimerMode::Once);
+    }
+
+    true
 }
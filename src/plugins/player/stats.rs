@@ -2,18 +2,19 @@ use bevy::prelude::*;
 
 use crate::components::player::PlayerComponent;
 use crate::components::player_list_effects::PlayerListEffectsComponent;
+use crate::components::player_relics::PlayerRelicsComponent;
 use crate::components::skill::SkillComponent;
 use crate::resources::effect::effect_type::EffectType;
-use crate::resources::skill::skill_type::SkillType;
 
 pub fn update_stats(
     mut player_query: Query<(
         &mut PlayerComponent,
         &SkillComponent,
         &PlayerListEffectsComponent,
+        &PlayerRelicsComponent,
     )>,
 ) {
-    let (mut player, player_skill, player_list_effects) = player_query.single_mut();
+    let (mut player, player_skill, player_list_effects, player_relics) = player_query.single_mut();
 
     let mut speed_percent_bonus = 0.0;
     let mut damage_percent_bonus = 0.0;
@@ -21,29 +22,29 @@ pub fn update_stats(
     let mut dodge_chance_bonus = 0.0;
     let mut restore_chance_bonus = 0.0;
 
-    if player_skill.skill.name == SkillType::TimeToHunt && !player_skill.duration.finished() {
-        speed_percent_bonus += player_skill.skill.speed_percent_bonus.unwrap();
-        critical_chance_bonus += player_skill.skill.critical_chance_bonus.unwrap();
-        dodge_chance_bonus = 1.0;
+    if !player_skill.duration.finished() {
+        let skill = &player_skill.skill;
+        speed_percent_bonus += skill.speed_percent_bonus.unwrap_or(0.0);
+        critical_chance_bonus += skill.critical_chance_bonus.unwrap_or(0.0);
+        dodge_chance_bonus += skill.dodge_chance_bonus.unwrap_or(0.0);
+        restore_chance_bonus += skill.restore_chance_bonus.unwrap_or(0.0);
+        damage_percent_bonus += skill.damge_precent_bonus.unwrap_or(0.0);
     }
 
-    if player_skill.skill.name == SkillType::AnimalInstinct && !player_skill.duration.finished() {
-        speed_percent_bonus += player_skill.skill.speed_percent_bonus.unwrap();
-        critical_chance_bonus += player_skill.skill.critical_chance_bonus.unwrap();
-        restore_chance_bonus += player_skill.skill.restore_chance_bonus.unwrap();
-        damage_percent_bonus += player_skill.skill.damge_precent_bonus.unwrap();
+    for relic in player_relics.relics.iter() {
+        speed_percent_bonus += relic.speed_percent_bonus.unwrap_or(0.0);
+        critical_chance_bonus += relic.critical_chance_bonus.unwrap_or(0.0);
+        dodge_chance_bonus += relic.dodge_chance_bonus.unwrap_or(0.0);
+        restore_chance_bonus += relic.restore_chance_bonus.unwrap_or(0.0);
+        damage_percent_bonus += relic.damage_percent_bonus.unwrap_or(0.0);
     }
 
-    for (effect_type, duration) in player_list_effects.activated_effects.iter() {
+    for (effect_id, duration) in player_list_effects.activated_effects.iter() {
         if !duration.finished() {
-            let bonus = player_list_effects
-                .information
-                .iter()
-                .find(|effect_information| effect_information.name == *effect_type)
-                .unwrap()
-                .bonus;
+            let information = player_list_effects.get(effect_id);
+            let bonus = information.bonus + player_relics.effect_bonus(effect_id);
 
-            match effect_type {
+            match information.effect_type {
                 EffectType::SpeedUp | EffectType::Slow => {
                     speed_percent_bonus += bonus;
                 }
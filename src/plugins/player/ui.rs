@@ -3,12 +3,17 @@ use std::slice::Iter;
 // use bevy::ui::ContentSize;
 
 use crate::components::player::PlayerComponent;
+use crate::components::player_experience::PlayerExperienceComponent;
+use crate::components::player_relics::PlayerRelicsComponent;
+use crate::components::potion_belt::{PotionBeltComponent, POTION_BELT_SLOTS};
 use crate::components::skill::SkillComponent;
+use crate::components::weapon_inventory::{WeaponInventoryComponent, WEAPON_INVENTORY_SLOTS};
 use crate::config::{RESOLUTION, WINDOW_HEIGHT};
 use crate::materials::font::FontMaterials;
 use crate::materials::ingame::InGameMaterials;
 use crate::resources::dictionary::Dictionary;
-use crate::resources::skill::skill_type::SkillType;
+use crate::resources::profile::Profile;
+use crate::resources::skill::skill_trigger::SkillTrigger;
 
 #[derive(Component, Clone)]
 pub enum InformationTextComponent {
@@ -50,6 +55,38 @@ pub struct SkillDurationComponent;
 #[derive(Component)]
 pub struct SkillCooldownComponent;
 
+#[derive(Component)]
+pub struct WeaponSlotComponent {
+    index: usize,
+}
+
+#[derive(Component)]
+pub struct WeaponSlotImageComponent {
+    index: usize,
+}
+
+#[derive(Component)]
+pub struct PotionSlotImageComponent {
+    index: usize,
+}
+
+#[derive(Component)]
+pub struct PotionSlotTextComponent {
+    index: usize,
+}
+
+#[derive(Component)]
+pub struct RelicListComponent;
+
+#[derive(Component)]
+pub struct GoldTextComponent;
+
+#[derive(Component)]
+pub struct ExperienceBarComponent;
+
+#[derive(Component)]
+pub struct ExperienceTextComponent;
+
 #[derive(Resource)]
 pub struct PlayerUIData {
     user_interface_root: Entity,
@@ -77,6 +114,11 @@ pub fn setup(
             information_texts(parent, &font_materials, &dictionary);
             skill_cooldown(parent);
             skill_duration(parent);
+            weapon_slots(parent);
+            potion_slots(parent, &font_materials, &ingame_materials, &dictionary);
+            gold_text(parent, &font_materials, &dictionary);
+            experience_bar(parent, &font_materials, &dictionary);
+            relic_list(parent);
         })
         .insert(Name::new("PlayerUI"))
         .id();
@@ -368,7 +410,7 @@ pub fn skill_cooldown_handle(
     let player_skill = player_skill_query.single();
 
     let (mut style, mut visibility) = skill_cooldown_query.single_mut();
-    if player_skill.skill.name == SkillType::Armor {
+    if player_skill.skill.trigger == SkillTrigger::KillCounter {
         let require_monsters = player_skill.require_monsters as f32;
         let monster_counter = player_skill.monster_counter as f32;
         if monster_counter <= require_monsters {
@@ -388,3 +430,387 @@ pub fn skill_cooldown_handle(
         }
     }
 }
+
+fn weapon_slots(root: &mut ChildBuilder) {
+    let slot_size = 48.0;
+
+    root.spawn(NodeBundle {
+        style: Style {
+            position_type: PositionType::Absolute,
+            right: Val::Px(25.0),
+            bottom: Val::Px(10.0),
+            width: Val::Px((slot_size + 5.0) * WEAPON_INVENTORY_SLOTS as f32),
+            height: Val::Px(slot_size),
+            ..Default::default()
+        },
+        background_color: BackgroundColor(Color::NONE),
+        ..Default::default()
+    })
+    .with_children(|parent| {
+        for index in 0..WEAPON_INVENTORY_SLOTS {
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        position_type: PositionType::Absolute,
+                        left: Val::Px((slot_size + 5.0) * index as f32),
+                        top: Val::Px(0.0),
+                        width: Val::Px(slot_size),
+                        height: Val::Px(slot_size),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..Default::default()
+                    },
+                    background_color: BackgroundColor(Color::rgba(0.0, 0.0, 0.0, 0.5)),
+                    ..Default::default()
+                })
+                .with_children(|parent| {
+                    parent
+                        .spawn(ImageBundle {
+                            style: Style {
+                                width: Val::Px(slot_size / 2.0),
+                                height: Val::Px(slot_size - 8.0),
+                                ..Default::default()
+                            },
+                            visibility: Visibility::Hidden,
+                            ..Default::default()
+                        })
+                        .insert(WeaponSlotImageComponent { index });
+                })
+                .insert(WeaponSlotComponent { index })
+                .insert(Name::new(format!("WeaponSlot:{}", index)));
+        }
+    })
+    .insert(Name::new("WeaponSlots"));
+}
+
+pub fn weapon_slots_handle(
+    weapon_inventory_query: Query<Ref<WeaponInventoryComponent>>,
+    mut weapon_slot_query: Query<(&WeaponSlotComponent, &mut BackgroundColor)>,
+    mut weapon_slot_image_query: Query<(
+        &WeaponSlotImageComponent,
+        &mut UiImage,
+        &mut Style,
+        &mut Visibility,
+    )>,
+    ingame_materials: Res<InGameMaterials>,
+) {
+    let weapon_inventory = weapon_inventory_query.single();
+    if !weapon_inventory.is_changed() {
+        return;
+    }
+
+    for (weapon_slot, mut background_color) in weapon_slot_query.iter_mut() {
+        background_color.0 = if weapon_slot.index == weapon_inventory.current_index {
+            Color::rgba(1.0, 0.84, 0.0, 0.6)
+        } else {
+            Color::rgba(0.0, 0.0, 0.0, 0.5)
+        };
+    }
+
+    for (weapon_slot_image, mut ui_image, mut style, mut visibility) in
+        weapon_slot_image_query.iter_mut()
+    {
+        match weapon_inventory.weapons.get(weapon_slot_image.index) {
+            Some(weapon) => {
+                let height = 40.0;
+                ui_image.texture = ingame_materials.weapons_materials.get_texture(&weapon.sprite);
+                style.width = Val::Px(height * weapon.width / weapon.height);
+                style.height = Val::Px(height);
+                *visibility = Visibility::Inherited;
+            }
+            None => *visibility = Visibility::Hidden,
+        }
+    }
+}
+
+fn potion_slots(
+    root: &mut ChildBuilder,
+    font_materials: &FontMaterials,
+    ingame_materials: &InGameMaterials,
+    dictionary: &Dictionary,
+) {
+    let slot_size = 40.0;
+    let font = font_materials.get_font(dictionary.get_current_language());
+    let width = (slot_size + 5.0) * POTION_BELT_SLOTS.len() as f32;
+
+    root.spawn(NodeBundle {
+        style: Style {
+            position_type: PositionType::Absolute,
+            left: Val::Px(WINDOW_HEIGHT * RESOLUTION / 2.0 - width / 2.0),
+            bottom: Val::Px(20.0),
+            width: Val::Px(width),
+            height: Val::Px(slot_size),
+            ..Default::default()
+        },
+        background_color: BackgroundColor(Color::NONE),
+        ..Default::default()
+    })
+    .with_children(|parent| {
+        for (index, potion_type) in POTION_BELT_SLOTS.iter().enumerate() {
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        position_type: PositionType::Absolute,
+                        left: Val::Px((slot_size + 5.0) * index as f32),
+                        top: Val::Px(0.0),
+                        width: Val::Px(slot_size),
+                        height: Val::Px(slot_size),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..Default::default()
+                    },
+                    background_color: BackgroundColor(Color::rgba(0.0, 0.0, 0.0, 0.5)),
+                    ..Default::default()
+                })
+                .with_children(|parent| {
+                    parent
+                        .spawn(ImageBundle {
+                            style: Style {
+                                width: Val::Px(slot_size - 12.0),
+                                height: Val::Px(slot_size - 12.0),
+                                ..Default::default()
+                            },
+                            image: UiImage::new(
+                                ingame_materials.potions_materials.get_texture(*potion_type),
+                            ),
+                            ..Default::default()
+                        })
+                        .insert(PotionSlotImageComponent { index });
+
+                    parent.spawn(TextBundle {
+                        style: Style {
+                            position_type: PositionType::Absolute,
+                            left: Val::Px(2.0),
+                            top: Val::Px(0.0),
+                            ..Default::default()
+                        },
+                        text: Text::from_section(
+                            format!("{}", index + 1),
+                            TextStyle {
+                                font: font.clone(),
+                                font_size: 14.0,
+                                color: Color::GRAY,
+                            },
+                        ),
+                        ..Default::default()
+                    });
+
+                    parent
+                        .spawn(TextBundle {
+                            style: Style {
+                                position_type: PositionType::Absolute,
+                                right: Val::Px(2.0),
+                                bottom: Val::Px(0.0),
+                                ..Default::default()
+                            },
+                            text: Text::from_section(
+                                "0",
+                                TextStyle {
+                                    font: font.clone(),
+                                    font_size: 16.0,
+                                    color: Color::WHITE,
+                                },
+                            ),
+                            ..Default::default()
+                        })
+                        .insert(PotionSlotTextComponent { index });
+                })
+                .insert(Name::new(format!("PotionSlot:{}", index)));
+        }
+    })
+    .insert(Name::new("PotionSlots"));
+}
+
+pub fn potion_slots_handle(
+    potion_belt_query: Query<Ref<PotionBeltComponent>>,
+    mut potion_slot_image_query: Query<(&PotionSlotImageComponent, &mut BackgroundColor)>,
+    mut potion_slot_text_query: Query<(&PotionSlotTextComponent, &mut Text)>,
+) {
+    let potion_belt = potion_belt_query.single();
+    if !potion_belt.is_changed() {
+        return;
+    }
+
+    for (potion_slot_image, mut background_color) in potion_slot_image_query.iter_mut() {
+        background_color.0 = if potion_belt.counts[potion_slot_image.index] > 0 {
+            Color::WHITE
+        } else {
+            Color::rgba(1.0, 1.0, 1.0, 0.3)
+        };
+    }
+
+    for (potion_slot_text, mut text) in potion_slot_text_query.iter_mut() {
+        text.sections[0].value = format!("{}", potion_belt.counts[potion_slot_text.index]);
+    }
+}
+
+fn gold_text(root: &mut ChildBuilder, font_materials: &FontMaterials, dictionary: &Dictionary) {
+    let font = font_materials.get_font(dictionary.get_current_language());
+
+    root.spawn(TextBundle {
+        style: Style {
+            position_type: PositionType::Absolute,
+            left: Val::Px(10.0),
+            top: Val::Px(70.0),
+            ..Default::default()
+        },
+        text: Text::from_section(
+            "",
+            TextStyle {
+                font,
+                font_size: 20.0,
+                color: Color::GOLD,
+            },
+        ),
+        ..Default::default()
+    })
+    .insert(GoldTextComponent)
+    .insert(Name::new("GoldText"));
+}
+
+pub fn gold_text_handle(
+    mut gold_text_query: Query<&mut Text, With<GoldTextComponent>>,
+    profile: Res<Profile>,
+    dictionary: Res<Dictionary>,
+) {
+    let glossary = dictionary.get_glossary();
+    let mut text = gold_text_query.single_mut();
+    text.sections[0].value = format!("{}: {}", glossary.shop_text.gold, profile.gold);
+}
+
+fn experience_bar(root: &mut ChildBuilder, font_materials: &FontMaterials, dictionary: &Dictionary) {
+    let font = font_materials.get_font(dictionary.get_current_language());
+    let length = 300.0;
+
+    root.spawn(NodeBundle {
+        style: Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(5.0),
+            left: Val::Px(WINDOW_HEIGHT * RESOLUTION / 2.0 - length / 2.0),
+            width: Val::Px(length),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            ..Default::default()
+        },
+        background_color: BackgroundColor(Color::NONE),
+        ..Default::default()
+    })
+    .with_children(|parent| {
+        parent
+            .spawn(TextBundle {
+                text: Text::from_section(
+                    "",
+                    TextStyle {
+                        font,
+                        font_size: 18.0,
+                        color: Color::WHITE,
+                    },
+                ),
+                ..Default::default()
+            })
+            .insert(ExperienceTextComponent);
+
+        parent
+            .spawn(NodeBundle {
+                style: Style {
+                    width: Val::Px(length),
+                    height: Val::Px(8.0),
+                    ..Default::default()
+                },
+                background_color: BackgroundColor(Color::rgba(0.0, 0.0, 0.0, 0.5)),
+                ..Default::default()
+            })
+            .with_children(|parent| {
+                parent
+                    .spawn(NodeBundle {
+                        style: Style {
+                            width: Val::Percent(0.0),
+                            height: Val::Percent(100.0),
+                            ..Default::default()
+                        },
+                        background_color: BackgroundColor(Color::CYAN),
+                        ..Default::default()
+                    })
+                    .insert(ExperienceBarComponent);
+            });
+    })
+    .insert(Name::new("ExperienceBar"));
+}
+
+pub fn experience_bar_handle(
+    player_experience_query: Query<Ref<PlayerExperienceComponent>>,
+    mut experience_bar_query: Query<&mut Style, With<ExperienceBarComponent>>,
+    mut experience_text_query: Query<&mut Text, With<ExperienceTextComponent>>,
+    dictionary: Res<Dictionary>,
+) {
+    let player_experience = player_experience_query.single();
+    if !player_experience.is_changed() {
+        return;
+    }
+
+    let percent =
+        player_experience.experience as f32 / player_experience.required_experience() as f32;
+    let mut style = experience_bar_query.single_mut();
+    style.width = Val::Percent(percent * 100.0);
+
+    let glossary = dictionary.get_glossary();
+    let mut text = experience_text_query.single_mut();
+    text.sections[0].value = format!("{} {}", glossary.ingame_text.level, player_experience.level);
+}
+
+fn relic_list(root: &mut ChildBuilder) {
+    root.spawn(NodeBundle {
+        style: Style {
+            position_type: PositionType::Absolute,
+            left: Val::Px(10.0),
+            top: Val::Px(95.0),
+            flex_direction: FlexDirection::Column,
+            ..Default::default()
+        },
+        background_color: BackgroundColor(Color::NONE),
+        ..Default::default()
+    })
+    .insert(RelicListComponent)
+    .insert(Name::new("RelicList"));
+}
+
+pub fn relics_handle(
+    mut commands: Commands,
+    player_relics_query: Query<Ref<PlayerRelicsComponent>>,
+    relic_list_query: Query<Entity, With<RelicListComponent>>,
+    font_materials: Res<FontMaterials>,
+    dictionary: Res<Dictionary>,
+) {
+    let player_relics = player_relics_query.single();
+    if !player_relics.is_changed() {
+        return;
+    }
+
+    let font = font_materials.get_font(dictionary.get_current_language());
+    let glossary = dictionary.get_glossary();
+    let relic_list = relic_list_query.single();
+
+    commands
+        .entity(relic_list)
+        .despawn_descendants()
+        .with_children(|parent| {
+            for relic in player_relics.relics.iter() {
+                let relic_name = match glossary.relics.get(&relic.name) {
+                    Some(localized_name) => localized_name.clone(),
+                    None => relic.name.clone(),
+                };
+
+                parent.spawn(TextBundle {
+                    text: Text::from_section(
+                        relic_name,
+                        TextStyle {
+                            font: font.clone(),
+                            font_size: 18.0,
+                            color: Color::WHITE,
+                        },
+                    ),
+                    ..Default::default()
+                });
+            }
+        });
+}
@@ -8,7 +8,6 @@ use crate::components::weapon::WeaponComponent;
 use crate::components::weapon_shoot_attack::WeaponShootAttackComponent;
 use crate::materials::ingame::InGameMaterials;
 use crate::resources::weapon::attack_type::AttackType;
-use crate::resources::weapon::weapon_type::WeaponType;
 
 pub fn spawn_bullet(
     mut weapon_query: Query<(
@@ -23,13 +22,10 @@ pub fn spawn_bullet(
 
     if weapon_component.attack_type == AttackType::Shoot {
         if weapon_shoot_attack.spawn_bullet {
-            let texture = match weapon_component.name {
-                WeaponType::Bow => ingame_materials.bullet_materials.arrow.clone(),
-                WeaponType::Spear => ingame_materials.weapons_materials.spear.clone(),
-                _ => ingame_materials.bullet_materials.bullet.clone(),
-            };
-
             let bullet_information = weapon_shoot_attack.bullet_information.clone();
+            let texture = ingame_materials
+                .bullet_materials
+                .get_texture(&bullet_information.sprite);
 
             let start_y = weapon_transform.translation.y;
             let start_x = weapon_transform.translation.x;
@@ -52,11 +48,9 @@ pub fn spawn_bullet(
                 PI / 2.0 + angle
             };
 
-            let color = match weapon_component.name {
-                WeaponType::Bow | WeaponType::Spear => Color::default(),
-                WeaponType::SmallWand => Color::GRAY,
-                WeaponType::MagicWand => Color::default(),
-                _ => Color::CYAN,
+            let color = match bullet_information.color {
+                Some([red, green, blue]) => Color::rgb(red, green, blue),
+                None => Color::default(),
             };
 
             weapon_shoot_attack.spawn_bullet = false;
@@ -2,6 +2,7 @@ use bevy::prelude::*;
 
 use crate::plugins::weapon::WeaponEntity;
 use crate::components::bullet::BulletComponent;
+use crate::components::weapon_pickup::WeaponPickupComponent;
 
 pub fn cleanup_weapon(mut commands: Commands, weapon_entity: Res<WeaponEntity>) {
     commands.entity(weapon_entity.entity).despawn_recursive();
@@ -11,4 +12,13 @@ pub fn cleanup_bullet(mut commands: Commands, bullets_query: Query<Entity, With<
     for entity in bullets_query.iter() {
         commands.entity(entity).despawn_recursive();
     }
-}
\ No newline at end of file
+}
+
+pub fn cleanup_weapon_pickups(
+    mut commands: Commands,
+    weapon_pickups_query: Query<Entity, With<WeaponPickupComponent>>,
+) {
+    for entity in weapon_pickups_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
@@ -1,5 +1,6 @@
 use std::time::Duration;
 
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use bevy::sprite::collide_aabb::collide;
 use rand::Rng;
@@ -7,16 +8,41 @@ use rand::Rng;
 use crate::components::bullet::BulletComponent;
 use crate::components::invinsible_cooldown::InvisibleCooldownComponent;
 use crate::components::monster_list_effects::MonsterListEffectsComponent;
+use crate::components::player_relics::PlayerRelicsComponent;
+use crate::components::prop::PropComponent;
 use crate::components::weapon_shoot_attack::WeaponShootAttackComponent;
 use crate::components::{
     monster::MonsterComponent, player::PlayerComponent, weapon::WeaponComponent,
 };
+use crate::plugins::player::relic::RelicTriggerEvent;
+use crate::resources::game_data::GameData;
 use crate::resources::hero::power::Power;
+use crate::resources::relic::relic_trigger::RelicTrigger;
 use crate::resources::weapon::attack_type::AttackType;
 
+pub type BulletPropsQuery<'w, 's> = Query<
+    'w,
+    's,
+    (&'static mut PropComponent, &'static Transform),
+    (Without<BulletComponent>, Without<MonsterComponent>),
+>;
+
+pub type SwingPropsQuery<'w, 's> = Query<
+    'w,
+    's,
+    (&'static mut PropComponent, &'static Transform),
+    (Without<WeaponComponent>, Without<MonsterComponent>),
+>;
+
+#[derive(SystemParam)]
+pub struct WeaponHitContext<'w> {
+    relic_trigger_events: EventWriter<'w, RelicTriggerEvent>,
+    game_data: Res<'w, GameData>,
+}
+
 pub fn bullet_collision(
     mut commands: Commands,
-    player_query: Query<&PlayerComponent>,
+    player_query: Query<(&PlayerComponent, &PlayerRelicsComponent)>,
     weapon_query: Query<(&WeaponComponent, &WeaponShootAttackComponent)>,
     mut bullets_query: Query<
         (Entity, &Transform),
@@ -31,11 +57,14 @@ pub fn bullet_collision(
         ),
         (Without<BulletComponent>, With<MonsterComponent>),
     >,
+    mut props_query: BulletPropsQuery,
+    mut weapon_hit_context: WeaponHitContext,
 ) {
+    let game_data = &weapon_hit_context.game_data;
     let (weapon, weapon_shoot_attack) = weapon_query.single();
 
     if weapon.attack_type == AttackType::Shoot {
-        let player = player_query.single();
+        let (player, player_relics) = player_query.single();
 
         let mut damage = if player.power == Power::Intelligence {
             player.intelligence + weapon.intelligence
@@ -44,9 +73,17 @@ pub fn bullet_collision(
         };
 
         let mut rng = rand::thread_rng();
-        if rng.gen_range(0.0..1.0) < player.critical_chance {
+        let is_critical = rng.gen_range(0.0..1.0) < player.critical_chance;
+        if is_critical {
             damage += 1.0;
         }
+        damage += damage * player_relics.damage_percent_bonus();
+
+        let debuff_effect = weapon
+            .effect
+            .as_ref()
+            .map(|effect_id| game_data.get_effect(effect_id).effect_type)
+            .filter(|effect_type| !effect_type.is_buff());
 
         for (bullet_entity, bullet_transform) in bullets_query.iter_mut() {
             let mut bullet_position = bullet_transform.translation;
@@ -58,6 +95,7 @@ pub fn bullet_collision(
                 weapon_shoot_attack.bullet_information.height
                     * weapon_shoot_attack.bullet_information.scale,
             );
+            let mut is_hit = false;
 
             for (mut monster, mut monster_list_effects, mut invincible_cooldown, transform) in
                 monsters_query.iter_mut()
@@ -66,12 +104,9 @@ pub fn bullet_collision(
                 let monster_position = transform.translation;
 
                 if collide(bullet_position, bullet_size, monster_position, monster_size).is_some() {
-                    let debuff_effect = weapon.debuff_effect;
-                    let trigger_chance = weapon.trigger_chance;
-
-                    if debuff_effect != None && trigger_chance > 0.0 {
-                        if rng.gen_range(0.0..1.0) < trigger_chance {
-                            monster_list_effects.activate(debuff_effect.unwrap());
+                    if let Some(effect_type) = debuff_effect {
+                        if rng.gen_range(0.0..1.0) < weapon.trigger_chance {
+                            monster_list_effects.activate(effect_type);
                         }
                     }
 
@@ -84,6 +119,27 @@ pub fn bullet_collision(
                         monster.current_health_points - damage
                     };
 
+                    if is_critical {
+                        weapon_hit_context
+                            .relic_trigger_events
+                            .send(RelicTriggerEvent(RelicTrigger::OnCrit));
+                    }
+
+                    commands.entity(bullet_entity).despawn_recursive();
+                    is_hit = true;
+                    break;
+                }
+            }
+
+            if is_hit {
+                continue;
+            }
+
+            for (mut prop, transform) in props_query.iter_mut() {
+                let prop_size = Vec2::new(prop.width, prop.height);
+
+                if collide(bullet_position, bullet_size, transform.translation, prop_size).is_some() {
+                    prop.hurt(damage);
                     commands.entity(bullet_entity).despawn_recursive();
                     break;
                 }
@@ -93,7 +149,7 @@ pub fn bullet_collision(
 }
 
 pub fn swing_weapon_collision(
-    player_query: Query<&PlayerComponent>,
+    player_query: Query<(&PlayerComponent, &PlayerRelicsComponent)>,
     weapon_query: Query<
         (&WeaponComponent, &Transform),
         (Without<MonsterComponent>, With<WeaponComponent>),
@@ -107,10 +163,13 @@ pub fn swing_weapon_collision(
         ),
         (Without<WeaponComponent>, With<MonsterComponent>),
     >,
+    mut props_query: SwingPropsQuery,
+    mut weapon_hit_context: WeaponHitContext,
 ) {
+    let game_data = &weapon_hit_context.game_data;
     let (weapon, weapon_transform) = weapon_query.single();
     if weapon.attack_type == AttackType::Swing {
-        let player = player_query.single();
+        let (player, player_relics) = player_query.single();
 
         let mut weapon_position = weapon_transform.translation;
         weapon_position.z = 0.16;
@@ -126,9 +185,17 @@ pub fn swing_weapon_collision(
         };
 
         let mut rng = rand::thread_rng();
-        if rng.gen_range(0.0..1.0) < player.critical_chance {
+        let is_critical = rng.gen_range(0.0..1.0) < player.critical_chance;
+        if is_critical {
             damage += 1.0;
         }
+        damage += damage * player_relics.damage_percent_bonus();
+
+        let debuff_effect = weapon
+            .effect
+            .as_ref()
+            .map(|effect_id| game_data.get_effect(effect_id).effect_type)
+            .filter(|effect_type| !effect_type.is_buff());
 
         for (mut monster, mut monster_list_effects, mut invincible_cooldown, transform) in
             monsters_query.iter_mut()
@@ -136,15 +203,13 @@ pub fn swing_weapon_collision(
             let monster_size = Vec2::new(monster.width, monster.height);
             let monster_position = transform.translation;
             if collide(weapon_position, weapon_size, monster_position, monster_size).is_some() {
-                let debuff_effect = weapon.debuff_effect;
-                let trigger_chance = weapon.trigger_chance;
-
-                if debuff_effect != None && trigger_chance > 0.0 {
-                    if rng.gen_range(0.0..1.0) < trigger_chance {
-                        monster_list_effects.activate(debuff_effect.unwrap());
+                if let Some(effect_type) = debuff_effect {
+                    if rng.gen_range(0.0..1.0) < weapon.trigger_chance {
+                        monster_list_effects.activate(effect_type);
                     }
                 }
 
+                let is_new_hit = invincible_cooldown.hurt_duration.finished();
                 invincible_cooldown.hurt_duration = Timer::new(Duration::from_secs_f32(0.3), TimerMode::Once);
 
                 monster.current_health_points = if damage > monster.current_health_points {
@@ -152,6 +217,21 @@ pub fn swing_weapon_collision(
                 } else {
                     monster.current_health_points - damage
                 };
+
+                if is_critical && is_new_hit {
+                    weapon_hit_context
+                        .relic_trigger_events
+                        .send(RelicTriggerEvent(RelicTrigger::OnCrit));
+                }
+            }
+        }
+
+        for (mut prop, transform) in props_query.iter_mut() {
+            let prop_size = Vec2::new(prop.width, prop.height);
+            if collide(weapon_position, weapon_size, transform.translation, prop_size).is_some()
+                && prop.hurt_duration.finished()
+            {
+                prop.hurt(damage);
             }
         }
     }
@@ -10,7 +10,6 @@ use crate::components::weapon_swing_attack::WeaponSwingAttackComponent;
 use crate::materials::ingame::InGameMaterials;
 use crate::plugins::camera::Orthographic2DCamera;
 use crate::resources::weapon::attack_type::AttackType;
-use crate::resources::weapon::weapon_type::WeaponType;
 
 pub fn attach_to_player(
     mut weapon_query: Query<(&WeaponComponent, &mut Transform), Without<PlayerComponent>>,
@@ -97,16 +96,8 @@ pub fn aim(
                     weapon_shoot_attack.cooldown.tick(time.delta());
                 }
 
-                if weapon_component.name == WeaponType::Bow {
-                    weapon_transform.rotation = Quat::from_rotation_z(rotate_z + PI / 2.0);
-                }
-
-                if weapon_component.name == WeaponType::Spear {
-                    weapon_transform.rotation = Quat::from_rotation_z(rotate_z);
-                }
-
-                if weapon_component.name == WeaponType::MagicSword {
-                    weapon_transform.rotation = Quat::from_rotation_z(rotate_z);
+                if let Some(aim_rotation) = weapon_component.aim_rotation {
+                    weapon_transform.rotation = Quat::from_rotation_z(rotate_z + aim_rotation);
                 }
 
                 weapon_shoot_attack.bullet_target_x = mouse_pos.x;
@@ -136,18 +127,6 @@ pub fn change_weapon_texture(
             AttackType::Shoot => Anchor::Center,
         };
 
-        *texture = match weapon.name {
-            WeaponType::ShortSword => ingame_materials.weapons_materials.short_sword.clone(),
-            WeaponType::Sword => ingame_materials.weapons_materials.sword.clone(),
-            WeaponType::BigMachete => ingame_materials.weapons_materials.machete.clone(),
-            WeaponType::SmallWand => ingame_materials.weapons_materials.small_wand.clone(),
-            WeaponType::MagicWand => ingame_materials.weapons_materials.magic_wand.clone(),
-            WeaponType::MagicSword => ingame_materials.weapons_materials.magic_sword.clone(),
-            WeaponType::Mace => ingame_materials.weapons_materials.mace.clone(),
-            WeaponType::BigHammer => ingame_materials.weapons_materials.big_hammer.clone(),
-            WeaponType::SmallHammer => ingame_materials.weapons_materials.small_hammer.clone(),
-            WeaponType::Bow => ingame_materials.weapons_materials.bow.clone(),
-            WeaponType::Spear => ingame_materials.weapons_materials.spear.clone(),
-        };
+        *texture = ingame_materials.weapons_materials.get_texture(&weapon.sprite);
     }
 }
@@ -11,7 +11,6 @@ use crate::resources::game_data::GameData;
 use crate::resources::profile::Profile;
 use crate::resources::weapon::attack_type::AttackType;
 use crate::resources::weapon::bullet::Bullet;
-use crate::resources::weapon::weapon_type::WeaponType;
 
 pub fn initiate_weapon(
     ingame_materials: Res<InGameMaterials>,
@@ -25,13 +24,9 @@ pub fn initiate_weapon(
     let weapon_width = weapon.width;
     let weapon_height = weapon.height;
 
-    let weapon_texture = match weapon.name {
-        WeaponType::Bow => ingame_materials.weapons_materials.bow.clone(),
-        WeaponType::ShortSword => ingame_materials.weapons_materials.short_sword.clone(),
-        WeaponType::SmallWand => ingame_materials.weapons_materials.small_wand.clone(),
-        WeaponType::SmallHammer => ingame_materials.weapons_materials.small_hammer.clone(),
-        _ => ingame_materials.weapons_materials.short_sword.clone(),
-    };
+    let weapon_texture = ingame_materials
+        .weapons_materials
+        .get_texture(&weapon.sprite);
 
     let scale = weapon.scale;
 
@@ -46,6 +41,8 @@ pub fn initiate_weapon(
         height: 0.0,
         speed: 0.0,
         scale: 0.0,
+        sprite: String::new(),
+        color: None,
     });
 
     let weapon_entity = commands
@@ -76,8 +73,10 @@ pub fn initiate_weapon(
             size_width: weapon_width,
             size_height: weapon_height,
             trigger_chance: weapon.trigger_chance.unwrap_or(0.0),
-            buff_effect: None,
-            debuff_effect: None,
+            effect: weapon.effect.clone(),
+            sprite: weapon.sprite.clone(),
+            aim_rotation: weapon.aim_rotation,
+            shoot_while_moving: weapon.shoot_while_moving,
         })
         .insert(WeaponSwingAttackComponent {
             attack_duration,
@@ -0,0 +1,54 @@
+use bevy::prelude::*;
+
+use crate::components::weapon::WeaponComponent;
+use crate::components::weapon_pickup::WeaponPickupComponent;
+use crate::components::weapon_shoot_attack::WeaponShootAttackComponent;
+use crate::components::weapon_swing_attack::WeaponSwingAttackComponent;
+use crate::materials::ingame::InGameMaterials;
+use crate::resources::weapon::Weapon;
+
+const WEAPON_PICKUP_SCALE: f32 = 2.0;
+
+pub fn equip(
+    weapon: &mut WeaponComponent,
+    swing_attack: &mut WeaponSwingAttackComponent,
+    shoot_attack: &mut WeaponShootAttackComponent,
+    raw_weapon: &Weapon,
+) {
+    weapon.upgrade_weapon(raw_weapon);
+    swing_attack.upgrade(raw_weapon);
+    shoot_attack.upgrade(raw_weapon);
+}
+
+pub fn spawn_weapon_pickup(
+    commands: &mut Commands,
+    ingame_materials: &InGameMaterials,
+    weapon: Weapon,
+    position: Vec3,
+) {
+    let width = weapon.width * WEAPON_PICKUP_SCALE;
+    let component_name = format!("{}Pickup", weapon.name.0);
+    let height = weapon.height * WEAPON_PICKUP_SCALE;
+
+    commands
+        .spawn(SpriteBundle {
+            texture: ingame_materials
+                .weapons_materials
+                .get_texture(&weapon.sprite),
+            sprite: Sprite {
+                custom_size: Some(Vec2::new(width, height)),
+                ..Default::default()
+            },
+            transform: Transform {
+                translation: Vec3::new(position.x, position.y, 0.15),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .insert(WeaponPickupComponent {
+            weapon,
+            width,
+            height,
+        })
+        .insert(Name::new(component_name));
+}
@@ -8,6 +8,7 @@ mod cleanup;
 mod collisions;
 mod feature;
 mod initiate;
+pub mod inventory;
 
 pub struct WeaponPlugin;
 
@@ -33,11 +34,13 @@ impl Plugin for WeaponPlugin {
 
         app.add_systems(OnExit(SceneState::InGameClassicMode),(
             cleanup::cleanup_weapon,
-            cleanup::cleanup_bullet
+            cleanup::cleanup_bullet,
+            cleanup::cleanup_weapon_pickups
         ));
         app.add_systems(OnExit(SceneState::InGameSurvivalMode),(
             cleanup::cleanup_weapon,
-            cleanup::cleanup_bullet
+            cleanup::cleanup_bullet,
+            cleanup::cleanup_weapon_pickups
         ));
     }
 }
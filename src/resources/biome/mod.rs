@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+
+use crate::resources::dungeon::hazard_type::HazardType;
+use crate::resources::monster::monster_class::MonsterClass;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Biome {
+    pub name: String,
+    pub floors: Vec<usize>,
+    pub floor_sprite: String,
+    pub wall_sprite: String,
+    pub border_sprites: Option<BiomeBorderSprites>,
+    pub tint: Option<[f32; 3]>,
+    pub monsters: Vec<MonsterClass>,
+    pub hazards: Vec<HazardType>,
+    pub music: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BiomeBorderSprites {
+    pub border_mid: String,
+    pub border_corner_top_left: String,
+    pub border_corner_top_right: String,
+    pub wall_left: String,
+    pub wall_right: String,
+    pub border_left: String,
+    pub border_right: String,
+}
+
+impl Biome {
+    pub fn get_sprites(&self) -> Vec<String> {
+        let mut sprites = vec![self.floor_sprite.clone(), self.wall_sprite.clone()];
+        if let Some(border_sprites) = &self.border_sprites {
+            sprites.extend([
+                border_sprites.border_mid.clone(),
+                border_sprites.border_corner_top_left.clone(),
+                border_sprites.border_corner_top_right.clone(),
+                border_sprites.wall_left.clone(),
+                border_sprites.wall_right.clone(),
+                border_sprites.border_left.clone(),
+                border_sprites.border_right.clone(),
+            ]);
+        }
+        sprites
+    }
+}
@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+
+use crate::resources::dungeon::hazard_type::HazardType;
+use crate::resources::effect::effect_id::EffectId;
+use crate::resources::monster::monster_class::MonsterClass;
+
+const MAX_RECORDED_HITS: usize = 3;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum HitSource {
+    Monster(MonsterClass),
+    Hazard(HazardType),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Hit {
+    pub source: HitSource,
+    pub damage: f32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DeathRecap {
+    pub is_player_dead: bool,
+    pub killed_by: Option<HitSource>,
+    pub last_hits: Vec<Hit>,
+    pub active_effects: Vec<EffectId>,
+    pub floor: Option<usize>,
+    pub rooms_cleared: Option<usize>,
+    pub wave: Option<usize>,
+}
+
+impl DeathRecap {
+    pub fn record_hit(&mut self, source: HitSource, damage: f32) {
+        if self.last_hits.len() == MAX_RECORDED_HITS {
+            self.last_hits.remove(0);
+        }
+
+        self.last_hits.push(Hit {
+            source: source.clone(),
+            damage,
+        });
+        self.killed_by = Some(source);
+    }
+}
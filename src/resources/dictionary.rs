@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::resources::glossary::Glossary;
 use crate::resources::language::Language;
+use crate::resources::mods::ModList;
 use crate::resources::setting::Setting;
 
 #[derive(Resource, Serialize, Deserialize, Debug, Clone)]
@@ -13,10 +14,10 @@ pub struct Dictionary {
 }
 
 impl Dictionary {
-    pub fn new(current_language: Language) -> Self {
+    pub fn new(current_language: Language, mod_list: &ModList) -> Self {
         Dictionary {
-            vi_glossary: Glossary::new(Language::VI),
-            en_glossary: Glossary::new(Language::EN),
+            vi_glossary: Glossary::new(Language::VI, mod_list),
+            en_glossary: Glossary::new(Language::EN, mod_list),
             current_language,
         }
     }
@@ -38,7 +39,8 @@ impl Dictionary {
 
 impl FromWorld for Dictionary {
     fn from_world(world: &mut World) -> Self {
-        let setting = world.get_resource_mut::<Setting>().unwrap();
-        Dictionary::new(setting.get_language())
+        let language = world.get_resource::<Setting>().unwrap().get_language();
+        let mod_list = world.get_resource::<ModList>().unwrap();
+        Dictionary::new(language, mod_list)
     }
 }
@@ -0,0 +1,39 @@
+use bevy::prelude::*;
+use rand::Rng;
+use std::time::Duration;
+
+const CHALLENGE_SECONDS_PER_MONSTER: f32 = 5.0;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ChallengeCondition {
+    TimeLimit,
+    NoHit,
+}
+
+#[derive(Resource)]
+pub struct ChallengeRoom {
+    pub condition: ChallengeCondition,
+    pub timer: Timer,
+    pub is_failed: bool,
+    pub is_completed: bool,
+}
+
+impl ChallengeRoom {
+    pub fn new(require_monster: i8) -> Self {
+        let condition = if rand::thread_rng().gen_bool(0.5) {
+            ChallengeCondition::TimeLimit
+        } else {
+            ChallengeCondition::NoHit
+        };
+
+        ChallengeRoom {
+            condition,
+            timer: Timer::new(
+                Duration::from_secs_f32(require_monster as f32 * CHALLENGE_SECONDS_PER_MONSTER),
+                TimerMode::Once,
+            ),
+            is_failed: false,
+            is_completed: false,
+        }
+    }
+}
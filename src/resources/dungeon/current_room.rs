@@ -0,0 +1,11 @@
+use bevy::prelude::*;
+
+use crate::resources::dungeon::position::Position;
+use crate::resources::dungeon::room::Room;
+
+#[derive(Resource)]
+pub struct CurrentRoom {
+    pub map_name: String,
+    pub position: Position,
+    pub room: Room,
+}
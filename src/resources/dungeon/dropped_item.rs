@@ -0,0 +1,16 @@
+use crate::resources::potion::potion_type::PotionType;
+use crate::resources::weapon::weapon_type::WeaponType;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DroppedItemType {
+    Key,
+    Potion(PotionType),
+    Weapon(WeaponType),
+}
+
+#[derive(Debug, Clone)]
+pub struct DroppedItem {
+    pub item_type: DroppedItemType,
+    pub x: f32,
+    pub y: f32,
+}
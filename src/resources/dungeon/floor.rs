@@ -1,38 +1,102 @@
-use std::collections::HashMap;
-use std::fs::File;
+use rand::seq::SliceRandom;
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, File};
 use std::io::BufRead;
 use std::io::BufReader;
 
+use crate::resources::dungeon::door::Door;
+use crate::resources::dungeon::dropped_item::DroppedItem;
 use crate::resources::dungeon::position::Position;
+use crate::resources::dungeon::room_access::RoomAccess;
+use crate::resources::dungeon::room_type::RoomType;
+use crate::resources::prop::prop_placement::PropPlacement;
+use crate::resources::tiled::{is_tiled_file, TiledMap};
+
+const DEFAULT_ROOM_ID: f32 = 1.0;
 
 #[derive(Clone)]
 pub struct Floor {
     pub map_name: String,
+    pub biome: String,
     pub map: Vec<Vec<f32>>,
     pub total_rows: usize,
     pub total_columns: usize,
     pub current_position: Position,
     pub end_room_position: Position,
     pub start_room_position: Position,
+    pub shop_room_position: Option<Position>,
     pub cleared_positions: HashMap<Position, usize>,
+    pub looted_positions: HashSet<Position>,
+    pub unlocked_positions: HashSet<Position>,
+    pub revealed_positions: HashSet<Position>,
+    pub props: HashMap<Position, Vec<PropPlacement>>,
+    pub dropped_items: HashMap<Position, Vec<DroppedItem>>,
     pub is_last_floor: bool,
 }
 
-const PREFIX: &str = "./assets/floors/";
-
 impl Floor {
-    pub fn new(file_name: String, is_last_floor: bool) -> Floor {
-        let path = format!("{}{}", PREFIX, file_name);
-        let map_name = path.clone();
+    pub fn new(path: String, biome: String, is_last_floor: bool) -> Floor {
+        let map = Floor::load_map(&path);
+        Floor::from_map(path, map, biome, is_last_floor)
+    }
+
+    pub fn load_map(path: &str) -> Vec<Vec<f32>> {
+        if is_tiled_file(path) {
+            return TiledMap::load(path).get_floor_map();
+        }
+
         let file = match File::open(path) {
             Ok(file) => file,
-            Err(err) => panic!("Can't open map file {}: {}", file_name, err.to_string()),
+            Err(err) => panic!("Can't open map file {}: {}", path, err.to_string()),
         };
 
         let reader = BufReader::new(file);
 
         let mut map: Vec<Vec<f32>> = Vec::new();
 
+        for line in reader.lines() {
+            let str_line = line.unwrap();
+            let str_numbers = str_line.split(" ");
+
+            let mut row: Vec<f32> = Vec::new();
+
+            for str_number in str_numbers {
+                let number: f32 = match str_number.parse::<f32>() {
+                    Ok(number) => number,
+                    Err(_) => panic!("Invalid room value {} in {}", str_number, path),
+                };
+                row.push(number);
+            }
+            map.push(row);
+        }
+
+        map
+    }
+
+    pub fn save_map(map: &[Vec<f32>], path: &str) {
+        let content = map
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|value| format!("{:.1}", value))
+                    .collect::<Vec<String>>()
+                    .join(" ")
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        match fs::write(path, content) {
+            Ok(_) => (),
+            Err(err) => panic!("Can't save map file {}: {}", path, err.to_string()),
+        }
+    }
+
+    pub fn from_map(
+        map_name: String,
+        map: Vec<Vec<f32>>,
+        biome: String,
+        is_last_floor: bool,
+    ) -> Floor {
         let mut start_room_position: Position = Position {
             row_index: 0,
             column_index: 0,
@@ -41,15 +105,16 @@ impl Floor {
             row_index: 0,
             column_index: 0,
         };
+        let mut shop_room_position: Option<Position> = None;
 
-        for (row_index, line) in reader.lines().enumerate() {
-            let str_line = line.unwrap();
-            let str_numbers = str_line.split(" ");
+        for (row_index, row) in map.iter().enumerate() {
+            for (column_index, number) in row.iter().enumerate() {
+                let number = *number;
 
-            let mut row: Vec<f32> = Vec::new();
-
-            for (column_index, str_number) in str_numbers.enumerate() {
-                let number: f32 = str_number.parse::<f32>().unwrap();
+                if number != 0.0 {
+                    RoomType::from_value(number);
+                    RoomAccess::from_value(number);
+                }
 
                 if number == number.floor() + 0.1 {
                     start_room_position = Position {
@@ -61,28 +126,167 @@ impl Floor {
                         row_index,
                         column_index,
                     }
+                } else if number == number.floor() + 0.3 {
+                    shop_room_position = Some(Position {
+                        row_index,
+                        column_index,
+                    });
                 }
-                row.push(number);
             }
-            map.push(row);
+        }
+
+        let mut marked_positions = vec![start_room_position, end_room_position];
+        marked_positions.extend(shop_room_position);
+
+        for position in marked_positions {
+            let value = map[position.row_index][position.column_index];
+            if RoomAccess::from_value(value) != RoomAccess::Open {
+                panic!("Room {} in {} can't be locked or secret", value, map_name);
+            }
         }
 
         let total_rows = map.len();
         let total_columns = map[0].len();
 
+        if shop_room_position.is_none() {
+            let mut available_positions: Vec<Position> = Vec::new();
+            for (row_index, row) in map.iter().enumerate() {
+                for (column_index, room_id) in row.iter().enumerate() {
+                    let position = Position {
+                        row_index,
+                        column_index,
+                    };
+
+                    if *room_id != 0.0
+                        && RoomType::from_value(*room_id) == RoomType::Normal
+                        && RoomAccess::from_value(*room_id) == RoomAccess::Open
+                        && position != start_room_position
+                        && position != end_room_position
+                    {
+                        available_positions.push(position);
+                    }
+                }
+            }
+
+            shop_room_position = available_positions.choose(&mut rand::thread_rng()).cloned();
+        }
+
         let mut cleared_positions = HashMap::new();
         cleared_positions.insert(start_room_position, 0);
 
         Floor {
             map_name,
+            biome,
             map,
             start_room_position,
             end_room_position,
+            shop_room_position,
             current_position: start_room_position,
             cleared_positions,
+            looted_positions: HashSet::new(),
+            unlocked_positions: HashSet::new(),
+            revealed_positions: HashSet::new(),
+            props: HashMap::new(),
+            dropped_items: HashMap::new(),
             total_rows,
             total_columns,
             is_last_floor,
         }
     }
+
+    pub fn get_room_type(&self, position: Position) -> RoomType {
+        if self.shop_room_position == Some(position) {
+            RoomType::Shop
+        } else if position == self.start_room_position || position == self.end_room_position {
+            RoomType::Normal
+        } else {
+            RoomType::from_value(self.map[position.row_index][position.column_index])
+        }
+    }
+
+    pub fn get_room_id(&self, position: Position) -> f32 {
+        Floor::get_room_id_from_value(self.map[position.row_index][position.column_index])
+    }
+
+    pub fn get_room_id_from_value(value: f32) -> f32 {
+        match value.floor() as i32 / 100 {
+            0 => DEFAULT_ROOM_ID,
+            room_id => room_id as f32,
+        }
+    }
+
+    pub fn get_room_access(&self, position: Position) -> RoomAccess {
+        RoomAccess::from_value(self.map[position.row_index][position.column_index])
+    }
+
+    pub fn get_neighbour(&self, position: Position, door: &Door) -> Option<Position> {
+        match door {
+            Door::Top if position.row_index > 0 => Some(Position {
+                row_index: position.row_index - 1,
+                column_index: position.column_index,
+            }),
+            Door::Bottom if position.row_index < self.total_rows - 1 => Some(Position {
+                row_index: position.row_index + 1,
+                column_index: position.column_index,
+            }),
+            Door::Left if position.column_index > 0 => Some(Position {
+                row_index: position.row_index,
+                column_index: position.column_index - 1,
+            }),
+            Door::Right if position.column_index < self.total_columns - 1 => Some(Position {
+                row_index: position.row_index,
+                column_index: position.column_index + 1,
+            }),
+            _ => None,
+        }
+    }
+
+    pub fn has_room(&self, position: Position) -> bool {
+        self.map[position.row_index][position.column_index] != 0.0
+            && (self.get_room_access(position) != RoomAccess::Secret
+                || self.revealed_positions.contains(&position))
+    }
+
+    pub fn has_neighbour(&self, position: Position, door: &Door) -> bool {
+        match self.get_neighbour(position, door) {
+            Some(neighbour) => self.has_room(neighbour),
+            None => false,
+        }
+    }
+
+    pub fn is_secret(&self, position: Position) -> bool {
+        self.map[position.row_index][position.column_index] != 0.0
+            && self.get_room_access(position) == RoomAccess::Secret
+            && !self.revealed_positions.contains(&position)
+    }
+
+    pub fn is_locked(&self, position: Position) -> bool {
+        self.map[position.row_index][position.column_index] != 0.0
+            && self.get_room_access(position) == RoomAccess::Locked
+            && !self.unlocked_positions.contains(&position)
+    }
+
+    pub fn total_locked_rooms(&self) -> usize {
+        let mut total_locked_rooms = 0;
+        for row_index in 0..self.total_rows {
+            for column_index in 0..self.total_columns {
+                let position = Position {
+                    row_index,
+                    column_index,
+                };
+                if self.is_locked(position) {
+                    total_locked_rooms += 1;
+                }
+            }
+        }
+        total_locked_rooms
+    }
+
+    pub fn is_combat_room(&self, position: Position) -> bool {
+        position != self.start_room_position
+            && matches!(
+                self.get_room_type(position),
+                RoomType::Normal | RoomType::Challenge
+            )
+    }
 }
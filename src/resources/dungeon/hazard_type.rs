@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Copy, Clone, PartialEq, Eq, Debug)]
+pub enum HazardType {
+    SpikeTrap,
+    ArrowTurret,
+    Mud,
+    Poison,
+}
+
+impl HazardType {
+    pub fn from_value(value: i32) -> Option<HazardType> {
+        match value {
+            10 => Some(HazardType::SpikeTrap),
+            11 => Some(HazardType::ArrowTurret),
+            12 => Some(HazardType::Mud),
+            13 => Some(HazardType::Poison),
+            _ => None,
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<HazardType> {
+        match name {
+            "SpikeTrap" => Some(HazardType::SpikeTrap),
+            "ArrowTurret" => Some(HazardType::ArrowTurret),
+            "Mud" => Some(HazardType::Mud),
+            "Poison" => Some(HazardType::Poison),
+            _ => None,
+        }
+    }
+
+    pub fn get_value(&self) -> i32 {
+        match self {
+            HazardType::SpikeTrap => 10,
+            HazardType::ArrowTurret => 11,
+            HazardType::Mud => 12,
+            HazardType::Poison => 13,
+        }
+    }
+}
@@ -1,28 +1,41 @@
 use bevy::prelude::*;
 
 use rand::distributions::{Distribution, Uniform};
-use std::fs::File;
-use std::io::{self, BufRead};
 
 pub mod block_type;
+pub mod challenge_room;
+pub mod current_room;
 pub mod door;
+pub mod dropped_item;
 pub mod doors;
 pub mod end_point;
 pub mod floor;
 pub mod ground;
+pub mod hazard_type;
+pub mod hazards;
 pub mod layer;
 pub mod position;
+pub mod props;
 pub mod room;
+pub mod room_access;
+pub mod room_type;
 pub mod rooms;
+pub mod shop_point;
+pub mod shrine_point;
+pub mod treasure_point;
+pub mod validation;
 pub mod wall;
 pub mod wall_type;
 pub mod walls;
 pub mod wave;
 
-use crate::config::LIST_FLOOR_FILE;
+use crate::resources::game_data::GameData;
+use crate::resources::mods::ModList;
+use crate::resources::playtest::Playtest;
 use floor::Floor;
+use position::Position;
 
-const TOTAL_DUNGEON_FLOORS: usize = 5;
+pub const TOTAL_DUNGEON_FLOORS: usize = 5;
 
 #[derive(Resource)]
 pub struct Dungeon {
@@ -31,14 +44,8 @@ pub struct Dungeon {
 }
 
 impl Dungeon {
-    pub fn new() -> Self {
-        let file = match File::open(LIST_FLOOR_FILE) {
-            Ok(file) => file,
-            Err(err) => panic!("Can't open list floor file: {}", err.to_string()),
-        };
-
-        let buffered = io::BufReader::new(file);
-        let lines: Vec<_> = buffered.lines().collect();
+    pub fn new(mod_list: &ModList, game_data: &GameData) -> Self {
+        let lines = mod_list.get_floor_files();
         let total_available_floors: usize = lines.len();
 
         let mut floors: Vec<Floor> = Vec::new();
@@ -63,11 +70,12 @@ impl Dungeon {
 
         for (index, line) in lines.into_iter().enumerate() {
             if floors_indexes.contains(&index) {
-                let map_name = line.unwrap();
+                let map_name = line;
+                let biome = game_data.get_floor_biome(floor_index).name;
                 if floor_index == TOTAL_DUNGEON_FLOORS - 1 {
-                    floors.push(Floor::new(map_name, true));
+                    floors.push(Floor::new(map_name, biome, true));
                 } else {
-                    floors.push(Floor::new(map_name, false));
+                    floors.push(Floor::new(map_name, biome, false));
                 }
                 floor_index += 1;
             }
@@ -80,4 +88,27 @@ impl Dungeon {
             current_floor,
         }
     }
+
+    pub fn playtest(playtest: &Playtest, game_data: &GameData) -> Self {
+        let room_id = playtest.room_id.expect("Playtest needs a room");
+        let biome = match &playtest.biome {
+            Some(biome) => game_data.get_biome(biome).name,
+            None => game_data.get_floor_biome(0).name,
+        };
+
+        let map = vec![vec![1.1, room_id * 100.0 + 1.0, 1.3, 1.2]];
+        let mut floor = Floor::from_map(format!("playtest {}", room_id), map, biome, true);
+
+        let room_position = Position {
+            row_index: 0,
+            column_index: 1,
+        };
+        floor.current_position = room_position;
+        floor.cleared_positions.insert(room_position, 1);
+
+        Dungeon {
+            floors: vec![floor.clone()],
+            current_floor: floor,
+        }
+    }
 }
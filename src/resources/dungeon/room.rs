@@ -1,8 +1,13 @@
-use std::fs::File;
+use bevy::prelude::*;
+use std::fs::{self, File};
 use std::io::BufRead;
 use std::io::BufReader;
+use std::path::Path;
 
-const PREFIX: &str = "./assets/rooms/";
+use crate::config::TILE_SIZE;
+use crate::resources::dungeon::door::Door;
+use crate::resources::game_data::GameData;
+use crate::resources::tiled::{is_tiled_file, TiledMap};
 
 #[derive(Clone)]
 pub struct Room {
@@ -11,15 +16,12 @@ pub struct Room {
 }
 
 impl Room {
-    pub fn new(file_name: String) -> Self {
-        let spilt_file_name: Vec<&str> = file_name.split(".").collect();
-
-        let id = spilt_file_name[0].parse::<f32>().unwrap();
+    pub fn new(path: String) -> Self {
+        let id = Room::get_id_from_path(&path);
 
-        let path = format!("{}{}", PREFIX, file_name);
-        let file = match File::open(path) {
+        let file = match File::open(&path) {
             Ok(file) => file,
-            Err(err) => panic!("Can't open room file {}: {}", file_name, err.to_string()),
+            Err(err) => panic!("Can't open room file {}: {}", path, err.to_string()),
         };
 
         let reader = BufReader::new(file);
@@ -33,7 +35,10 @@ impl Room {
             let mut row: Vec<i32> = Vec::new();
 
             for str_number in str_numbers {
-                let number: i32 = str_number.parse::<i32>().unwrap();
+                let number: i32 = match str_number.parse::<i32>() {
+                    Ok(number) => number,
+                    Err(_) => panic!("Invalid tile value {} in {}", str_number, path),
+                };
                 row.push(number);
             }
             tilemap.push(row);
@@ -41,4 +46,126 @@ impl Room {
 
         Room { id, tilemap }
     }
+
+    pub fn load(path: String, game_data: Option<&GameData>) -> Self {
+        if is_tiled_file(&path) {
+            Room {
+                id: Room::get_id_from_path(&path),
+                tilemap: TiledMap::load(&path).get_room_tilemap(game_data),
+            }
+        } else {
+            Room::new(path)
+        }
+    }
+
+    pub fn get_id_from_path(path: &str) -> f32 {
+        let file_name = Path::new(path).file_name().unwrap().to_string_lossy();
+        let spilt_file_name: Vec<&str> = file_name.split(".").collect();
+
+        match spilt_file_name[0].parse::<f32>() {
+            Ok(id) => id,
+            Err(_) => panic!("Invalid room file name {}, expected a number", path),
+        }
+    }
+
+    pub fn save(&self, path: &str) {
+        let content = self
+            .tilemap
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|value| value.to_string())
+                    .collect::<Vec<String>>()
+                    .join(" ")
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        match fs::write(path, content) {
+            Ok(_) => (),
+            Err(err) => panic!("Can't save room file {}: {}", path, err.to_string()),
+        }
+    }
+
+    pub fn get_width(&self) -> usize {
+        self.tilemap.iter().map(|row| row.len()).max().unwrap_or(0)
+    }
+
+    pub fn get_height(&self) -> usize {
+        self.tilemap.len()
+    }
+
+    pub fn get_size(&self) -> Vec2 {
+        Vec2::new(
+            self.get_width() as f32 * TILE_SIZE,
+            self.get_height() as f32 * TILE_SIZE,
+        )
+    }
+
+    pub fn get_tile_translation(&self, row_index: f32, column_index: f32) -> Vec2 {
+        let start_x = -(self.get_width() as f32 - 1.0) * TILE_SIZE / 2.0;
+        let start_y = (self.get_height() as f32 - 1.0) * TILE_SIZE / 2.0;
+
+        Vec2::new(
+            start_x + column_index * TILE_SIZE,
+            start_y - row_index * TILE_SIZE,
+        )
+    }
+
+    pub fn get_center(&self) -> Vec2 {
+        self.get_tile_translation(
+            (self.get_height() / 2) as f32,
+            (self.get_width() / 2) as f32,
+        )
+    }
+
+    pub fn get_door_translation(&self, door: &Door) -> Option<Vec2> {
+        let tiles = self.get_door_tiles(door);
+
+        if tiles.is_empty() {
+            return None;
+        }
+
+        let middle = tiles.iter().sum::<usize>() as f32 / tiles.len() as f32;
+        let last_row = self.get_height() as f32 - 1.0;
+        let last_column = self.get_width() as f32 - 1.0;
+
+        Some(match door {
+            Door::Left => self.get_tile_translation(middle, 0.0),
+            Door::Right => self.get_tile_translation(middle, last_column),
+            Door::Top => self.get_tile_translation(0.5, middle),
+            Door::Bottom => self.get_tile_translation(last_row - 0.5, middle),
+        })
+    }
+
+    pub fn is_doorway(&self, row_index: usize, column_index: usize) -> bool {
+        self.get_door_tiles(&Door::Left).contains(&row_index)
+            || self.get_door_tiles(&Door::Right).contains(&row_index)
+            || self.get_door_tiles(&Door::Top).contains(&column_index)
+            || self.get_door_tiles(&Door::Bottom).contains(&column_index)
+    }
+
+    fn get_door_tiles(&self, door: &Door) -> Vec<usize> {
+        let height = self.get_height();
+        let width = self.get_width();
+
+        if height < 2 || width < 2 {
+            return Vec::new();
+        }
+
+        match door {
+            Door::Left => (0..height)
+                .filter(|row_index| self.tilemap[*row_index].first() == Some(&-7))
+                .collect(),
+            Door::Right => (0..height)
+                .filter(|row_index| self.tilemap[*row_index].get(width - 1) == Some(&-8))
+                .collect(),
+            Door::Top => (0..width)
+                .filter(|column_index| self.tilemap[1].get(*column_index) == Some(&-1))
+                .collect(),
+            Door::Bottom => (0..width)
+                .filter(|column_index| self.tilemap[height - 1].get(*column_index) == Some(&-1))
+                .collect(),
+        }
+    }
 }
@@ -0,0 +1,24 @@
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum RoomAccess {
+    Open,
+    Locked,
+    Secret,
+}
+
+impl RoomAccess {
+    pub fn from_value(value: f32) -> RoomAccess {
+        match RoomAccess::try_from_value(value) {
+            Some(room_access) => room_access,
+            None => panic!("Unknown room access: {}", value),
+        }
+    }
+
+    pub fn try_from_value(value: f32) -> Option<RoomAccess> {
+        match value.floor() as i32 / 10 % 10 {
+            0 => Some(RoomAccess::Open),
+            1 => Some(RoomAccess::Locked),
+            2 => Some(RoomAccess::Secret),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,27 @@
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum RoomType {
+    Normal,
+    Treasure,
+    Shrine,
+    Challenge,
+    Shop,
+}
+
+impl RoomType {
+    pub fn from_value(value: f32) -> RoomType {
+        match RoomType::try_from_value(value) {
+            Some(room_type) => room_type,
+            None => panic!("Unknown room type: {}", value),
+        }
+    }
+
+    pub fn try_from_value(value: f32) -> Option<RoomType> {
+        match value.floor() as i32 % 10 {
+            1 => Some(RoomType::Normal),
+            2 => Some(RoomType::Treasure),
+            3 => Some(RoomType::Shrine),
+            4 => Some(RoomType::Challenge),
+            _ => None,
+        }
+    }
+}
@@ -1,9 +1,8 @@
 use bevy::prelude::*;
-use std::fs::File;
-use std::io::{self, BufRead};
 
-use crate::config::LIST_ROOM_FILE;
 use crate::resources::dungeon::room::Room;
+use crate::resources::game_data::GameData;
+use crate::resources::mods::ModList;
 
 #[derive(Resource, Clone)]
 pub struct Rooms {
@@ -11,25 +10,24 @@ pub struct Rooms {
 }
 
 impl Rooms {
-    pub fn new() -> Self {
-        let file = match File::open(LIST_ROOM_FILE) {
-            Ok(file) => file,
-            Err(err) => panic!("Can't open list room file: {}", err.to_string()),
-        };
-
-        let buffered = io::BufReader::new(file);
-        let lines: Vec<_> = buffered.lines().collect();
-
+    pub fn new(mod_list: &ModList, game_data: &GameData) -> Self {
         let mut rooms: Vec<Room> = Vec::new();
 
-        for line in lines {
-            let file_name = line.unwrap();
-            rooms.push(Room::new(file_name));
+        for path in mod_list.get_room_files() {
+            rooms.push(Room::load(path, Some(game_data)));
         }
 
+        Rooms::from_rooms(rooms)
+    }
+
+    pub fn from_rooms(rooms: Vec<Room>) -> Self {
         Rooms { rooms }
     }
 
+    pub fn has_room(&self, room_id: f32) -> bool {
+        self.rooms.iter().any(|room| room.id == room_id.floor())
+    }
+
     pub fn get_room(&self, room_id: f32) -> Room {
         let result = self
             .rooms
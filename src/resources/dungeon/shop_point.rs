@@ -0,0 +1,6 @@
+use bevy::prelude::*;
+
+#[derive(Component)]
+pub struct ShopPoint {
+    pub is_player_inside: bool,
+}
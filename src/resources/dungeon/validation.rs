@@ -0,0 +1,228 @@
+use std::collections::HashSet;
+
+use crate::resources::dungeon::door::Door;
+use crate::resources::dungeon::floor::Floor;
+use crate::resources::dungeon::hazard_type::HazardType;
+use crate::resources::dungeon::room::Room;
+use crate::resources::dungeon::room_access::RoomAccess;
+use crate::resources::dungeon::room_type::RoomType;
+use crate::resources::dungeon::rooms::Rooms;
+use crate::resources::game_data::GameData;
+
+pub const WALL_VALUES: [i32; 12] = [-1, 1, -2, 2, 3, 4, 5, 6, -7, 7, -8, 8];
+const SURVIVAL_ROOM_ID: f32 = 0.0;
+
+pub fn is_known_tile(value: i32, game_data: Option<&GameData>) -> bool {
+    value == 0
+        || WALL_VALUES.contains(&value)
+        || HazardType::from_value(value).is_some()
+        || match game_data {
+            Some(game_data) => game_data.get_prop_by_tile(value).is_some(),
+            None => true,
+        }
+}
+
+pub fn validate_room(room: &Room, game_data: Option<&GameData>) -> Vec<String> {
+    let mut errors = Vec::new();
+    let width = room.get_width();
+
+    if room.get_height() < 3 || width < 3 {
+        errors.push(format!("Room {} is smaller than 3x3", room.id));
+        return errors;
+    }
+
+    for (row_index, row) in room.tilemap.iter().enumerate() {
+        if row.len() != width {
+            errors.push(format!(
+                "Room {} row {} has {} tiles, expected {}",
+                room.id,
+                row_index,
+                row.len(),
+                width
+            ));
+        }
+
+        for (column_index, value) in row.iter().enumerate() {
+            if !is_known_tile(*value, game_data) {
+                errors.push(format!(
+                    "Room {} has unknown tile {} at {}:{}",
+                    room.id, value, row_index, column_index
+                ));
+            }
+        }
+    }
+
+    for door in Door::iterator() {
+        if room.id != SURVIVAL_ROOM_ID && room.get_door_translation(door).is_none() {
+            errors.push(format!("Room {} has no {:?} door gap", room.id, door));
+        }
+    }
+
+    errors
+}
+
+pub fn validate_floor(name: &str, map: &[Vec<f32>], rooms: &Rooms) -> Vec<String> {
+    let mut errors = Vec::new();
+    let width = map.iter().map(|row| row.len()).max().unwrap_or(0);
+
+    if map.is_empty() || width == 0 {
+        errors.push(format!("Floor {} is empty", name));
+        return errors;
+    }
+
+    let mut starts = Vec::new();
+    let mut ends = Vec::new();
+    let mut shops = Vec::new();
+
+    for (row_index, row) in map.iter().enumerate() {
+        if row.len() != width {
+            errors.push(format!(
+                "Floor {} row {} has {} rooms, expected {}",
+                name,
+                row_index,
+                row.len(),
+                width
+            ));
+        }
+
+        for (column_index, value) in row.iter().enumerate() {
+            if *value == 0.0 {
+                continue;
+            }
+
+            let marker = ((value - value.floor()) * 10.0).round() as i32;
+            let room_access = match (
+                RoomType::try_from_value(*value),
+                RoomAccess::try_from_value(*value),
+            ) {
+                (Some(_), Some(room_access)) if marker <= 3 => room_access,
+                _ => {
+                    errors.push(format!(
+                        "Floor {} has invalid room value {} at {}:{}",
+                        name, value, row_index, column_index
+                    ));
+                    continue;
+                }
+            };
+
+            let room_id = Floor::get_room_id_from_value(*value);
+            if !rooms.has_room(room_id) {
+                errors.push(format!(
+                    "Floor {} uses unknown room {} at {}:{}",
+                    name, room_id, row_index, column_index
+                ));
+            }
+
+            if marker != 0 && room_access != RoomAccess::Open {
+                errors.push(format!(
+                    "Floor {} room {} at {}:{} can't be locked or secret",
+                    name, value, row_index, column_index
+                ));
+            }
+
+            match marker {
+                1 => starts.push((row_index, column_index)),
+                2 => ends.push((row_index, column_index)),
+                3 => shops.push((row_index, column_index)),
+                _ => (),
+            }
+        }
+    }
+
+    if starts.len() != 1 {
+        errors.push(format!(
+            "Floor {} has {} start rooms, expected 1",
+            name,
+            starts.len()
+        ));
+    }
+    if ends.len() != 1 {
+        errors.push(format!(
+            "Floor {} has {} end rooms, expected 1",
+            name,
+            ends.len()
+        ));
+    }
+    if shops.len() > 1 {
+        errors.push(format!(
+            "Floor {} has {} shop rooms, expected at most 1",
+            name,
+            shops.len()
+        ));
+    }
+
+    if let Some(start) = starts.first() {
+        let connected = get_connected_rooms(map, *start, |_| true);
+        for (row_index, row) in map.iter().enumerate() {
+            for (column_index, value) in row.iter().enumerate() {
+                if *value != 0.0 && !connected.contains(&(row_index, column_index)) {
+                    errors.push(format!(
+                        "Floor {} room at {}:{} is not connected to the start room",
+                        name, row_index, column_index
+                    ));
+                }
+            }
+        }
+
+        // the end room and the shop must not need a key or a bomb to reach
+        let open = get_connected_rooms(map, *start, |value| {
+            RoomAccess::try_from_value(value) == Some(RoomAccess::Open)
+        });
+        for (room_name, (row_index, column_index)) in ends
+            .iter()
+            .map(|end| ("end", end))
+            .chain(shops.iter().map(|shop| ("shop", shop)))
+        {
+            if connected.contains(&(*row_index, *column_index))
+                && !open.contains(&(*row_index, *column_index))
+            {
+                errors.push(format!(
+                    "Floor {} {} room at {}:{} is only reachable through locked or secret rooms",
+                    name, room_name, row_index, column_index
+                ));
+            }
+        }
+    }
+
+    errors
+}
+
+fn get_connected_rooms(
+    map: &[Vec<f32>],
+    start: (usize, usize),
+    is_passable: impl Fn(f32) -> bool,
+) -> HashSet<(usize, usize)> {
+    let has_room = |row_index: usize, column_index: usize| {
+        map[row_index]
+            .get(column_index)
+            .is_some_and(|value| *value != 0.0 && is_passable(*value))
+    };
+
+    let mut visited = HashSet::new();
+    let mut stack = vec![start];
+
+    while let Some((row_index, column_index)) = stack.pop() {
+        if !visited.insert((row_index, column_index)) {
+            continue;
+        }
+
+        let mut neighbours = vec![(row_index, column_index + 1)];
+        if row_index > 0 {
+            neighbours.push((row_index - 1, column_index));
+        }
+        if row_index + 1 < map.len() {
+            neighbours.push((row_index + 1, column_index));
+        }
+        if column_index > 0 {
+            neighbours.push((row_index, column_index - 1));
+        }
+
+        for (row_index, column_index) in neighbours {
+            if has_room(row_index, column_index) {
+                stack.push((row_index, column_index));
+            }
+        }
+    }
+
+    visited
+}
@@ -0,0 +1,15 @@
+use bevy_inspector_egui::InspectorOptions;
+use serde::{Deserialize, Serialize};
+
+use crate::resources::effect::effect_type::EffectType;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, InspectorOptions)]
+#[serde(transparent)]
+pub struct EffectId(pub String);
+
+// the built-in definition of every effect type uses the type name as its id
+impl From<EffectType> for EffectId {
+    fn from(effect_type: EffectType) -> Self {
+        EffectId(format!("{:?}", effect_type))
+    }
+}
@@ -11,4 +11,14 @@ pub enum EffectType {
     Disarm,
     Confuse,
     Stun,
+    Poison,
+}
+
+impl EffectType {
+    pub fn is_buff(&self) -> bool {
+        matches!(
+            self,
+            EffectType::SpeedUp | EffectType::EvasionUp | EffectType::Focus
+        )
+    }
 }
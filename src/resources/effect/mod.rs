@@ -1,12 +1,15 @@
 use serde::{Deserialize, Serialize};
 
+pub mod effect_id;
 pub mod effect_type;
 
+use effect_id::EffectId;
 use effect_type::EffectType;
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct Effect {
-    pub name: EffectType,
+    pub name: EffectId,
+    pub effect_type: EffectType,
     pub duration: i64,
     pub bonus: f32,
 }
@@ -1,15 +1,26 @@
 use serde::{Deserialize, Serialize};
 use bevy::prelude::*;
-use std::fs::File;
-use std::io::prelude::*;
+use std::collections::HashSet;
 
 use crate::config::DATA_FILE;
+use crate::resources::biome::Biome;
+use crate::resources::dungeon::TOTAL_DUNGEON_FLOORS;
+use crate::resources::effect::effect_id::EffectId;
 use crate::resources::effect::Effect;
+use crate::resources::glossary::Glossary;
 use crate::resources::hero::hero_class::HeroClass;
 use crate::resources::hero::Hero;
+use crate::resources::meta_progression::achievement::{Achievement, Unlock};
+use crate::resources::meta_progression::meta_upgrade::MetaUpgrade;
+use crate::resources::mods::{ModList, DATA_ID_KEYS};
 use crate::resources::monster::Monster;
+use crate::resources::prop::Prop;
+use crate::resources::relic::Relic;
+use crate::resources::shop::ShopItem;
 use crate::resources::skill::Skill;
+use crate::resources::upgrade::UpgradesData;
 use crate::resources::weapon::weapon_type::WeaponType;
+use crate::resources::weapon::weapon_upgrade::WeaponUpgrade;
 use crate::resources::weapon::Weapon;
 
 #[derive(Resource)]
@@ -19,87 +30,449 @@ pub struct PauseSceneData {
 
 #[derive(Resource, Serialize, Deserialize, Debug, Clone)]
 pub struct GameData {
-    heroes: [Hero; 4],
-    weapons: [Weapon; 11],
-    skills: [Skill; 4],
-    player_list_effects_information: [Effect; 8],
-    monsters: [Monster; 10],
+    heroes: Vec<Hero>,
+    weapons: Vec<Weapon>,
+    weapon_upgrades: Vec<WeaponUpgrade>,
+    skills: Vec<Skill>,
+    player_list_effects_information: Vec<Effect>,
+    monsters: Vec<Monster>,
+    relics: Vec<Relic>,
+    shop_items: Vec<ShopItem>,
+    meta_upgrades: Vec<MetaUpgrade>,
+    achievements: Vec<Achievement>,
+    props: Vec<Prop>,
+    biomes: Vec<Biome>,
 }
 
 impl GameData {
-    pub fn new() -> Self {
-        let data = match File::open(DATA_FILE) {
-            Ok(mut file) => {
-                let mut contents = String::new();
-                file.read_to_string(&mut contents).unwrap();
-                serde_json::from_str(&contents).expect("JSON was not well-formatted")
-            }
-            Err(err) => panic!("Can't find language file: {}", err),
-        };
+    pub fn new(mod_list: &ModList) -> Self {
+        let data = mod_list.load_json(DATA_FILE, &DATA_ID_KEYS);
+        GameData::check_ids(&data);
         data
     }
 
+    fn check_ids(data: &GameData) {
+        let mut ids = HashSet::new();
+        let all_ids = data
+            .heroes
+            .iter()
+            .map(|hero| format!("hero {}", hero.hero_class.0))
+            .chain(data.weapons.iter().map(|weapon| format!("weapon {}", weapon.name.0)))
+            .chain(data.skills.iter().map(|skill| format!("skill {}", skill.name)))
+            .chain(
+                data.player_list_effects_information
+                    .iter()
+                    .map(|effect| format!("effect {}", effect.name.0)),
+            )
+            .chain(data.monsters.iter().map(|monster| format!("monster {}", monster.class.0)))
+            .chain(data.props.iter().map(|prop| format!("prop {}", prop.name)))
+            .chain(data.props.iter().map(|prop| format!("prop tile {}", prop.tile)))
+            .chain(data.biomes.iter().map(|biome| format!("biome {}", biome.name)))
+            .chain(
+                data.biomes
+                    .iter()
+                    .flat_map(|biome| biome.floors.iter())
+                    .map(|floor| format!("biome floor {}", floor)),
+            );
+
+        for id in all_ids {
+            if !ids.insert(id.clone()) {
+                panic!("Duplicate {} in {}", id, DATA_FILE);
+            }
+        }
+
+        let biome_errors = data.validate_biomes();
+        if !biome_errors.is_empty() {
+            panic!("{} in {}", biome_errors.join(", "), DATA_FILE);
+        }
+    }
+
+    fn validate_biomes(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+
+        for biome in self.biomes.iter() {
+            for monster_class in biome.monsters.iter() {
+                if !self.monsters.iter().any(|monster| monster.class == *monster_class) {
+                    errors.push(format!("Unknown monster {} in biome {}", monster_class.0, biome.name));
+                }
+            }
+
+            for floor in biome.floors.iter() {
+                let max_level = Monster::get_max_level(floor.saturating_sub(1));
+                if !self.monsters.iter().any(|monster| {
+                    monster.level <= max_level && biome.monsters.contains(&monster.class)
+                }) {
+                    errors.push(format!(
+                        "Biome {} has no monsters up to level {} for floor {}",
+                        biome.name, max_level, floor
+                    ));
+                }
+            }
+        }
+
+        for floor in 1..=TOTAL_DUNGEON_FLOORS {
+            if !self.biomes.iter().any(|biome| biome.floors.contains(&floor)) {
+                errors.push(format!("No biome for floor {}", floor));
+            }
+        }
+
+        errors
+    }
+
+    pub fn validate_references(&self) -> Vec<String> {
+        let mut errors = self.validate_biomes();
+
+        for hero in self.heroes.iter() {
+            if !self.has_skill(&hero.skill) {
+                errors.push(format!("Hero {} uses unknown skill {}", hero.hero_class.0, hero.skill));
+            }
+            if !hero.weapons.contains(&hero.weapon) {
+                errors.push(format!(
+                    "Hero {} starts with weapon {} which isn't in its weapons",
+                    hero.hero_class.0, hero.weapon.0
+                ));
+            }
+            for weapon_type in hero.weapons.iter().chain([&hero.weapon]) {
+                if !self.has_weapon(weapon_type) {
+                    errors.push(format!(
+                        "Hero {} uses unknown weapon {}",
+                        hero.hero_class.0, weapon_type.0
+                    ));
+                }
+            }
+        }
+
+        for weapon_upgrade in self.weapon_upgrades.iter() {
+            for weapon_type in [&weapon_upgrade.weapon]
+                .into_iter()
+                .chain(weapon_upgrade.upgrades_to.iter())
+            {
+                if !self.has_weapon(weapon_type) {
+                    errors.push(format!(
+                        "Weapon upgrade {} uses unknown weapon {}",
+                        weapon_upgrade.weapon.0, weapon_type.0
+                    ));
+                }
+            }
+
+            if weapon_upgrade.upgrades_to.len() > 1
+                && !self.heroes.iter().any(|hero| {
+                    hero.weapons.contains(&weapon_upgrade.weapon)
+                        && weapon_upgrade
+                            .upgrades_to
+                            .iter()
+                            .filter(|weapon_type| hero.weapons.contains(weapon_type))
+                            .count()
+                            > 1
+                })
+            {
+                errors.push(format!(
+                    "Weapon upgrade {} branches, but no hero can use more than one of its branches",
+                    weapon_upgrade.weapon.0
+                ));
+            }
+        }
+
+        let effects = self
+            .weapons
+            .iter()
+            .map(|weapon| (format!("Weapon {}", weapon.name.0), weapon.effect.clone()))
+            .chain(
+                self.skills
+                    .iter()
+                    .map(|skill| (format!("Skill {}", skill.name), skill.apply_effect.clone())),
+            )
+            .chain(
+                self.monsters
+                    .iter()
+                    .map(|monster| {
+                        (format!("Monster {}", monster.class.0), monster.trigger_effect.clone())
+                    }),
+            )
+            .chain(self.relics.iter().flat_map(|relic| {
+                [
+                    (format!("Relic {}", relic.name), relic.boosted_effect.clone()),
+                    (format!("Relic {}", relic.name), relic.trigger_effect.clone()),
+                ]
+            }));
+
+        for (owner, effect_id) in effects {
+            match effect_id {
+                Some(effect_id) if !self.has_effect(&effect_id) => {
+                    errors.push(format!("{} uses unknown effect {}", owner, effect_id.0))
+                }
+                _ => (),
+            }
+        }
+
+        for achievement in self.achievements.iter() {
+            match &achievement.unlock {
+                Unlock::Hero(hero_class) => {
+                    if !self.heroes.iter().any(|hero| hero.hero_class == *hero_class) {
+                        errors.push(format!(
+                            "Achievement {} unlocks unknown hero {}",
+                            achievement.name, hero_class.0
+                        ));
+                    }
+                }
+                Unlock::Weapon(weapon_type) => {
+                    if !self.has_weapon(weapon_type) {
+                        errors.push(format!(
+                            "Achievement {} unlocks unknown weapon {}",
+                            achievement.name, weapon_type.0
+                        ));
+                    }
+                }
+            }
+        }
+
+        errors
+    }
+
+    pub fn validate_upgrades(&self, upgrades_data: &UpgradesData) -> Vec<String> {
+        let mut errors = Vec::new();
+
+        for (index, upgrade) in upgrades_data.upgrades.iter().enumerate() {
+            if let Some(skill_upgrade) = &upgrade.skill_upgrade {
+                if !self.has_skill(&skill_upgrade.name) {
+                    errors.push(format!(
+                        "Upgrade {} uses unknown skill {}",
+                        index, skill_upgrade.name
+                    ));
+                }
+            }
+            if let Some(effect_upgrade) = &upgrade.effect_upgrade {
+                if !self.has_effect(&effect_upgrade.name) {
+                    errors.push(format!(
+                        "Upgrade {} uses unknown effect {}",
+                        index, effect_upgrade.name.0
+                    ));
+                }
+            }
+        }
+
+        errors
+    }
+
+    pub fn validate_glossary(&self, glossary: &Glossary) -> Vec<String> {
+        let mut errors = Vec::new();
+
+        for relic in self.relics.iter() {
+            if !glossary.relics.contains_key(&relic.name) {
+                errors.push(format!("Missing text for relic {}", relic.name));
+            }
+        }
+        for name in glossary.relics.keys() {
+            if !self.relics.iter().any(|relic| relic.name == *name) {
+                errors.push(format!("Text for unknown relic {}", name));
+            }
+        }
+
+        for achievement in self.achievements.iter() {
+            if !glossary.achievements.contains_key(&achievement.name) {
+                errors.push(format!("Missing text for achievement {}", achievement.name));
+            }
+        }
+        for name in glossary.achievements.keys() {
+            if !self
+                .achievements
+                .iter()
+                .any(|achievement| achievement.name == *name)
+            {
+                errors.push(format!("Text for unknown achievement {}", name));
+            }
+        }
+
+        for weapon in self.weapons.iter() {
+            if !glossary.weapons.contains_key(&weapon.name.0) {
+                errors.push(format!("Missing text for weapon {}", weapon.name.0));
+            }
+        }
+        for name in glossary.weapons.keys() {
+            if !self.weapons.iter().any(|weapon| weapon.name.0 == *name) {
+                errors.push(format!("Text for unknown weapon {}", name));
+            }
+        }
+
+        for monster in self.monsters.iter() {
+            if !glossary.monsters.contains_key(&monster.class.0) {
+                errors.push(format!("Missing text for monster {}", monster.class.0));
+            }
+        }
+        for name in glossary.monsters.keys() {
+            if !self.monsters.iter().any(|monster| monster.class.0 == *name) {
+                errors.push(format!("Text for unknown monster {}", name));
+            }
+        }
+
+        for effect in self.player_list_effects_information.iter() {
+            if !glossary.effects.contains_key(&effect.name.0) {
+                errors.push(format!("Missing text for effect {}", effect.name.0));
+            }
+        }
+        for name in glossary.effects.keys() {
+            if !self
+                .player_list_effects_information
+                .iter()
+                .any(|effect| effect.name.0 == *name)
+            {
+                errors.push(format!("Text for unknown effect {}", name));
+            }
+        }
+
+        errors
+    }
+
+    fn has_skill(&self, name: &str) -> bool {
+        self.skills.iter().any(|skill| skill.name == name)
+    }
+
+    fn has_weapon(&self, weapon_type: &WeaponType) -> bool {
+        self.weapons.iter().any(|weapon| weapon.name == *weapon_type)
+    }
+
+    fn has_effect(&self, effect_id: &EffectId) -> bool {
+        self.player_list_effects_information
+            .iter()
+            .any(|effect| effect.name == *effect_id)
+    }
+
     pub fn get_skill(&self, hero_class: HeroClass) -> Skill {
         let hero = self.get_hero(hero_class);
-        self.skills
-            .iter()
-            .find(|skill| skill.name == hero.skill)
-            .unwrap()
-            .clone()
+        match self.skills.iter().find(|skill| skill.name == hero.skill) {
+            Some(skill) => skill.clone(),
+            None => panic!("Unknown skill {:?}", hero.skill),
+        }
     }
 
     pub fn get_hero(&self, hero_class: HeroClass) -> Hero {
-        self.heroes
-            .iter()
-            .find(|hero| hero.hero_class == hero_class)
-            .unwrap()
-            .clone()
+        match self.heroes.iter().find(|hero| hero.hero_class == hero_class) {
+            Some(hero) => hero.clone(),
+            None => panic!("Unknown hero {:?}", hero_class),
+        }
+    }
+
+    pub fn get_heroes(&self) -> Vec<Hero> {
+        self.heroes.clone()
     }
 
     pub fn get_weapon(&self, hero_class: HeroClass) -> Weapon {
         let hero = self.get_hero(hero_class);
-        *self
-            .weapons
-            .iter()
-            .find(|weapon| weapon.name == hero.weapon)
-            .unwrap()
+        self.get_weapon_by_type(hero.weapon)
+    }
+
+    pub fn get_weapon_by_type(&self, weapon_type: WeaponType) -> Weapon {
+        match self.weapons.iter().find(|weapon| weapon.name == weapon_type) {
+            Some(weapon) => weapon.clone(),
+            None => panic!("Unknown weapon {:?}", weapon_type),
+        }
+    }
+
+    pub fn get_all_weapons(&self) -> Vec<Weapon> {
+        self.weapons.clone()
     }
 
     pub fn get_weapons(&self, hero_class: HeroClass) -> Vec<Weapon> {
-        let weapons_type: Vec<WeaponType> = match hero_class {
-            HeroClass::Elf => [WeaponType::Bow, WeaponType::Spear].to_vec(),
-            HeroClass::Knight => [
-                WeaponType::ShortSword,
-                WeaponType::Sword,
-                WeaponType::BigMachete,
-            ]
-            .to_vec(),
-            HeroClass::Wizard => [
-                WeaponType::SmallWand,
-                WeaponType::MagicWand,
-                WeaponType::MagicSword,
-            ]
-            .to_vec(),
-            HeroClass::Lizard => [
-                WeaponType::SmallHammer,
-                WeaponType::Mace,
-                WeaponType::BigHammer,
-            ]
-            .to_vec(),
-        };
+        let hero = self.get_hero(hero_class);
 
         self.weapons
             .iter()
-            .filter(|weapon| weapons_type.contains(&weapon.name))
+            .filter(|weapon| hero.weapons.contains(&weapon.name))
             .cloned()
             .collect()
     }
 
+    pub fn get_weapon_upgrades(&self, hero_class: HeroClass, weapon_type: WeaponType) -> Vec<Weapon> {
+        let hero = self.get_hero(hero_class);
+
+        match self
+            .weapon_upgrades
+            .iter()
+            .find(|weapon_upgrade| weapon_upgrade.weapon == weapon_type)
+        {
+            Some(weapon_upgrade) => self
+                .weapons
+                .iter()
+                .filter(|weapon| {
+                    weapon_upgrade.upgrades_to.contains(&weapon.name)
+                        && hero.weapons.contains(&weapon.name)
+                })
+                .cloned()
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
     pub fn get_player_list_effects_information(&self) -> Vec<Effect> {
-        self.player_list_effects_information.to_vec()
+        self.player_list_effects_information.clone()
+    }
+
+    pub fn get_effect(&self, effect_id: &EffectId) -> Effect {
+        match self
+            .player_list_effects_information
+            .iter()
+            .find(|effect| effect.name == *effect_id)
+        {
+            Some(effect) => effect.clone(),
+            None => panic!("Unknown effect {}", effect_id.0),
+        }
     }
 
     pub fn get_monsters(&self) -> Vec<Monster> {
-        self.monsters.to_vec()
+        self.monsters.clone()
+    }
+
+    pub fn get_relics(&self) -> Vec<Relic> {
+        self.relics.clone()
+    }
+
+    pub fn get_shop_items(&self) -> Vec<ShopItem> {
+        self.shop_items.clone()
+    }
+
+    pub fn get_meta_upgrades(&self) -> Vec<MetaUpgrade> {
+        self.meta_upgrades.clone()
+    }
+
+    pub fn get_achievements(&self) -> Vec<Achievement> {
+        self.achievements.clone()
+    }
+
+    pub fn get_biomes(&self) -> Vec<Biome> {
+        self.biomes.clone()
+    }
+
+    pub fn get_biome(&self, name: &str) -> Biome {
+        match self.biomes.iter().find(|biome| biome.name == name) {
+            Some(biome) => biome.clone(),
+            None => panic!("Unknown biome {}", name),
+        }
+    }
+
+    pub fn get_floor_biome(&self, floor_index: usize) -> Biome {
+        match self
+            .biomes
+            .iter()
+            .find(|biome| biome.floors.contains(&(floor_index + 1)))
+            .or(self.biomes.first())
+        {
+            Some(biome) => biome.clone(),
+            None => panic!("No biomes in {}", DATA_FILE),
+        }
+    }
+
+    pub fn get_props(&self) -> Vec<Prop> {
+        self.props.clone()
+    }
+
+    pub fn get_prop(&self, name: &str) -> Prop {
+        match self.props.iter().find(|prop| prop.name == name) {
+            Some(prop) => prop.clone(),
+            None => panic!("Unknown prop {}", name),
+        }
+    }
+
+    pub fn get_prop_by_tile(&self, tile: i32) -> Option<Prop> {
+        self.props.iter().find(|prop| prop.tile == tile).cloned()
     }
 }
@@ -1,9 +1,13 @@
 use serde::{Deserialize, Serialize};
-use std::fs::File;
-use std::io::prelude::*;
+use std::collections::HashMap;
 
 use crate::config::*;
+use crate::resources::death_recap::HitSource;
+use crate::resources::effect::effect_id::EffectId;
 use crate::resources::language::Language;
+use crate::resources::mods::ModList;
+use crate::resources::monster::monster_class::MonsterClass;
+use crate::resources::weapon::weapon_type::WeaponType;
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Glossary {
@@ -15,11 +19,22 @@ pub struct Glossary {
     pub help_scene_text: HelpSceneText,
     pub ingame_text: InGameText,
     pub shared_text: SharedText,
+    pub shop_text: ShopText,
+    pub reward_scene_text: RewardSceneText,
+    pub upgrade_text: UpgradeText,
+    pub meta_progression_scene_text: MetaProgressionSceneText,
+    pub relics: HashMap<String, String>,
+    pub achievements: HashMap<String, String>,
+    pub weapons: HashMap<String, String>,
+    pub monsters: HashMap<String, String>,
+    pub effects: HashMap<String, String>,
+    pub hazards: HashMap<String, String>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct MainMenuSceneText {
     pub play: String,
+    pub upgrades: String,
     pub highscore: String,
     pub options: String,
     pub help: String,
@@ -50,6 +65,11 @@ pub struct OptionsSceneText {
     pub enable_music: String,
     pub enable_sound: String,
     pub language: String,
+    pub mods: String,
+    pub no_mods: String,
+    pub disabled: String,
+    pub conflicts: String,
+    pub more_mods: String,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -62,6 +82,11 @@ pub struct HelpSceneText {
     pub use_skill: String,
     pub attack: String,
     pub aim: String,
+    pub dash: String,
+    pub pick_up_weapon: String,
+    pub swap_weapon: String,
+    pub use_potion: String,
+    pub toggle_map: String,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -86,6 +111,13 @@ pub struct ResultSceneText {
     pub total_cleared_rooms: String,
     pub total_cleared_waves: String,
     pub playtime: String,
+    pub death_recap: String,
+    pub killed_by: String,
+    pub reached: String,
+    pub rooms_cleared: String,
+    pub active_effects: String,
+    pub last_hits: String,
+    pub none: String,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -104,32 +136,127 @@ pub struct InGameText {
     pub dodge_chance: String,
     pub restore_chance: String,
     pub damage_percent_bonus: String,
+    pub level: String,
+    pub map: String,
+    pub current_room: String,
+    pub cleared_room: String,
+    pub visited_room: String,
+    pub unknown_room: String,
+    pub exit_room: String,
+    pub shop_room: String,
+    pub treasure_room: String,
+    pub shrine_room: String,
+    pub shrine_offer: String,
+    pub challenge_room: String,
+    pub challenge_time_limit: String,
+    pub challenge_no_hit: String,
+    pub challenge_completed: String,
+    pub challenge_failed: String,
+    pub locked_room: String,
+    pub keys: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ShopText {
+    pub shop: String,
+    pub gold: String,
+    pub leave: String,
+    pub relic: String,
+    pub heal_potion: String,
+    pub speed_up_potion: String,
+    pub evasion_up_potion: String,
+    pub focus_potion: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RewardSceneText {
+    pub choose_reward: String,
+    pub reroll: String,
+    pub banish: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct UpgradeText {
+    pub common: String,
+    pub rare: String,
+    pub epic: String,
+    pub legendary: String,
+    pub max_health: String,
+    pub dash_cooldown: String,
+    pub dash_invincible_duration: String,
+    pub skill_duration: String,
+    pub skill_cooldown: String,
+    pub required_kills: String,
+    pub skill_area: String,
+    pub skill_heal: String,
+    pub skill_damage: String,
+    pub effect_duration: String,
+    pub effect_bonus: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MetaProgressionSceneText {
+    pub upgrades: String,
+    pub currency: String,
+    pub earned: String,
+    pub achievements: String,
+    pub unlocked: String,
+    pub locked: String,
+    pub max: String,
+    pub killed_monsters: String,
+    pub cleared_rooms: String,
+    pub cleared_waves: String,
+    pub completed_runs: String,
+    pub reached_level: String,
+    pub max_health: String,
+    pub strength: String,
+    pub intelligence: String,
+    pub critical_chance: String,
+    pub starting_potion: String,
+    pub extra_reroll: String,
 }
 
 impl Glossary {
-    pub fn new(language: Language) -> Self {
+    pub fn new(language: Language, mod_list: &ModList) -> Self {
         let file_name = match language {
             Language::VI => VIETNAMESE_LANGUAGE_FILE,
             Language::EN => ENGLISH_LANGUAGE_FILE,
         };
 
-        match File::open(file_name) {
-            Ok(mut file) => {
-                let error_message = format!(
-                    "{}: JSON was not well-formatted",
-                    if language == Language::VI {
-                        "Language::VI"
-                    } else {
-                        "Language::EN"
-                    }
-                );
-
-                let mut contents = String::new();
-                file.read_to_string(&mut contents).unwrap();
-                let glossary = serde_json::from_str(&contents).unwrap_or_else(|_| { panic!("{}", error_message) });
-                glossary
+        mod_list.load_json(file_name, &[])
+    }
+
+    pub fn get_weapon_name(&self, weapon_type: &WeaponType) -> String {
+        match self.weapons.get(&weapon_type.0) {
+            Some(localized_name) => localized_name.clone(),
+            None => weapon_type.0.clone(),
+        }
+    }
+
+    pub fn get_monster_name(&self, monster_class: &MonsterClass) -> String {
+        match self.monsters.get(&monster_class.0) {
+            Some(localized_name) => localized_name.clone(),
+            None => monster_class.0.clone(),
+        }
+    }
+
+    pub fn get_hit_source_name(&self, source: &HitSource) -> String {
+        match source {
+            HitSource::Monster(monster_class) => self.get_monster_name(monster_class),
+            HitSource::Hazard(hazard_type) => {
+                let name = format!("{:?}", hazard_type);
+                match self.hazards.get(&name) {
+                    Some(localized_name) => localized_name.clone(),
+                    None => name,
+                }
             }
-            Err(err) => panic!("Can't find language file: {}", err),
+        }
+    }
+
+    pub fn get_effect_name(&self, effect_id: &EffectId) -> String {
+        match self.effects.get(&effect_id.0) {
+            Some(localized_name) => localized_name.clone(),
+            None => effect_id.0.clone(),
         }
     }
 }
@@ -0,0 +1,10 @@
+use bevy_inspector_egui::InspectorOptions;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, InspectorOptions)]
+pub struct Dash {
+    pub cooldown: f32,
+    pub duration: f32,
+    pub speed_multiplier: f32,
+    pub invincible_duration: f32,
+}
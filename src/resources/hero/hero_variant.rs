@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+use crate::resources::hero::gender::Gender;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct HeroVariant {
+    pub gender: Gender,
+    pub sprite: String,
+    pub portrait: String,
+}
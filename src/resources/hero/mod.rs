@@ -1,14 +1,18 @@
 use serde::{Deserialize, Serialize};
 
-use crate::resources::skill::skill_type::SkillType;
 use crate::resources::weapon::weapon_type::WeaponType;
 
+pub mod dash;
 pub mod gender;
 pub mod hero_class;
+pub mod hero_variant;
 pub mod power;
 pub mod stats;
 
+use dash::Dash;
+use gender::Gender;
 use hero_class::HeroClass;
+use hero_variant::HeroVariant;
 use power::Power;
 use stats::Stats;
 
@@ -16,7 +20,23 @@ use stats::Stats;
 pub struct Hero {
     pub hero_class: HeroClass,
     pub stats: Stats,
+    pub dash: Dash,
     pub power: Power,
     pub weapon: WeaponType,
-    pub skill: SkillType,
+    pub weapons: Vec<WeaponType>,
+    pub skill: String,
+    pub variants: Vec<HeroVariant>,
+}
+
+impl Hero {
+    pub fn get_variant(&self, gender: Gender) -> HeroVariant {
+        match self
+            .variants
+            .iter()
+            .find(|variant| variant.gender == gender)
+        {
+            Some(variant) => variant.clone(),
+            None => panic!("Hero {:?} has no {:?} variant", self.hero_class, gender),
+        }
+    }
 }
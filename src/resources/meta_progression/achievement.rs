@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+
+use crate::resources::hero::hero_class::HeroClass;
+use crate::resources::weapon::weapon_type::WeaponType;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum AchievementRequirement {
+    TotalKilledMonsters(usize),
+    TotalClearedRooms(usize),
+    TotalClearedWaves(usize),
+    CompletedRuns(usize),
+    ReachedLevel(usize),
+}
+
+impl AchievementRequirement {
+    pub fn get_goal(&self) -> usize {
+        match *self {
+            AchievementRequirement::TotalKilledMonsters(goal)
+            | AchievementRequirement::TotalClearedRooms(goal)
+            | AchievementRequirement::TotalClearedWaves(goal)
+            | AchievementRequirement::CompletedRuns(goal)
+            | AchievementRequirement::ReachedLevel(goal) => goal,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum Unlock {
+    Hero(HeroClass),
+    Weapon(WeaponType),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Achievement {
+    pub name: String,
+    pub requirement: AchievementRequirement,
+    pub unlock: Unlock,
+}
@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MetaUpgradeType {
+    MaxHealth,
+    Strength,
+    Intelligence,
+    CriticalChance,
+    StartingPotion,
+    ExtraReroll,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MetaUpgrade {
+    pub meta_upgrade_type: MetaUpgradeType,
+    pub max_level: usize,
+    pub price: usize,
+    pub bonus: f32,
+}
+
+impl MetaUpgrade {
+    pub fn get_price(&self, level: usize) -> usize {
+        self.price * (level + 1)
+    }
+}
@@ -0,0 +1,164 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::prelude::*;
+
+pub mod achievement;
+pub mod meta_upgrade;
+
+use crate::config::META_PROGRESSION_FILE;
+use crate::resources::profile::Profile;
+use achievement::{Achievement, AchievementRequirement, Unlock};
+use meta_upgrade::{MetaUpgrade, MetaUpgradeType};
+
+pub const META_PROGRESSION_VERSION: u32 = 1;
+
+const CURRENCY_PER_KILLED_MONSTER: usize = 1;
+const CURRENCY_PER_CLEARED_ROOM: usize = 2;
+const CURRENCY_PER_CLEARED_WAVE: usize = 3;
+const CURRENCY_PER_LEVEL: usize = 2;
+const GOLD_PER_CURRENCY: usize = 10;
+const COMPLETED_RUN_CURRENCY: usize = 25;
+
+#[derive(Resource, Serialize, Deserialize, Debug, Clone)]
+pub struct MetaProgression {
+    pub version: u32,
+    pub currency: usize,
+    pub total_killed_monsters: usize,
+    pub total_cleared_rooms: usize,
+    pub total_cleared_waves: usize,
+    pub completed_runs: usize,
+    pub highest_level: usize,
+    pub upgrades: HashMap<MetaUpgradeType, usize>,
+    pub achievements: Vec<String>,
+}
+
+impl MetaProgression {
+    fn new() -> Self {
+        MetaProgression {
+            version: META_PROGRESSION_VERSION,
+            currency: 0,
+            total_killed_monsters: 0,
+            total_cleared_rooms: 0,
+            total_cleared_waves: 0,
+            completed_runs: 0,
+            highest_level: 1,
+            upgrades: HashMap::new(),
+            achievements: Vec::new(),
+        }
+    }
+
+    pub fn load() -> Self {
+        match File::open(META_PROGRESSION_FILE) {
+            Ok(mut file) => {
+                let mut contents = String::new();
+                file.read_to_string(&mut contents).unwrap();
+                let mut meta_progression: MetaProgression =
+                    serde_json::from_str(&contents).expect("JSON was not well-formatted");
+
+                if meta_progression.version > META_PROGRESSION_VERSION {
+                    panic!(
+                        "Unsupported meta progression version {} (expected at most {})",
+                        meta_progression.version, META_PROGRESSION_VERSION
+                    );
+                }
+
+                if meta_progression.version < META_PROGRESSION_VERSION {
+                    meta_progression.version = META_PROGRESSION_VERSION;
+                    meta_progression.store();
+                }
+                meta_progression
+            }
+            Err(_err) => {
+                let meta_progression = MetaProgression::new();
+                meta_progression.store();
+                meta_progression
+            }
+        }
+    }
+
+    pub fn store(&self) {
+        let mut meta_progression_file =
+            File::create(META_PROGRESSION_FILE).expect("Can't open meta progression file");
+        let meta_progression_str: String = serde_json::to_string(&self).unwrap();
+        meta_progression_file
+            .write_all(meta_progression_str.as_bytes())
+            .expect("Unable to write file");
+    }
+
+    pub fn calculate_currency(profile: &Profile) -> usize {
+        let mut currency = profile.total_killed_monsters * CURRENCY_PER_KILLED_MONSTER
+            + profile.total_cleared_rooms * CURRENCY_PER_CLEARED_ROOM
+            + profile.total_cleared_waves * CURRENCY_PER_CLEARED_WAVE
+            + (profile.level - 1) * CURRENCY_PER_LEVEL
+            + profile.total_gold / GOLD_PER_CURRENCY;
+
+        if profile.is_run_completed {
+            currency += COMPLETED_RUN_CURRENCY;
+        }
+        currency
+    }
+
+    pub fn record_run(&mut self, profile: &Profile, achievements: &[Achievement]) -> Vec<String> {
+        self.currency += MetaProgression::calculate_currency(profile);
+        self.total_killed_monsters += profile.total_killed_monsters;
+        self.total_cleared_rooms += profile.total_cleared_rooms;
+        self.total_cleared_waves += profile.total_cleared_waves;
+        self.highest_level = self.highest_level.max(profile.level);
+        if profile.is_run_completed {
+            self.completed_runs += 1;
+        }
+
+        let mut unlocked_achievements = Vec::new();
+        for achievement in achievements.iter() {
+            if !self.achievements.contains(&achievement.name)
+                && self.get_progress(achievement.requirement) >= achievement.requirement.get_goal()
+            {
+                self.achievements.push(achievement.name.clone());
+                unlocked_achievements.push(achievement.name.clone());
+            }
+        }
+        unlocked_achievements
+    }
+
+    pub fn get_progress(&self, requirement: AchievementRequirement) -> usize {
+        match requirement {
+            AchievementRequirement::TotalKilledMonsters(_) => self.total_killed_monsters,
+            AchievementRequirement::TotalClearedRooms(_) => self.total_cleared_rooms,
+            AchievementRequirement::TotalClearedWaves(_) => self.total_cleared_waves,
+            AchievementRequirement::CompletedRuns(_) => self.completed_runs,
+            AchievementRequirement::ReachedLevel(_) => self.highest_level,
+        }
+    }
+
+    pub fn is_unlocked(&self, achievements: &[Achievement], unlock: Unlock) -> bool {
+        achievements
+            .iter()
+            .filter(|achievement| achievement.unlock == unlock)
+            .all(|achievement| self.achievements.contains(&achievement.name))
+    }
+
+    pub fn get_upgrade_level(&self, meta_upgrade_type: MetaUpgradeType) -> usize {
+        *self.upgrades.get(&meta_upgrade_type).unwrap_or(&0)
+    }
+
+    pub fn buy_upgrade(&mut self, meta_upgrade: &MetaUpgrade) -> bool {
+        let level = self.get_upgrade_level(meta_upgrade.meta_upgrade_type);
+        let price = meta_upgrade.get_price(level);
+
+        if level >= meta_upgrade.max_level || self.currency < price {
+            return false;
+        }
+
+        self.currency -= price;
+        self.upgrades.insert(meta_upgrade.meta_upgrade_type, level + 1);
+        true
+    }
+}
+
+impl FromWorld for MetaProgression {
+    fn from_world(_world: &mut World) -> Self {
+        MetaProgression::load()
+    }
+}
@@ -1,4 +1,6 @@
 pub mod animation_state;
+pub mod biome;
+pub mod death_recap;
 pub mod dictionary;
 pub mod dungeon;
 pub mod effect;
@@ -7,13 +9,20 @@ pub mod game_mode;
 pub mod glossary;
 pub mod hero;
 pub mod language;
+pub mod meta_progression;
+pub mod mods;
 pub mod monster;
 pub mod player;
+pub mod playtest;
 pub mod potion;
 pub mod profile;
+pub mod prop;
+pub mod relic;
 pub mod setting;
+pub mod shop;
 pub mod skill;
 pub mod stored_profile;
 pub mod tile_size;
+pub mod tiled;
 pub mod upgrade;
 pub mod weapon;
@@ -0,0 +1,371 @@
+use bevy::prelude::*;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::prelude::*;
+use std::path::Path;
+
+pub mod mod_conflict;
+pub mod mod_pack;
+
+use crate::config::*;
+use mod_conflict::ModConflict;
+use mod_pack::{ModManifest, ModPack};
+
+pub const DATA_ID_KEYS: [(&str, &str); 12] = [
+    ("heroes", "hero_class"),
+    ("weapons", "name"),
+    ("weapon_upgrades", "weapon"),
+    ("skills", "name"),
+    ("player_list_effects_information", "name"),
+    ("monsters", "class"),
+    ("relics", "name"),
+    ("shop_items", "item"),
+    ("meta_upgrades", "meta_upgrade_type"),
+    ("achievements", "name"),
+    ("props", "name"),
+    ("biomes", "name"),
+];
+
+pub const UPGRADES_ID_KEYS: [(&str, &str); 1] = [("rarities", "rarity")];
+
+const MERGED_FILES: [&str; 4] = [
+    DATA_FILE,
+    UPGRADES_FILE,
+    ENGLISH_LANGUAGE_FILE,
+    VIETNAMESE_LANGUAGE_FILE,
+];
+
+#[derive(Resource, Debug, Clone)]
+pub struct ModList {
+    pub packs: Vec<ModPack>,
+    pub conflicts: Vec<ModConflict>,
+}
+
+impl ModList {
+    pub fn load() -> Self {
+        let mut packs = Vec::new();
+
+        if let Ok(entries) = fs::read_dir(MODS_DIRECTORY) {
+            for entry in entries {
+                let directory = entry.unwrap().path();
+                let manifest_path = directory.join(MOD_MANIFEST_FILE);
+                if !manifest_path.is_file() {
+                    continue;
+                }
+
+                let manifest: ModManifest = serde_json::from_str(&read_file(&manifest_path))
+                    .unwrap_or_else(|err| panic!("{}: {}", manifest_path.display(), err));
+
+                packs.push(ModPack {
+                    manifest,
+                    directory: directory.to_string_lossy().replace('\\', "/"),
+                });
+            }
+        }
+
+        packs.sort_by(|a, b| {
+            a.manifest
+                .load_order
+                .cmp(&b.manifest.load_order)
+                .then(a.manifest.id.cmp(&b.manifest.id))
+        });
+
+        for (index, pack) in packs.iter().enumerate() {
+            if packs[..index]
+                .iter()
+                .any(|other| other.manifest.id == pack.manifest.id)
+            {
+                panic!(
+                    "Duplicate mod id {} in {}",
+                    pack.manifest.id, pack.directory
+                );
+            }
+        }
+
+        let mut mod_list = ModList {
+            packs,
+            conflicts: Vec::new(),
+        };
+        mod_list.conflicts = mod_list.find_conflicts();
+
+        for pack in mod_list.get_enabled_packs() {
+            info!(
+                "Loaded mod {} {} ({})",
+                pack.manifest.name, pack.manifest.version, pack.directory
+            );
+        }
+        for conflict in mod_list.conflicts.iter() {
+            warn!(
+                "Mod conflict on {}: {} (last one wins)",
+                conflict.key,
+                conflict.mods.join(", ")
+            );
+        }
+
+        mod_list
+    }
+
+    pub fn get_enabled_packs(&self) -> Vec<&ModPack> {
+        self.packs
+            .iter()
+            .filter(|pack| pack.manifest.enabled)
+            .collect()
+    }
+
+    pub fn load_json<T: DeserializeOwned>(&self, file_name: &str, id_keys: &[(&str, &str)]) -> T {
+        let mut value: Value = serde_json::from_str(&read_file(Path::new(file_name)))
+            .unwrap_or_else(|err| panic!("{}: JSON was not well-formatted: {}", file_name, err));
+
+        for pack in self.get_enabled_packs() {
+            let path = pack.get_path(file_name);
+            if Path::new(&path).is_file() {
+                let patch: Value = serde_json::from_str(&read_file(Path::new(&path)))
+                    .unwrap_or_else(|err| panic!("{}: JSON was not well-formatted: {}", path, err));
+                merge(&mut value, patch, id_keys, "", &mut Vec::new());
+            }
+        }
+
+        serde_json::from_value(value)
+            .unwrap_or_else(|err| panic!("{} (with mods): {}", file_name, err))
+    }
+
+    pub fn get_floor_files(&self) -> Vec<String> {
+        let mut floor_files: Vec<String> = read_file(Path::new(LIST_FLOOR_FILE))
+            .lines()
+            .map(|line| format!("{}{}", FLOORS_DIRECTORY, line))
+            .collect();
+
+        for pack in self.get_enabled_packs() {
+            floor_files.extend(list_files(&pack.get_path(MOD_FLOORS_DIRECTORY)));
+        }
+        floor_files
+    }
+
+    pub fn get_room_files(&self) -> Vec<String> {
+        let mut room_files: HashMap<String, String> = read_file(Path::new(LIST_ROOM_FILE))
+            .lines()
+            .map(|line| (line.to_string(), format!("{}{}", ROOMS_DIRECTORY, line)))
+            .collect();
+
+        for pack in self.get_enabled_packs() {
+            for path in list_files(&pack.get_path(MOD_ROOMS_DIRECTORY)) {
+                let file_name = Path::new(&path).file_name().unwrap().to_string_lossy();
+                room_files.insert(file_name.to_string(), path);
+            }
+        }
+
+        let mut room_files: Vec<String> = room_files.into_values().collect();
+        room_files.sort();
+        room_files
+    }
+
+    pub fn resolve_sprite(&self, path: &str) -> String {
+        match self
+            .get_enabled_packs()
+            .into_iter()
+            .rev()
+            .find(|pack| Path::new(&pack.get_path(&sprite_path(path))).is_file())
+        {
+            Some(pack) => format!(
+                "{}://{}/{}",
+                MODS_ASSET_SOURCE,
+                Path::new(&pack.directory)
+                    .file_name()
+                    .unwrap()
+                    .to_string_lossy(),
+                sprite_path(path)
+            ),
+            None => path.to_string(),
+        }
+    }
+
+    pub fn get_conflicts(&self, mod_id: &str) -> usize {
+        self.conflicts
+            .iter()
+            .filter(|conflict| conflict.mods.iter().any(|id| id == mod_id))
+            .count()
+    }
+
+    fn find_conflicts(&self) -> Vec<ModConflict> {
+        let mut touched: Vec<(String, String)> = Vec::new();
+
+        for pack in self.get_enabled_packs() {
+            let mut keys = Vec::new();
+
+            for file_name in MERGED_FILES.iter() {
+                let path = pack.get_path(file_name);
+                if !Path::new(&path).is_file() {
+                    continue;
+                }
+                let patch: Value = serde_json::from_str(&read_file(Path::new(&path)))
+                    .unwrap_or_else(|err| panic!("{}: JSON was not well-formatted: {}", path, err));
+                let id_keys: &[(&str, &str)] = match *file_name {
+                    DATA_FILE => &DATA_ID_KEYS,
+                    UPGRADES_FILE => &UPGRADES_ID_KEYS,
+                    _ => &[],
+                };
+                merge(&mut Value::Null, patch, id_keys, file_name, &mut keys);
+            }
+
+            for path in list_files(&pack.get_path(MOD_ROOMS_DIRECTORY)) {
+                let file_name = Path::new(&path).file_name().unwrap().to_string_lossy();
+                keys.push(format!("{}{}", MOD_ROOMS_DIRECTORY, file_name));
+            }
+
+            let sprites_directory = pack.get_path(MOD_SPRITES_DIRECTORY);
+            for path in list_files_recursive(&sprites_directory) {
+                keys.push(
+                    path.replacen(&pack.directory, "", 1)
+                        .trim_start_matches('/')
+                        .to_string(),
+                );
+            }
+
+            for key in keys {
+                touched.push((key, pack.manifest.id.clone()));
+            }
+        }
+
+        let mut conflicts: Vec<ModConflict> = Vec::new();
+        for (key, mod_id) in touched {
+            match conflicts.iter_mut().find(|conflict| conflict.key == key) {
+                Some(conflict) => {
+                    if !conflict.mods.contains(&mod_id) {
+                        conflict.mods.push(mod_id);
+                    }
+                }
+                None => conflicts.push(ModConflict {
+                    key,
+                    mods: vec![mod_id],
+                }),
+            }
+        }
+        conflicts.retain(|conflict| conflict.mods.len() > 1);
+        conflicts
+    }
+}
+
+impl FromWorld for ModList {
+    fn from_world(_world: &mut World) -> Self {
+        ModList::load()
+    }
+}
+
+fn merge(
+    base: &mut Value,
+    patch: Value,
+    id_keys: &[(&str, &str)],
+    path: &str,
+    touched: &mut Vec<String>,
+) {
+    match patch {
+        Value::Object(patch_object) => {
+            if !base.is_object() {
+                *base = Value::Object(serde_json::Map::new());
+            }
+            let base_object = base.as_object_mut().unwrap();
+
+            for (key, patch_value) in patch_object {
+                let key_path = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", path, key)
+                };
+
+                match id_keys.iter().find(|(collection, _)| *collection == key) {
+                    Some((_, id_key)) if patch_value.is_array() => {
+                        let base_value = base_object
+                            .entry(key)
+                            .or_insert_with(|| Value::Array(Vec::new()));
+                        merge_entries(base_value, patch_value, id_key, &key_path, touched);
+                    }
+                    _ => {
+                        let base_value = base_object.entry(key).or_insert(Value::Null);
+                        merge(base_value, patch_value, id_keys, &key_path, touched);
+                    }
+                }
+            }
+        }
+        Value::Array(patch_array) => {
+            if !base.is_array() {
+                *base = Value::Array(Vec::new());
+            }
+            base.as_array_mut().unwrap().extend(patch_array);
+        }
+        _ => {
+            touched.push(path.to_string());
+            *base = patch;
+        }
+    }
+}
+
+fn merge_entries(
+    base: &mut Value,
+    patch: Value,
+    id_key: &str,
+    path: &str,
+    touched: &mut Vec<String>,
+) {
+    let base_entries = base.as_array_mut().unwrap();
+
+    for entry in patch.as_array().unwrap().iter() {
+        let id = match entry.get(id_key) {
+            Some(id) => id.clone(),
+            None => panic!("{}: entry without \"{}\"", path, id_key),
+        };
+        touched.push(format!("{}[{}]", path, id));
+
+        match base_entries
+            .iter_mut()
+            .find(|base_entry| base_entry.get(id_key) == Some(&id))
+        {
+            Some(base_entry) => *base_entry = entry.clone(),
+            None => base_entries.push(entry.clone()),
+        }
+    }
+}
+
+fn sprite_path(path: &str) -> String {
+    format!("{}{}", MOD_SPRITES_DIRECTORY, path)
+}
+
+fn read_file(path: &Path) -> String {
+    match File::open(path) {
+        Ok(mut file) => {
+            let mut contents = String::new();
+            file.read_to_string(&mut contents).unwrap();
+            contents
+        }
+        Err(err) => panic!("Can't open {}: {}", path.display(), err),
+    }
+}
+
+fn list_files(directory: &str) -> Vec<String> {
+    let mut files: Vec<String> = match fs::read_dir(directory) {
+        Ok(entries) => entries
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.is_file())
+            .map(|path| path.to_string_lossy().replace('\\', "/"))
+            .collect(),
+        Err(_err) => Vec::new(),
+    };
+    files.sort();
+    files
+}
+
+fn list_files_recursive(directory: &str) -> Vec<String> {
+    let mut files = list_files(directory);
+    if let Ok(entries) = fs::read_dir(directory) {
+        for entry in entries {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                files.extend(list_files_recursive(
+                    &path.to_string_lossy().replace('\\', "/"),
+                ));
+            }
+        }
+    }
+    files
+}
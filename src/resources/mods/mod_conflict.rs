@@ -0,0 +1,5 @@
+#[derive(Debug, Clone)]
+pub struct ModConflict {
+    pub key: String,
+    pub mods: Vec<String>,
+}
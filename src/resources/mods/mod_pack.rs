@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ModManifest {
+    pub id: String,
+    pub name: String,
+    pub version: String,
+    #[serde(default)]
+    pub load_order: i32,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+#[derive(Debug, Clone)]
+pub struct ModPack {
+    pub manifest: ModManifest,
+    pub directory: String,
+}
+
+impl ModPack {
+    pub fn get_path(&self, file_name: &str) -> String {
+        format!("{}/{}", self.directory, file_name)
+    }
+}
@@ -4,7 +4,7 @@ pub mod monster_class;
 pub mod monster_skill;
 pub mod monster_spawn_controller;
 
-use crate::resources::effect::effect_type::EffectType;
+use crate::resources::effect::effect_id::EffectId;
 use monster_class::MonsterClass;
 use monster_skill::MonsterSkill;
 
@@ -15,9 +15,25 @@ pub struct Monster {
     pub level: u8,
     pub speed: f32,
     pub health_points: f32,
-    pub trigger_effect: Option<EffectType>,
+    pub gold: usize,
+    pub experience: usize,
+    pub trigger_effect: Option<EffectId>,
     pub trigger_chance: Option<f32>,
     pub skill: Option<MonsterSkill>,
     pub origin_width: f32,
     pub origin_height: f32,
+    pub sprite: String,
+    pub frames: u8,
+}
+
+impl Monster {
+    pub fn get_max_level(floor_index: usize) -> u8 {
+        if floor_index < 2 {
+            1
+        } else if floor_index < 4 {
+            2
+        } else {
+            3
+        }
+    }
 }
@@ -1,16 +1,6 @@
 use bevy_inspector_egui::InspectorOptions;
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Debug, Clone, InspectorOptions)]
-pub enum MonsterClass {
-    SmallZombie,
-    Zombie,
-    BigZombie,
-    Goblin,
-    Orc,
-    Ogre,
-    Imp,
-    Chort,
-    BigDemon,
-    Swampy,
-}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, InspectorOptions)]
+#[serde(transparent)]
+pub struct MonsterClass(pub String);
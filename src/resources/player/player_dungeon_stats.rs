@@ -8,4 +8,5 @@ pub struct PlayerDungeonStats {
     pub current_floor_index: usize,
     pub current_room_position: Position,
     pub is_room_cleared: bool,
+    pub keys: usize,
 }
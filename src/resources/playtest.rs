@@ -0,0 +1,37 @@
+use bevy::prelude::*;
+use std::env;
+
+use crate::config::PLAYTEST_ARGUMENT;
+
+#[derive(Resource, Clone, Debug, Default)]
+pub struct Playtest {
+    pub room_id: Option<f32>,
+    pub biome: Option<String>,
+}
+
+impl Playtest {
+    pub fn from_args() -> Self {
+        let args: Vec<String> = env::args().collect();
+
+        match args.iter().position(|arg| arg == PLAYTEST_ARGUMENT) {
+            Some(index) => {
+                let room_id = match args.get(index + 1) {
+                    Some(room_id) => room_id
+                        .parse::<f32>()
+                        .unwrap_or_else(|_| panic!("Invalid playtest room: {}", room_id)),
+                    None => panic!("Missing room id after {}", PLAYTEST_ARGUMENT),
+                };
+
+                Playtest {
+                    room_id: Some(room_id),
+                    biome: args.get(index + 2).cloned(),
+                }
+            }
+            None => Playtest::default(),
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.room_id.is_some()
+    }
+}
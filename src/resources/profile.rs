@@ -2,11 +2,11 @@ use chrono::{DateTime, Local};
 use serde::{Deserialize, Serialize};
 use bevy::prelude::*;
 
+use crate::resources::death_recap::DeathRecap;
 use crate::resources::game_mode::GameMode;
 use crate::resources::hero::gender::Gender;
 use crate::resources::hero::hero_class::HeroClass;
 use crate::resources::stored_profile::StoredProfile;
-use crate::scenes::hero_select_scene::ButtonComponent;
 
 #[derive(Resource, Serialize, Deserialize, Debug, Clone)]
 pub struct Profile {
@@ -17,10 +17,16 @@ pub struct Profile {
     pub total_killed_monsters: usize,
     pub total_cleared_rooms: usize,
     pub total_cleared_waves: usize,
+    pub gold: usize,
+    pub total_gold: usize,
+    pub level: usize,
+    pub earned_currency: usize,
+    pub unlocked_achievements: Vec<String>,
     pub start_time: String,
     pub end_time: String,
     pub is_run_completed: bool,
     pub is_run_finished: bool,
+    pub death_recap: DeathRecap,
 }
 
 impl Profile {
@@ -30,15 +36,21 @@ impl Profile {
         Profile {
             name: String::new(),
             game_mode: GameMode::ClassicMode,
-            hero_class: HeroClass::Elf,
+            hero_class: HeroClass(String::new()),
             gender: Gender::Male,
             total_cleared_rooms: 0,
             total_killed_monsters: 0,
             total_cleared_waves: 0,
+            gold: 0,
+            total_gold: 0,
+            level: 1,
+            earned_currency: 0,
+            unlocked_achievements: Vec::new(),
             end_time: start_time.to_rfc3339(),
             start_time: start_time.to_rfc3339(),
             is_run_completed: false,
             is_run_finished: false,
+            death_recap: DeathRecap::default(),
         }
     }
 
@@ -46,41 +58,9 @@ impl Profile {
         self.game_mode = game_mode;
     }
 
-    pub fn set_hero(&mut self, button: ButtonComponent) {
-        match button {
-            ButtonComponent::MaleElf => {
-                self.hero_class = HeroClass::Elf;
-                self.gender = Gender::Male;
-            }
-            ButtonComponent::FemaleElf => {
-                self.hero_class = HeroClass::Elf;
-                self.gender = Gender::Female;
-            }
-            ButtonComponent::MaleKnight => {
-                self.hero_class = HeroClass::Knight;
-                self.gender = Gender::Male;
-            }
-            ButtonComponent::FemaleKnight => {
-                self.hero_class = HeroClass::Knight;
-                self.gender = Gender::Female;
-            }
-            ButtonComponent::MaleLizard => {
-                self.hero_class = HeroClass::Lizard;
-                self.gender = Gender::Male;
-            }
-            ButtonComponent::FemaleLizard => {
-                self.hero_class = HeroClass::Lizard;
-                self.gender = Gender::Female;
-            }
-            ButtonComponent::MaleWizard => {
-                self.hero_class = HeroClass::Wizard;
-                self.gender = Gender::Male;
-            }
-            ButtonComponent::FemaleWizard => {
-                self.hero_class = HeroClass::Wizard;
-                self.gender = Gender::Female;
-            }
-        }
+    pub fn set_hero(&mut self, hero_class: HeroClass, gender: Gender) {
+        self.hero_class = hero_class;
+        self.gender = gender;
     }
 
     pub fn set_name(&mut self, user_name: String) {
@@ -107,6 +87,7 @@ impl Profile {
             total_cleared_waves: self.total_cleared_waves,
             date: self.start_time.clone(),
             playtime: diff_time_second,
+            level: Some(self.level),
         }
     }
 }
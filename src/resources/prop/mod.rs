@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+pub mod prop_loot;
+pub mod prop_placement;
+
+use prop_loot::PropLoot;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Prop {
+    pub name: String,
+    pub tile: i32,
+    pub health_points: f32,
+    pub width: f32,
+    pub height: f32,
+    pub sprite: String,
+    pub color: Option<[f32; 3]>,
+    pub spawn_weight: f32,
+    pub loot_table: Vec<PropLoot>,
+}
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+use crate::resources::potion::potion_type::PotionType;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum PropLootItem {
+    Gold(usize),
+    Potion(PotionType),
+    Relic,
+    Key,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PropLoot {
+    pub item: PropLootItem,
+    pub chance: f32,
+}
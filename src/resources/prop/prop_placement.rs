@@ -0,0 +1,7 @@
+#[derive(Debug, Clone)]
+pub struct PropPlacement {
+    pub name: String,
+    pub row_index: usize,
+    pub column_index: usize,
+    pub is_broken: bool,
+}
@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+pub mod relic_trigger;
+
+use crate::resources::effect::effect_id::EffectId;
+use relic_trigger::RelicTrigger;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Relic {
+    pub name: String,
+    pub speed_percent_bonus: Option<f32>,
+    pub critical_chance_bonus: Option<f32>,
+    pub dodge_chance_bonus: Option<f32>,
+    pub restore_chance_bonus: Option<f32>,
+    pub damage_percent_bonus: Option<f32>,
+    pub boosted_effect: Option<EffectId>,
+    pub boosted_effect_bonus: Option<f32>,
+    pub trigger: Option<RelicTrigger>,
+    pub trigger_chance: Option<f32>,
+    pub trigger_effect: Option<EffectId>,
+    pub trigger_heal: Option<f32>,
+}
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+pub mod shop_item_type;
+
+use shop_item_type::ShopItemType;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ShopItem {
+    pub item: ShopItemType,
+    pub price: usize,
+}
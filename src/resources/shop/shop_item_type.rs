@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+use crate::resources::potion::potion_type::PotionType;
+use crate::resources::upgrade::upgrade_type::UpgradeType;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum ShopItemType {
+    Potion(PotionType),
+    Upgrade(UpgradeType),
+    Relic,
+}
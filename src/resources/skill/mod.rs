@@ -1,12 +1,25 @@
 use serde::{Deserialize, Serialize};
 
-pub mod skill_type;
+pub mod skill_damage;
+pub mod skill_target;
+pub mod skill_trigger;
 
-use skill_type::SkillType;
+use crate::resources::effect::effect_id::EffectId;
+use skill_damage::SkillDamage;
+use skill_target::SkillTarget;
+use skill_trigger::SkillTrigger;
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct Skill {
-    pub name: SkillType,
+    pub name: String,
+    #[serde(default)]
+    pub trigger: SkillTrigger,
+    pub target: Option<SkillTarget>,
+    pub area: Option<f32>,
+    pub damage: Option<SkillDamage>,
+    pub apply_effect: Option<EffectId>,
+    pub heal: Option<f32>,
+    pub freeze_monsters: Option<bool>,
     pub duration: Option<i64>,
     pub cooldown: Option<i64>,
     pub require_health_points: Option<f32>,
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+use crate::resources::hero::power::Power;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SkillDamage {
+    pub power: Power,
+    pub multiplier: f32,
+}
@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+pub enum SkillTrigger {
+    #[default]
+    Manual,
+    KillCounter,
+}
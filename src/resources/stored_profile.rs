@@ -15,4 +15,5 @@ pub struct StoredProfile {
     pub total_cleared_waves: usize,
     pub date: String,
     pub playtime: i64,
+    pub level: Option<usize>,
 }
@@ -0,0 +1,193 @@
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fs;
+
+use crate::resources::tiled::{load_tileset as load_external_tileset, VALUE_PROPERTY};
+use crate::resources::tiled::{TiledLayer, TiledMap, TiledObject, TiledTileset};
+
+#[derive(Deserialize)]
+struct JsonMap {
+    width: usize,
+    height: usize,
+    tilewidth: f32,
+    tileheight: f32,
+    #[serde(default)]
+    infinite: bool,
+    layers: Vec<JsonLayer>,
+    #[serde(default)]
+    tilesets: Vec<JsonTileset>,
+}
+
+#[derive(Deserialize)]
+struct JsonLayer {
+    #[serde(rename = "type")]
+    layer_type: String,
+    data: Option<Value>,
+    encoding: Option<String>,
+    #[serde(default)]
+    objects: Vec<JsonObject>,
+    #[serde(default)]
+    layers: Vec<JsonLayer>,
+}
+
+#[derive(Deserialize)]
+struct JsonObject {
+    #[serde(default)]
+    name: String,
+    #[serde(default, rename = "type")]
+    object_type: String,
+    #[serde(default)]
+    class: String,
+    x: f32,
+    y: f32,
+    #[serde(default)]
+    width: f32,
+    #[serde(default)]
+    height: f32,
+    gid: Option<u32>,
+    #[serde(default)]
+    properties: Vec<JsonProperty>,
+}
+
+#[derive(Deserialize)]
+struct JsonTileset {
+    #[serde(default)]
+    firstgid: u32,
+    source: Option<String>,
+    #[serde(default)]
+    tiles: Vec<JsonTile>,
+}
+
+#[derive(Deserialize)]
+struct JsonTile {
+    id: u32,
+    #[serde(default)]
+    properties: Vec<JsonProperty>,
+}
+
+#[derive(Deserialize)]
+struct JsonProperty {
+    name: String,
+    value: Value,
+}
+
+pub fn load_map(path: &str) -> TiledMap {
+    let json_map: JsonMap = read_json(path);
+
+    if json_map.infinite {
+        panic!("Infinite Tiled maps are not supported: {}", path);
+    }
+
+    let mut map = TiledMap {
+        path: path.to_string(),
+        width: json_map.width,
+        height: json_map.height,
+        tile_width: json_map.tilewidth,
+        tile_height: json_map.tileheight,
+        ..Default::default()
+    };
+
+    for tileset in json_map.tilesets {
+        let tileset = match &tileset.source {
+            Some(source) => load_external_tileset(path, source, tileset.firstgid),
+            None => get_tileset(tileset),
+        };
+        map.tilesets.push(tileset);
+    }
+
+    add_layers(&mut map, json_map.layers, path);
+
+    map
+}
+
+pub fn load_tileset(path: &str) -> TiledTileset {
+    get_tileset(read_json(path))
+}
+
+fn add_layers(map: &mut TiledMap, layers: Vec<JsonLayer>, path: &str) {
+    for layer in layers {
+        match layer.layer_type.as_str() {
+            "tilelayer" => {
+                match &layer.encoding {
+                    Some(encoding) if encoding != "csv" => panic!(
+                        "Unsupported layer encoding {} in {}, save the map with the CSV layer format",
+                        encoding, path
+                    ),
+                    _ => (),
+                }
+
+                let data = match layer.data {
+                    Some(data) => match serde_json::from_value::<Vec<u32>>(data) {
+                        Ok(data) => data,
+                        Err(err) => panic!("Invalid tile layer in {}: {}", path, err),
+                    },
+                    None => panic!("Infinite Tiled maps are not supported: {}", path),
+                };
+                map.layers.push(TiledLayer::Tiles(data));
+            }
+            "objectgroup" => {
+                let objects = layer
+                    .objects
+                    .into_iter()
+                    .map(|object| TiledObject {
+                        name: object.name,
+                        class: if object.class.is_empty() {
+                            object.object_type.to_lowercase()
+                        } else {
+                            object.class.to_lowercase()
+                        },
+                        x: object.x,
+                        y: object.y,
+                        width: object.width,
+                        height: object.height,
+                        gid: object.gid,
+                        properties: get_properties(object.properties),
+                    })
+                    .collect();
+                map.layers.push(TiledLayer::Objects(objects));
+            }
+            "group" => add_layers(map, layer.layers, path),
+            _ => (),
+        }
+    }
+}
+
+fn get_tileset(tileset: JsonTileset) -> TiledTileset {
+    let mut values = HashMap::new();
+
+    for tile in tileset.tiles {
+        if let Some(value) = get_properties(tile.properties).remove(VALUE_PROPERTY) {
+            values.insert(tile.id, value);
+        }
+    }
+
+    TiledTileset {
+        first_gid: tileset.firstgid,
+        values,
+    }
+}
+
+fn get_properties(properties: Vec<JsonProperty>) -> HashMap<String, String> {
+    properties
+        .into_iter()
+        .map(|property| {
+            let value = match property.value {
+                Value::String(value) => value,
+                value => value.to_string(),
+            };
+            (property.name, value)
+        })
+        .collect()
+}
+
+fn read_json<T: DeserializeOwned>(path: &str) -> T {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(err) => panic!("Can't open Tiled file {}: {}", path, err),
+    };
+
+    serde_json::from_str(&content)
+        .unwrap_or_else(|err| panic!("{}: JSON was not well-formatted: {}", path, err))
+}
@@ -0,0 +1,359 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+pub mod json;
+pub mod tmx;
+
+use crate::resources::dungeon::hazard_type::HazardType;
+use crate::resources::dungeon::validation::WALL_VALUES;
+use crate::resources::game_data::GameData;
+
+const FLIP_FLAGS: u32 = 0xF000_0000;
+pub const VALUE_PROPERTY: &str = "value";
+const ROOM_PROPERTY: &str = "room";
+
+#[derive(Clone, Debug, Default)]
+pub struct TiledTileset {
+    pub first_gid: u32,
+    pub values: HashMap<u32, String>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct TiledObject {
+    pub name: String,
+    pub class: String,
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+    pub gid: Option<u32>,
+    pub properties: HashMap<String, String>,
+}
+
+#[derive(Clone, Debug)]
+pub enum TiledLayer {
+    Tiles(Vec<u32>),
+    Objects(Vec<TiledObject>),
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct TiledMap {
+    pub path: String,
+    pub width: usize,
+    pub height: usize,
+    pub tile_width: f32,
+    pub tile_height: f32,
+    pub tilesets: Vec<TiledTileset>,
+    pub layers: Vec<TiledLayer>,
+}
+
+pub fn is_tiled_file(path: &str) -> bool {
+    match Path::new(path).extension() {
+        Some(extension) => extension == "tmx" || extension == "json",
+        None => false,
+    }
+}
+
+pub fn load_tileset(map_path: &str, source: &str, first_gid: u32) -> TiledTileset {
+    let path = match Path::new(map_path).parent() {
+        Some(directory) => directory.join(source).to_string_lossy().to_string(),
+        None => source.to_string(),
+    };
+
+    let mut tileset = match Path::new(&path).extension() {
+        Some(extension) if extension == "tsx" => tmx::load_tileset(&path),
+        Some(extension) if extension == "tsj" || extension == "json" => json::load_tileset(&path),
+        _ => panic!(
+            "Unsupported Tiled tileset {}, expected .tsx, .tsj or .json",
+            path
+        ),
+    };
+
+    tileset.first_gid = first_gid;
+    tileset
+}
+
+impl TiledMap {
+    pub fn load(path: &str) -> Self {
+        let map = match Path::new(path).extension() {
+            Some(extension) if extension == "tmx" => tmx::load_map(path),
+            Some(extension) if extension == "json" => json::load_map(path),
+            _ => panic!("Unsupported Tiled map {}, expected .tmx or .json", path),
+        };
+
+        for layer in map.layers.iter() {
+            match layer {
+                TiledLayer::Tiles(data) if data.len() != map.width * map.height => panic!(
+                    "Tile layer in {} has {} tiles, expected {}",
+                    path,
+                    data.len(),
+                    map.width * map.height
+                ),
+                _ => (),
+            }
+        }
+
+        map
+    }
+
+    pub fn get_room_tilemap(&self, game_data: Option<&GameData>) -> Vec<Vec<i32>> {
+        let mut tilemap = vec![vec![0; self.width]; self.height];
+
+        for layer in self.layers.iter() {
+            match layer {
+                TiledLayer::Tiles(data) => {
+                    for (index, gid) in data.iter().enumerate() {
+                        if let Some(value) = self.get_tile_value(*gid) {
+                            tilemap[index / self.width][index % self.width] =
+                                self.parse_room_value(&value);
+                        }
+                    }
+                }
+                TiledLayer::Objects(objects) => {
+                    for object in objects.iter() {
+                        self.apply_room_object(&mut tilemap, object, game_data);
+                    }
+                }
+            }
+        }
+
+        tilemap
+    }
+
+    pub fn get_floor_map(&self) -> Vec<Vec<f32>> {
+        let mut map = vec![vec![0.0; self.width]; self.height];
+
+        for layer in self.layers.iter() {
+            match layer {
+                TiledLayer::Tiles(data) => {
+                    for (index, gid) in data.iter().enumerate() {
+                        if let Some(value) = self.get_tile_value(*gid) {
+                            map[index / self.width][index % self.width] =
+                                self.parse_floor_value(&value);
+                        }
+                    }
+                }
+                TiledLayer::Objects(objects) => {
+                    for object in objects.iter() {
+                        self.apply_floor_object(&mut map, object);
+                    }
+                }
+            }
+        }
+
+        map
+    }
+
+    fn apply_room_object(
+        &self,
+        tilemap: &mut [Vec<i32>],
+        object: &TiledObject,
+        game_data: Option<&GameData>,
+    ) {
+        let value = match object.class.as_str() {
+            "door" => {
+                for (row_index, column_index) in self.get_cells(object) {
+                    let value = tilemap[row_index][column_index];
+                    if value > 0 && WALL_VALUES.contains(&-value) {
+                        tilemap[row_index][column_index] = -value;
+                    }
+                }
+                return;
+            }
+            "hazard" => match HazardType::from_name(&object.name) {
+                Some(hazard_type) => hazard_type.get_value(),
+                None => panic!("Unknown hazard {} in {}", object.name, self.path),
+            },
+            "prop" => match game_data {
+                Some(game_data) => match game_data
+                    .get_props()
+                    .into_iter()
+                    .find(|prop| prop.name == object.name)
+                {
+                    Some(prop) => prop.tile,
+                    None => panic!("Unknown prop {} in {}", object.name, self.path),
+                },
+                // Props are defined in data.json, without it they are left out
+                None => return,
+            },
+            _ => match object.gid.and_then(|gid| self.get_tile_value(gid)) {
+                Some(value) => self.parse_room_value(&value),
+                None => panic!(
+                    "Unknown room object {:?} in {}, expected door, hazard, prop or a tile",
+                    object.class, self.path
+                ),
+            },
+        };
+
+        for (row_index, column_index) in self.get_cells(object) {
+            tilemap[row_index][column_index] = value;
+        }
+    }
+
+    fn apply_floor_object(&self, map: &mut [Vec<f32>], object: &TiledObject) {
+        let room_id = match object.properties.get(ROOM_PROPERTY) {
+            Some(room_id) => match room_id.parse::<i32>() {
+                Ok(room_id) => Some(room_id),
+                Err(_) => panic!("Invalid room id {} in {}", room_id, self.path),
+            },
+            None => None,
+        };
+
+        for (row_index, column_index) in self.get_cells(object) {
+            let value = map[row_index][column_index];
+            let mut room_number = value.floor() as i32 / 100;
+            let mut room_access = value.floor() as i32 / 10 % 10;
+            let mut room_type = (value.floor() as i32 % 10).max(1);
+            let mut marker = ((value - value.floor()) * 10.0).round() as i32;
+
+            match object.class.as_str() {
+                "normal" => room_type = 1,
+                "treasure" => room_type = 2,
+                "shrine" => room_type = 3,
+                "challenge" => room_type = 4,
+                "locked" => room_access = 1,
+                "secret" => room_access = 2,
+                "start" | "spawn" => marker = 1,
+                "end" => marker = 2,
+                "shop" => marker = 3,
+                "room" => (),
+                _ => panic!(
+                    "Unknown floor object {:?} in {}, expected a room type, locked, secret, start, end, shop or room",
+                    object.class, self.path
+                ),
+            }
+
+            if let Some(room_id) = room_id {
+                room_number = room_id;
+            }
+
+            map[row_index][column_index] =
+                (room_number * 100 + room_access * 10 + room_type) as f32 + marker as f32 / 10.0;
+        }
+    }
+
+    fn get_tile_value(&self, gid: u32) -> Option<String> {
+        let gid = gid & !FLIP_FLAGS;
+        if gid == 0 {
+            return None;
+        }
+
+        match self
+            .tilesets
+            .iter()
+            .filter(|tileset| tileset.first_gid <= gid)
+            .max_by_key(|tileset| tileset.first_gid)
+        {
+            Some(tileset) => {
+                let tile_id = gid - tileset.first_gid;
+                match tileset.values.get(&tile_id) {
+                    Some(value) => Some(value.clone()),
+                    None => Some(tile_id.to_string()),
+                }
+            }
+            None => panic!("Tile {} in {} has no tileset", gid, self.path),
+        }
+    }
+
+    fn parse_room_value(&self, value: &str) -> i32 {
+        match value.parse::<i32>() {
+            Ok(value) => value,
+            Err(_) => panic!("Invalid tile value {} in {}", value, self.path),
+        }
+    }
+
+    fn parse_floor_value(&self, value: &str) -> f32 {
+        match value.parse::<f32>() {
+            Ok(value) => value,
+            Err(_) => panic!("Invalid room value {} in {}", value, self.path),
+        }
+    }
+
+    fn get_cells(&self, object: &TiledObject) -> Vec<(usize, usize)> {
+        // Tile objects are anchored at their bottom left corner
+        let top = match object.gid {
+            Some(_) => object.y - object.height,
+            None => object.y,
+        };
+
+        let first_column = (object.x / self.tile_width).floor();
+        let first_row = (top / self.tile_height).floor();
+        let last_column = ((object.x + object.width) / self.tile_width)
+            .ceil()
+            .max(first_column + 1.0);
+        let last_row = ((top + object.height) / self.tile_height)
+            .ceil()
+            .max(first_row + 1.0);
+
+        if first_column < 0.0
+            || first_row < 0.0
+            || last_column > self.width as f32
+            || last_row > self.height as f32
+        {
+            panic!(
+                "Object {:?} at {}:{} is outside of {}",
+                object.class, object.x, object.y, self.path
+            );
+        }
+
+        let mut cells = Vec::new();
+        for row_index in first_row as usize..last_row as usize {
+            for column_index in first_column as usize..last_column as usize {
+                cells.push((row_index, column_index));
+            }
+        }
+        cells
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resources::mods::ModList;
+
+    const FIXTURES_DIRECTORY: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/tiled");
+
+    fn load_fixture(file_name: &str) -> TiledMap {
+        TiledMap::load(&format!("{}/{}", FIXTURES_DIRECTORY, file_name))
+    }
+
+    #[test]
+    fn room_maps_read_tiles_doors_hazards_and_props() {
+        let game_data = GameData::new(&ModList::load());
+        let pot = game_data.get_prop("Pot").tile;
+
+        for file_name in ["room.tmx", "room.json"] {
+            assert_eq!(
+                load_fixture(file_name).get_room_tilemap(Some(&game_data)),
+                vec![
+                    vec![3, 2, -2, 2, 4],
+                    vec![7, 10, 0, 0, 8],
+                    vec![7, 0, 0, pot, 8],
+                    vec![7, 0, 0, 0, 8],
+                    vec![5, 1, 1, 1, 6],
+                ],
+                "{}",
+                file_name
+            );
+        }
+    }
+
+    #[test]
+    fn room_maps_leave_props_out_without_game_data() {
+        let tilemap = load_fixture("room.tmx").get_room_tilemap(None);
+        assert_eq!(tilemap[2], vec![7, 0, 0, 0, 8]);
+    }
+
+    #[test]
+    fn floor_maps_read_room_values_and_markers() {
+        // The json fixture marks the player spawn with `spawn` instead of `start`
+        for file_name in ["floor.tmx", "floor.json"] {
+            assert_eq!(
+                load_fixture(file_name).get_floor_map(),
+                vec![vec![1.1, 1.0, 12.0], vec![0.0, 501.0, 1.2]],
+                "{}",
+                file_name
+            );
+        }
+    }
+}
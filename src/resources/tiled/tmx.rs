@@ -0,0 +1,183 @@
+use std::fs::File;
+use std::io::BufReader;
+use std::str::FromStr;
+use xml::attribute::OwnedAttribute;
+use xml::reader::{EventReader, XmlEvent};
+
+use crate::resources::tiled::{load_tileset as load_external_tileset, VALUE_PROPERTY};
+use crate::resources::tiled::{TiledLayer, TiledMap, TiledObject, TiledTileset};
+
+pub fn load_map(path: &str) -> TiledMap {
+    let mut map = TiledMap {
+        path: path.to_string(),
+        ..Default::default()
+    };
+
+    let mut tileset: Option<TiledTileset> = None;
+    let mut tile_id: Option<u32> = None;
+    let mut objects: Option<Vec<TiledObject>> = None;
+    let mut object: Option<TiledObject> = None;
+    let mut tiles: Option<Vec<u32>> = None;
+    let mut tiles_text = String::new();
+
+    let file = match File::open(path) {
+        Ok(file) => file,
+        Err(err) => panic!("Can't open Tiled file {}: {}", path, err),
+    };
+
+    for event in EventReader::new(BufReader::new(file)) {
+        let event = match event {
+            Ok(event) => event,
+            Err(err) => panic!("{}: XML was not well-formatted: {}", path, err),
+        };
+
+        match event {
+            XmlEvent::StartElement {
+                name, attributes, ..
+            } => match name.local_name.as_str() {
+                "map" => {
+                    if get_attribute(&attributes, "infinite").as_deref() == Some("1") {
+                        panic!("Infinite Tiled maps are not supported: {}", path);
+                    }
+                    map.width = parse_attribute(&attributes, "width", path).unwrap_or(0);
+                    map.height = parse_attribute(&attributes, "height", path).unwrap_or(0);
+                    map.tile_width = parse_attribute(&attributes, "tilewidth", path).unwrap_or(1.0);
+                    map.tile_height =
+                        parse_attribute(&attributes, "tileheight", path).unwrap_or(1.0);
+                }
+                "tileset" => {
+                    let first_gid = parse_attribute(&attributes, "firstgid", path).unwrap_or(0);
+                    match get_attribute(&attributes, "source") {
+                        Some(source) => map
+                            .tilesets
+                            .push(load_external_tileset(path, &source, first_gid)),
+                        None => {
+                            tileset = Some(TiledTileset {
+                                first_gid,
+                                ..Default::default()
+                            })
+                        }
+                    }
+                }
+                "tile" => match tiles.as_mut() {
+                    Some(tiles) => {
+                        tiles.push(parse_attribute(&attributes, "gid", path).unwrap_or(0))
+                    }
+                    None => tile_id = parse_attribute(&attributes, "id", path),
+                },
+                "data" => {
+                    match get_attribute(&attributes, "encoding") {
+                        Some(encoding) if encoding != "csv" => panic!(
+                            "Unsupported layer encoding {} in {}, save the map with the CSV layer format",
+                            encoding, path
+                        ),
+                        _ => (),
+                    }
+                    tiles = Some(Vec::new());
+                    tiles_text.clear();
+                }
+                "chunk" => panic!("Infinite Tiled maps are not supported: {}", path),
+                // Object groups inside a tileset are tile collision shapes
+                "objectgroup" if tileset.is_none() => objects = Some(Vec::new()),
+                "object" if objects.is_some() => {
+                    let class = match get_attribute(&attributes, "class") {
+                        Some(class) => class,
+                        None => get_attribute(&attributes, "type").unwrap_or_default(),
+                    };
+                    object = Some(TiledObject {
+                        name: get_attribute(&attributes, "name").unwrap_or_default(),
+                        class: class.to_lowercase(),
+                        x: parse_attribute(&attributes, "x", path).unwrap_or(0.0),
+                        y: parse_attribute(&attributes, "y", path).unwrap_or(0.0),
+                        width: parse_attribute(&attributes, "width", path).unwrap_or(0.0),
+                        height: parse_attribute(&attributes, "height", path).unwrap_or(0.0),
+                        gid: parse_attribute(&attributes, "gid", path),
+                        ..Default::default()
+                    });
+                }
+                "property" => {
+                    let name = get_attribute(&attributes, "name").unwrap_or_default();
+                    let value = get_attribute(&attributes, "value").unwrap_or_default();
+                    match (object.as_mut(), tileset.as_mut(), tile_id) {
+                        (Some(object), _, _) => {
+                            object.properties.insert(name, value);
+                        }
+                        (None, Some(tileset), Some(tile_id)) if name == VALUE_PROPERTY => {
+                            tileset.values.insert(tile_id, value);
+                        }
+                        _ => (),
+                    }
+                }
+                _ => (),
+            },
+            XmlEvent::Characters(text) | XmlEvent::CData(text) => {
+                if tiles.is_some() {
+                    tiles_text.push_str(&text);
+                }
+            }
+            XmlEvent::EndElement { name } => match name.local_name.as_str() {
+                "tileset" => {
+                    if let Some(tileset) = tileset.take() {
+                        map.tilesets.push(tileset);
+                    }
+                }
+                "tile" => {
+                    if tiles.is_none() {
+                        tile_id = None;
+                    }
+                }
+                "data" => {
+                    let mut layer_tiles = tiles.take().unwrap_or_default();
+                    for gid in tiles_text.split(',').map(|gid| gid.trim()) {
+                        if gid.is_empty() {
+                            continue;
+                        }
+                        match gid.parse::<u32>() {
+                            Ok(gid) => layer_tiles.push(gid),
+                            Err(_) => panic!("Invalid tile {} in {}", gid, path),
+                        }
+                    }
+                    map.layers.push(TiledLayer::Tiles(layer_tiles));
+                }
+                "object" => {
+                    if let (Some(objects), Some(object)) = (objects.as_mut(), object.take()) {
+                        objects.push(object);
+                    }
+                }
+                "objectgroup" => {
+                    if let Some(objects) = objects.take() {
+                        map.layers.push(TiledLayer::Objects(objects));
+                    }
+                }
+                _ => (),
+            },
+            _ => (),
+        }
+    }
+
+    map
+}
+
+pub fn load_tileset(path: &str) -> TiledTileset {
+    match load_map(path).tilesets.pop() {
+        Some(tileset) => tileset,
+        None => panic!("No tileset in {}", path),
+    }
+}
+
+fn get_attribute(attributes: &[OwnedAttribute], name: &str) -> Option<String> {
+    attributes
+        .iter()
+        .find(|attribute| attribute.name.local_name == name)
+        .map(|attribute| attribute.value.clone())
+}
+
+fn parse_attribute<T: FromStr>(attributes: &[OwnedAttribute], name: &str, path: &str) -> Option<T> {
+    match get_attribute(attributes, name) {
+        Some(value) => match value.parse::<T>() {
+            Ok(value) => Some(value),
+            Err(_) => panic!("Invalid {} {} in {}", name, value, path),
+        },
+        None => None,
+    }
+}
@@ -1,10 +1,10 @@
 use serde::{Deserialize, Serialize};
 
-use crate::resources::effect::effect_type::EffectType;
+use crate::resources::effect::effect_id::EffectId;
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct EffectUpgrade {
-    pub name: EffectType,
+    pub name: EffectId,
     pub duration_bonus: Option<i64>,
     pub duration_reduce: Option<i64>,
     pub speed_percent_bonus: Option<f32>,
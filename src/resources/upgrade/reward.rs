@@ -0,0 +1,35 @@
+use crate::resources::upgrade::rarity::Rarity;
+use crate::resources::upgrade::Upgrade;
+use crate::resources::weapon::Weapon;
+
+#[derive(Debug, Clone)]
+pub enum Reward {
+    Upgrade(Upgrade),
+    Weapon(Weapon),
+}
+
+impl PartialEq for Reward {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Reward::Upgrade(upgrade), Reward::Upgrade(other_upgrade)) => upgrade == other_upgrade,
+            (Reward::Weapon(weapon), Reward::Weapon(other_weapon)) => {
+                weapon.name == other_weapon.name
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Reward {
+    pub fn rarity(&self) -> Rarity {
+        match self {
+            Reward::Upgrade(upgrade) => upgrade.rarity,
+            Reward::Weapon(weapon) => match weapon.level {
+                0..=1 => Rarity::Common,
+                2 => Rarity::Rare,
+                3 => Rarity::Epic,
+                _ => Rarity::Legendary,
+            },
+        }
+    }
+}
@@ -1,14 +1,16 @@
 use serde::{Deserialize, Serialize};
 
-use crate::resources::skill::skill_type::SkillType;
-
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct SkillUpgrade {
-    pub name: SkillType,
+    pub name: String,
     pub duration_bonus: Option<i64>,
     pub cooldown_reduce: Option<i64>,
     pub require_monsters_reduce: Option<u32>,
+    pub area_bonus: Option<f32>,
+    pub heal_bonus: Option<f32>,
+    pub damage_multiplier_bonus: Option<f32>,
     pub speed_percent_bonus: Option<f32>,
+    pub damage_percent_bonus: Option<f32>,
     pub critical_chance_bonus: Option<f32>,
     pub dodge_chance_bonus: Option<f32>,
     pub restore_chance_bonus: Option<f32>,
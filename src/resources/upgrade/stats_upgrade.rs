@@ -9,4 +9,6 @@ pub struct StatsUpgrade {
     pub intelligence_bonus: Option<f32>,
     pub dodge_chance_bonus: Option<f32>,
     pub restore_chance_bonus: Option<f32>,
+    pub dash_cooldown_reduce: Option<f32>,
+    pub dash_invincible_duration_bonus: Option<f32>,
 }
@@ -1,34 +1,44 @@
 use rand::prelude::*;
 use bevy::prelude::*;
-use std::fs::File;
-use std::io::prelude::*;
 
 use crate::config::*;
-use crate::resources::hero::hero_class::HeroClass;
-use crate::resources::skill::skill_type::SkillType;
+use crate::resources::mods::{ModList, UPGRADES_ID_KEYS};
+use crate::resources::upgrade::rarity::{Rarity, RarityWeight};
+use crate::resources::upgrade::reward::Reward;
 use crate::resources::upgrade::upgrade_type::UpgradeType;
-use crate::resources::upgrade::Upgrade;
+use crate::resources::upgrade::{Upgrade, UpgradesData};
+use crate::resources::weapon::weapon_type::WeaponType;
+use crate::resources::weapon::Weapon;
+
+pub const REWARD_CHOICES: usize = 3;
+pub const REWARD_REROLLS: usize = 2;
+pub const REWARD_BANISHES: usize = 2;
 
 #[derive(Resource)]
 pub struct UpgradeController {
     pub upgrades: Vec<Upgrade>,
+    pub rarities: Vec<RarityWeight>,
+    pub rerolls: usize,
+    pub banishes: usize,
+    pub banished: Vec<Reward>,
+    pub locked_weapons: Vec<WeaponType>,
 }
 
 impl UpgradeController {
-    pub fn new() -> Self {
-        let upgrades: Vec<Upgrade> = match File::open(UPGRADES_FILE) {
-            Ok(mut file) => {
-                let mut contents = String::new();
-                file.read_to_string(&mut contents).unwrap();
-                serde_json::from_str(&contents).expect("JSON was not well-formatted")
-            }
-            Err(err) => panic!("Can't find language file: {}", err),
-        };
-
-        UpgradeController { upgrades }
+    pub fn new(mod_list: &ModList) -> Self {
+        let upgrades_data: UpgradesData = mod_list.load_json(UPGRADES_FILE, &UPGRADES_ID_KEYS);
+
+        UpgradeController {
+            upgrades: upgrades_data.upgrades,
+            rarities: upgrades_data.rarities,
+            rerolls: REWARD_REROLLS,
+            banishes: REWARD_BANISHES,
+            banished: Vec::new(),
+            locked_weapons: Vec::new(),
+        }
     }
 
-    pub fn get_skill_upgrade(&self, skill_name: SkillType) -> Upgrade {
+    pub fn get_skill_upgrade(&self, skill_name: String) -> Upgrade {
         let skill_upgrades: Vec<Upgrade> = self
             .upgrades
             .iter()
@@ -70,36 +80,54 @@ impl UpgradeController {
         effect_upgrades[random_index].clone()
     }
 
-    pub fn get_three_upgrades(&self, hero_class: HeroClass, weapon_level: u8) -> Vec<UpgradeType> {
-        let mut three_upgrades: Vec<UpgradeType> = Vec::new();
-        let mut upgrade_types: Vec<UpgradeType> =
-            vec![UpgradeType::Stats, UpgradeType::Skill, UpgradeType::Effect];
-
-        if (hero_class == HeroClass::Elf && weapon_level < 2)
-            || (hero_class != HeroClass::Elf && weapon_level < 3)
-        {
-            upgrade_types.push(UpgradeType::Weapon);
-        }
+    pub fn get_rewards(
+        &self,
+        skill_name: String,
+        weapon_upgrades: Vec<Weapon>,
+        excluded: &[Reward],
+    ) -> Vec<Reward> {
+        let rewards: Vec<Reward> = self
+            .upgrades
+            .iter()
+            .filter(|upgrade| match upgrade.upgrade_type {
+                UpgradeType::Skill => upgrade.skill_upgrade.clone().unwrap().name == skill_name,
+                UpgradeType::Weapon => false,
+                _ => true,
+            })
+            .cloned()
+            .map(Reward::Upgrade)
+            .chain(
+                weapon_upgrades
+                    .into_iter()
+                    .filter(|weapon| !self.locked_weapons.contains(&weapon.name))
+                    .map(Reward::Weapon),
+            )
+            .filter(|reward| !self.banished.contains(reward) && !excluded.contains(reward))
+            .collect();
 
         let mut rng = rand::thread_rng();
+        rewards
+            .choose_multiple_weighted(&mut rng, REWARD_CHOICES, |reward| {
+                self.get_rarity_weight(reward.rarity())
+            })
+            .expect("Rarity weights must be positive")
+            .cloned()
+            .collect()
+    }
 
-        loop {
-            if three_upgrades.len() < 4 {
-                let random_index = rng.gen_range(0..upgrade_types.len());
-                let upgrade_type = upgrade_types[random_index].clone();
-
-                if upgrade_type == UpgradeType::Weapon {
-                    if !three_upgrades.contains(&upgrade_type) {
-                        three_upgrades.push(UpgradeType::Weapon);
-                    }
-                } else {
-                    three_upgrades.push(upgrade_type);
-                }
-            } else {
-                break;
-            }
+    pub fn get_rarity_weight(&self, rarity: Rarity) -> f32 {
+        match self
+            .rarities
+            .iter()
+            .find(|rarity_weight| rarity_weight.rarity == rarity)
+        {
+            Some(rarity_weight) => rarity_weight.weight,
+            None => panic!("Missing weight for rarity {:?}", rarity),
         }
+    }
 
-        three_upgrades
+    pub fn banish(&mut self, reward: Reward) {
+        self.banishes -= 1;
+        self.banished.push(reward);
     }
 }
@@ -1,10 +1,12 @@
 use bevy_inspector_egui::InspectorOptions;
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Debug, Clone, InspectorOptions, Copy)]
+#[derive(Serialize, Deserialize, Debug, Clone, InspectorOptions)]
 pub struct Bullet {
     pub width: f32,
     pub height: f32,
     pub speed: f32,
     pub scale: f32,
+    pub sprite: String,
+    pub color: Option<[f32; 3]>,
 }
@@ -1,17 +1,18 @@
 use bevy_inspector_egui::InspectorOptions;
 use serde::{Deserialize, Serialize};
 
-use crate::resources::effect::effect_type::EffectType;
+use crate::resources::effect::effect_id::EffectId;
 
 pub mod attack_type;
 pub mod bullet;
 pub mod weapon_type;
+pub mod weapon_upgrade;
 
 use attack_type::AttackType;
 use bullet::Bullet;
 use weapon_type::WeaponType;
 
-#[derive(Serialize, Deserialize, Debug, Clone, InspectorOptions, Copy)]
+#[derive(Serialize, Deserialize, Debug, Clone, InspectorOptions)]
 pub struct Weapon {
     pub name: WeaponType,
     pub attack_type: AttackType,
@@ -21,10 +22,14 @@ pub struct Weapon {
     pub level: u8,
     pub width: f32,
     pub height: f32,
-    pub effect: Option<EffectType>,
+    pub effect: Option<EffectId>,
     pub trigger_chance: Option<f32>,
     pub effect_bonus: Option<f32>,
     pub bullet: Option<Bullet>,
     pub cooldown: Option<u64>,
     pub scale: f32,
+    pub sprite: String,
+    pub aim_rotation: Option<f32>,
+    #[serde(default)]
+    pub shoot_while_moving: bool,
 }
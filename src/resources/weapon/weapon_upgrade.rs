@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+use crate::resources::weapon::weapon_type::WeaponType;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct WeaponUpgrade {
+    pub weapon: WeaponType,
+    pub upgrades_to: Vec<WeaponType>,
+}
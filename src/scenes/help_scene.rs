@@ -125,15 +125,20 @@ fn texts(root: &mut ChildBuilder, font_materials: &FontMaterials, dictionary: &D
     let font = font_materials.get_font(dictionary.get_current_language());
     let glossary = dictionary.get_glossary();
 
-    let position_of_texts: [[f32; 2]; 8] = [
+    let position_of_texts: [[f32; 2]; 13] = [
         [465.0, 100.0],
-        [300.0, 160.0],
-        [300.0, 205.0],
-        [300.0, 250.0],
+        [300.0, 150.0],
+        [300.0, 179.0],
+        [300.0, 208.0],
+        [300.0, 237.0],
+        [300.0, 266.0],
         [300.0, 295.0],
-        [300.0, 340.0],
-        [300.0, 385.0],
-        [300.0, 430.0],
+        [300.0, 324.0],
+        [300.0, 353.0],
+        [300.0, 382.0],
+        [300.0, 411.0],
+        [300.0, 440.0],
+        [300.0, 469.0],
     ];
 
     for (index, position) in position_of_texts.iter().enumerate() {
@@ -146,6 +151,11 @@ fn texts(root: &mut ChildBuilder, font_materials: &FontMaterials, dictionary: &D
             5 => glossary.help_scene_text.use_skill.clone(),
             6 => glossary.help_scene_text.attack.clone(),
             7 => glossary.help_scene_text.aim.clone(),
+            8 => glossary.help_scene_text.dash.clone(),
+            9 => glossary.help_scene_text.pick_up_weapon.clone(),
+            10 => glossary.help_scene_text.swap_weapon.clone(),
+            11 => glossary.help_scene_text.use_potion.clone(),
+            12 => glossary.help_scene_text.toggle_map.clone(),
             _ => panic!("Unknown text"),
         };
 
@@ -185,14 +195,19 @@ fn texts(root: &mut ChildBuilder, font_materials: &FontMaterials, dictionary: &D
 fn control_texts(root: &mut ChildBuilder, font_materials: &FontMaterials, dictionary: &Dictionary) {
     let font = font_materials.get_font(dictionary.get_current_language());
 
-    let position_of_texts: [[f32; 2]; 7] = [
-        [645.0, 160.0],
-        [650.0, 205.0],
-        [650.0, 250.0],
-        [650.0, 295.0],
-        [620.0, 340.0],
-        [620.0, 385.0],
-        [620.0, 430.0],
+    let position_of_texts: [[f32; 2]; 12] = [
+        [645.0, 150.0],
+        [650.0, 179.0],
+        [650.0, 208.0],
+        [650.0, 237.0],
+        [620.0, 266.0],
+        [620.0, 295.0],
+        [620.0, 324.0],
+        [620.0, 353.0],
+        [650.0, 382.0],
+        [650.0, 411.0],
+        [635.0, 440.0],
+        [640.0, 469.0],
     ];
 
     for (index, position) in position_of_texts.iter().enumerate() {
@@ -204,6 +219,11 @@ fn control_texts(root: &mut ChildBuilder, font_materials: &FontMaterials, dictio
             4 => "SPACE",
             5 => "MOUSE 1",
             6 => "MOUSE",
+            7 => "SHIFT",
+            8 => "E",
+            9 => "Q",
+            10 => "1 - 4",
+            11 => "TAB",
             _ => panic!("Unknown text"),
         };
 
@@ -1,20 +1,31 @@
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
-use std::slice::Iter;
 
+use crate::config::*;
 use crate::materials::font::FontMaterials;
 use crate::materials::ingame::InGameMaterials;
 use crate::materials::menu_box::MenuBoxMaterials;
 use crate::materials::scenes::ScenesMaterials;
 use crate::resources::dictionary::Dictionary;
+use crate::resources::game_data::GameData;
 use crate::resources::game_mode::GameMode;
 use crate::resources::hero::gender::Gender;
 use crate::resources::hero::hero_class::HeroClass;
+use crate::resources::meta_progression::achievement::Unlock;
+use crate::resources::meta_progression::MetaProgression;
 use crate::resources::profile::Profile;
 use crate::scenes::SceneState;
 
 const RETURN_BUTTON_SIZE: f32 = 50.0;
 const BOX_TILE_SIZE: f32 = 60.0;
 
+const ROSTER_CENTER_X: f32 = 12.5;
+const ROSTER_TOP_Y: f32 = 75.0;
+const ROSTER_MAX_SPACING: f32 = 175.0;
+const ROSTER_WIDTH: f32 = 700.0;
+const HERO_BUTTON_WIDTH: f32 = 100.0;
+const HERO_BUTTON_HEIGHT: f32 = 150.0;
+
 const BOX_ARRAY: [[i8; 13]; 9] = [
     [0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 2],
     [3, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 5],
@@ -30,55 +41,21 @@ const BOX_ARRAY: [[i8; 13]; 9] = [
 #[derive(Component)]
 struct ReturnButtonComponent;
 
-#[derive(Component, PartialEq, Clone)]
-pub enum ButtonComponent {
-    MaleElf,
-    FemaleElf,
-    MaleKnight,
-    FemaleKnight,
-    MaleWizard,
-    FemaleWizard,
-    MaleLizard,
-    FemaleLizard,
-}
-
-impl ButtonComponent {
-    pub fn iterator() -> Iter<'static, ButtonComponent> {
-        [
-            ButtonComponent::MaleElf,
-            ButtonComponent::MaleKnight,
-            ButtonComponent::MaleWizard,
-            ButtonComponent::MaleLizard,
-            ButtonComponent::FemaleElf,
-            ButtonComponent::FemaleKnight,
-            ButtonComponent::FemaleWizard,
-            ButtonComponent::FemaleLizard,
-        ]
-        .iter()
-    }
+#[derive(Component, Clone, PartialEq, Eq)]
+struct HeroComponent {
+    hero_class: HeroClass,
+    gender: Gender,
 }
 
 #[derive(Resource)]
 struct AnimationController {
     run_animation: bool,
-    hero_image: HeroImageComponent,
+    hero: Option<HeroComponent>,
     timer: Timer,
 }
 
 pub struct HeroSelectScenePlugin;
 
-#[derive(Component, Clone, PartialEq, Eq)]
-enum HeroImageComponent {
-    MaleElf,
-    FemaleElf,
-    MaleKnight,
-    FemaleKnight,
-    MaleWizard,
-    FemaleWizard,
-    MaleLizard,
-    FemaleLizard,
-}
-
 #[derive(Resource)]
 struct HeroSelectSceneData {
     sprite_bundle: Entity,
@@ -101,12 +78,28 @@ impl Plugin for HeroSelectScenePlugin {
     }
 }
 
+#[derive(SystemParam)]
+struct HeroRoster<'w> {
+    game_data: Res<'w, GameData>,
+    meta_progression: Res<'w, MetaProgression>,
+}
+
+impl HeroRoster<'_> {
+    fn is_unlocked(&self, hero_class: &HeroClass) -> bool {
+        self.meta_progression.is_unlocked(
+            &self.game_data.get_achievements(),
+            Unlock::Hero(hero_class.clone()),
+        )
+    }
+}
+
 fn setup(
     texture_atlases: ResMut<Assets<TextureAtlas>>,
     ingame_materials: Res<InGameMaterials>,
     scenes_materials: Res<ScenesMaterials>,
     font_materials: Res<FontMaterials>,
     dictionary: Res<Dictionary>,
+    hero_roster: HeroRoster,
     mut commands: Commands,
 ) {
     let sprite_bundle = commands
@@ -116,7 +109,13 @@ fn setup(
         })
         .with_children(|parent| {
             menu_box(parent, &scenes_materials.menu_box_materials);
-            heroes_images(parent, &ingame_materials, texture_atlases)
+            heroes_images(
+                parent,
+                &ingame_materials,
+                texture_atlases,
+                &hero_roster.game_data,
+                &hero_roster.meta_progression,
+            )
         })
         .insert(Name::new("SpriteBundle"))
         .id();
@@ -134,7 +133,7 @@ fn setup(
         .with_children(|parent| {
             select_hero_text(parent, &font_materials, &dictionary);
             return_button(parent, &scenes_materials);
-            heroes_buttons(parent);
+            heroes_buttons(parent, &hero_roster.game_data);
         })
         .insert(Name::new("UIRoot"))
         .id();
@@ -146,7 +145,7 @@ fn setup(
 
     commands.insert_resource(AnimationController {
         run_animation: false,
-        hero_image: HeroImageComponent::MaleElf,
+        hero: None,
         timer: Timer::from_seconds(0.1, TimerMode::Repeating),
     });
 }
@@ -248,100 +247,72 @@ fn return_button_handle(
     }
 }
 
+fn roster_layout(game_data: &GameData) -> Vec<(HeroComponent, Vec2)> {
+    let heroes = game_data.get_heroes();
+    let spacing = ROSTER_MAX_SPACING.min(ROSTER_WIDTH / heroes.len() as f32);
+    let start_x = ROSTER_CENTER_X - spacing * (heroes.len() as f32 - 1.0) / 2.0;
+
+    let mut layout = Vec::new();
+    for (column_index, hero) in heroes.iter().enumerate() {
+        for (row_index, variant) in hero.variants.iter().enumerate() {
+            layout.push((
+                HeroComponent {
+                    hero_class: hero.hero_class.clone(),
+                    gender: variant.gender,
+                },
+                Vec2::new(
+                    start_x + spacing * column_index as f32,
+                    ROSTER_TOP_Y - ROSTER_MAX_SPACING * row_index as f32,
+                ),
+            ));
+        }
+    }
+    layout
+}
+
 fn heroes_images(
     root: &mut ChildBuilder,
     ingame_materials: &InGameMaterials,
     mut texture_atlases: ResMut<Assets<TextureAtlas>>,
+    game_data: &GameData,
+    meta_progression: &MetaProgression,
 ) {
-    let mut index = 0;
-    let hero_image_positions: [[f32; 2]; 8] = [
-        [-250.0, 75.0],
-        [-250.0, -100.0],
-        [-75.0, 75.0],
-        [-75.0, -100.0],
-        [100.0, 75.0],
-        [100.0, -100.0],
-        [275.0, 75.0],
-        [275.0, -100.0],
-    ];
-
-    for hero_class in HeroClass::iterator() {
-        for gender in Gender::iterator() {
-            let hero_tileset;
-            let hero_image;
-            let component_name;
-
-            match hero_class {
-                HeroClass::Elf => match gender {
-                    Gender::Male => {
-                        hero_tileset = ingame_materials.heroes_materials.male_elf.clone();
-                        hero_image = HeroImageComponent::MaleElf;
-                        component_name = format!("{}_{}", "Elf", "Male");
-                    }
-                    Gender::Female => {
-                        hero_tileset = ingame_materials.heroes_materials.female_elf.clone();
-                        hero_image = HeroImageComponent::FemaleElf;
-                        component_name = format!("{}_{}", "Elf", "Female");
-                    }
-                },
-                HeroClass::Knight => match gender {
-                    Gender::Male => {
-                        hero_tileset = ingame_materials.heroes_materials.male_knight.clone();
-                        hero_image = HeroImageComponent::MaleKnight;
-                        component_name = format!("{}_{}", "Knight", "Male");
-                    }
-                    Gender::Female => {
-                        hero_tileset = ingame_materials.heroes_materials.female_knight.clone();
-                        hero_image = HeroImageComponent::FemaleKnight;
-                        component_name = format!("{}_{}", "Knight", "Female");
-                    }
-                },
-                HeroClass::Lizard => match gender {
-                    Gender::Male => {
-                        hero_tileset = ingame_materials.heroes_materials.male_lizard.clone();
-                        hero_image = HeroImageComponent::MaleLizard;
-                        component_name = format!("{}_{}", "Lizard", "Male");
-                    }
-                    Gender::Female => {
-                        hero_tileset = ingame_materials.heroes_materials.female_lizard.clone();
-                        hero_image = HeroImageComponent::FemaleLizard;
-                        component_name = format!("{}_{}", "Lizard", "Female");
-                    }
-                },
-                HeroClass::Wizard => match gender {
-                    Gender::Male => {
-                        hero_tileset = ingame_materials.heroes_materials.male_wizard.clone();
-                        hero_image = HeroImageComponent::MaleWizard;
-                        component_name = format!("{}_{}", "Wizard", "Male");
-                    }
-                    Gender::Female => {
-                        hero_tileset = ingame_materials.heroes_materials.female_wizard.clone();
-                        hero_image = HeroImageComponent::FemaleWizard;
-                        component_name = format!("{}_{}", "Wizard", "Female");
-                    }
-                },
-            };
-
-            let texture_atlas =
-                TextureAtlas::from_grid(hero_tileset, Vec2::new(16.0, 28.0), 9, 1, None, None);
-            let texture_atlas_handle = texture_atlases.add(texture_atlas);
-
-            let x = hero_image_positions[index][0];
-            let y = hero_image_positions[index][1];
-
-            root.spawn(SpriteSheetBundle {
-                texture_atlas: texture_atlas_handle,
-                transform: Transform {
-                    translation: Vec3::new(x, y, 0.2),
-                    scale: Vec3::splat(4.0),
-                    ..Default::default()
+    let achievements = game_data.get_achievements();
+
+    for (hero, position) in roster_layout(game_data) {
+        let is_unlocked =
+            meta_progression.is_unlocked(&achievements, Unlock::Hero(hero.hero_class.clone()));
+        let variant = game_data
+            .get_hero(hero.hero_class.clone())
+            .get_variant(hero.gender);
+        let hero_tileset = ingame_materials
+            .heroes_materials
+            .get_texture(&variant.sprite);
+        let component_name = format!("{}_{:?}", hero.hero_class.0, hero.gender);
+
+        let texture_atlas =
+            TextureAtlas::from_grid(hero_tileset, Vec2::new(16.0, 28.0), 9, 1, None, None);
+        let texture_atlas_handle = texture_atlases.add(texture_atlas);
+
+        root.spawn(SpriteSheetBundle {
+            texture_atlas: texture_atlas_handle,
+            sprite: TextureAtlasSprite {
+                color: if is_unlocked {
+                    Color::WHITE
+                } else {
+                    Color::rgb(0.15, 0.15, 0.15)
                 },
                 ..Default::default()
-            })
-            .insert(Name::new(component_name))
-            .insert(hero_image);
-            index += 1;
-        }
+            },
+            transform: Transform {
+                translation: Vec3::new(position.x, position.y, 0.2),
+                scale: Vec3::splat(4.0),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .insert(Name::new(component_name))
+        .insert(hero);
     }
 }
 
@@ -373,89 +344,51 @@ fn select_hero_text(
     .insert(Name::new("SelectHeroText"));
 }
 
-fn heroes_buttons(root: &mut ChildBuilder) {
-    let button_positions: [[f32; 2]; 8] = [
-        [210.0, 170.0],
-        [380.0, 170.0],
-        [560.0, 170.0],
-        [740.0, 170.0],
-        [210.0, 350.0],
-        [380.0, 350.0],
-        [560.0, 350.0],
-        [740.0, 350.0],
-    ];
-
-    for (index, value) in ButtonComponent::iterator().enumerate() {
-        let component_name = match index {
-            1 => "MaleElf",
-            2 => "MaleKnight",
-            3 => "MaleLizard",
-            4 => "MaleWizard",
-            5 => "FemaleElf",
-            6 => "FemaleKnight",
-            7 => "FemaleLizard",
-            _ => "FemaleWizard",
-        };
+fn heroes_buttons(root: &mut ChildBuilder, game_data: &GameData) {
+    for (hero, position) in roster_layout(game_data) {
+        let component_name = format!("{:?}{}", hero.gender, hero.hero_class.0);
 
         root.spawn(ButtonBundle {
             style: Style {
                 position_type: PositionType::Absolute,
-                left: Val::Px(button_positions[index][0]),
-                top: Val::Px(button_positions[index][1]),
+                left: Val::Px(
+                    WINDOW_HEIGHT * RESOLUTION / 2.0 + position.x - HERO_BUTTON_WIDTH / 2.0,
+                ),
+                top: Val::Px(WINDOW_HEIGHT / 2.0 - position.y - 43.0),
                 right: Val::Auto,
                 bottom: Val::Auto,
-                width: Val::Px(100.0),
-                height: Val::Px(150.0),
+                width: Val::Px(HERO_BUTTON_WIDTH),
+                height: Val::Px(HERO_BUTTON_HEIGHT),
                 ..Default::default()
             },
             background_color: BackgroundColor(Color::NONE),
             ..Default::default()
         })
         .insert(Name::new(component_name))
-        .insert(value.clone());
+        .insert(hero);
     }
 }
 
 fn hero_select_handle(
-    mut button_query: Query<(&Interaction, &ButtonComponent), (Changed<Interaction>, With<Button>)>,
+    mut button_query: Query<(&Interaction, &HeroComponent), (Changed<Interaction>, With<Button>)>,
     mut profile: ResMut<Profile>,
     mut animation_controller: ResMut<AnimationController>,
     mut state: ResMut<NextState<SceneState>>,
+    hero_roster: HeroRoster,
 ) {
-    for (interaction, button) in button_query.iter_mut() {
+    for (interaction, hero) in button_query.iter_mut() {
+        if !hero_roster.is_unlocked(&hero.hero_class) {
+            continue;
+        }
+
         match interaction {
             Interaction::None => animation_controller.run_animation = false,
             Interaction::Hovered => {
                 animation_controller.run_animation = true;
-                match button {
-                    ButtonComponent::MaleElf => {
-                        animation_controller.hero_image = HeroImageComponent::MaleElf
-                    }
-                    ButtonComponent::FemaleElf => {
-                        animation_controller.hero_image = HeroImageComponent::FemaleElf
-                    }
-                    ButtonComponent::MaleKnight => {
-                        animation_controller.hero_image = HeroImageComponent::MaleKnight
-                    }
-                    ButtonComponent::FemaleKnight => {
-                        animation_controller.hero_image = HeroImageComponent::FemaleKnight
-                    }
-                    ButtonComponent::MaleLizard => {
-                        animation_controller.hero_image = HeroImageComponent::MaleLizard
-                    }
-                    ButtonComponent::FemaleLizard => {
-                        animation_controller.hero_image = HeroImageComponent::FemaleLizard
-                    }
-                    ButtonComponent::MaleWizard => {
-                        animation_controller.hero_image = HeroImageComponent::MaleWizard
-                    }
-                    ButtonComponent::FemaleWizard => {
-                        animation_controller.hero_image = HeroImageComponent::FemaleWizard
-                    }
-                };
+                animation_controller.hero = Some(hero.clone());
             }
             Interaction::Pressed => {
-                profile.set_hero(button.clone());
+                profile.set_hero(hero.hero_class.clone(), hero.gender);
                 if profile.game_mode == GameMode::ClassicMode {
                     state.set(SceneState::PreClassicMode);
                 } else {
@@ -468,11 +401,11 @@ fn hero_select_handle(
 
 fn hero_image_animation_handle(
     time: Res<Time>,
-    mut query: Query<(&HeroImageComponent, &mut TextureAtlasSprite)>,
+    mut query: Query<(&HeroComponent, &mut TextureAtlasSprite)>,
     mut animation_controller: ResMut<AnimationController>,
 ) {
-    for (hero_image, mut sprite) in query.iter_mut() {
-        if animation_controller.run_animation && *hero_image == animation_controller.hero_image {
+    for (hero, mut sprite) in query.iter_mut() {
+        if animation_controller.run_animation && animation_controller.hero.as_ref() == Some(hero) {
             animation_controller.timer.tick(time.delta());
             if animation_controller.timer.just_finished() {
                 let min_index = 0;
@@ -9,8 +9,8 @@ use crate::config::HIGHSCORE_FILE;
 use crate::materials::font::FontMaterials;
 use crate::materials::scenes::ScenesMaterials;
 use crate::resources::dictionary::Dictionary;
+use crate::resources::game_data::GameData;
 use crate::resources::hero::gender::Gender;
-use crate::resources::hero::hero_class::HeroClass;
 use crate::resources::language::Language;
 use crate::resources::tile_size::TileSize;
 use crate::resources::{game_mode::GameMode, stored_profile::StoredProfile};
@@ -390,30 +390,26 @@ fn hero_image_handle_system(
     mut query: Query<(&HeroImageComponent, &mut UiImage, &mut Visibility)>,
     mut highscore_book_query: Query<&mut HighscoreBookComponent>,
     scenes_materials: Res<ScenesMaterials>,
+    game_data: Res<GameData>,
 ) {
     for (_hero_image, mut ui_image, mut visibility) in query.iter_mut() {
         let highscore_book = highscore_book_query.get_single_mut().unwrap();
         if highscore_book.current_page != -1 && highscore_book.animation_indexes.is_empty() {
             let index = highscore_book.current_page as usize;
-            ui_image.texture = match highscore_book.profiles[index].hero_class {
-                HeroClass::Elf => match highscore_book.profiles[index].gender {
-                    Gender::Male => scenes_materials.heroes_materials.male_elf.clone(),
-                    Gender::Female => scenes_materials.heroes_materials.female_elf.clone(),
-                },
-                HeroClass::Knight => match highscore_book.profiles[index].gender {
-                    Gender::Male => scenes_materials.heroes_materials.male_knight.clone(),
-                    Gender::Female => scenes_materials.heroes_materials.female_knight.clone(),
-                },
-                HeroClass::Lizard => match highscore_book.profiles[index].gender {
-                    Gender::Male => scenes_materials.heroes_materials.male_lizard.clone(),
-                    Gender::Female => scenes_materials.heroes_materials.female_lizard.clone(),
-                },
-                HeroClass::Wizard => match highscore_book.profiles[index].gender {
-                    Gender::Male => scenes_materials.heroes_materials.male_wizard.clone(),
-                    Gender::Female => scenes_materials.heroes_materials.female_wizard.clone(),
-                },
-            };
-            *visibility = Visibility::Visible;
+            let stored_profile = &highscore_book.profiles[index];
+            let hero = game_data
+                .get_heroes()
+                .into_iter()
+                .find(|hero| hero.hero_class == stored_profile.hero_class);
+
+            match hero {
+                Some(hero) => {
+                    let portrait = hero.get_variant(stored_profile.gender).portrait;
+                    ui_image.texture = scenes_materials.heroes_materials.get_texture(&portrait);
+                    *visibility = Visibility::Visible;
+                }
+                None => *visibility = Visibility::Hidden,
+            }
         } else {
             *visibility = Visibility::Hidden;
         }
@@ -3,23 +3,21 @@ use bevy::prelude::*;
 use crate::config::*;
 use crate::scenes::SceneState;
 
-use crate::materials::bullets::BulletsMaterials;
-use crate::materials::dungeon::DungeonMaterials;
 use crate::materials::flag::FlagMaterials;
 use crate::materials::font::FontMaterials;
-use crate::materials::hearts::HeartsMaterials;
 use crate::materials::heroes::HeroesMaterials;
 use crate::materials::icon::IconMaterials;
 use crate::materials::ingame::InGameMaterials;
 use crate::materials::menu_box::MenuBoxMaterials;
-use crate::materials::monsters::MonstersMaterials;
-use crate::materials::potions::PotionsMaterials;
 use crate::materials::scenes::ScenesMaterials;
-use crate::materials::weapons::WeaponsMaterials;
 use crate::resources::dictionary::Dictionary;
 use crate::resources::dungeon::rooms::Rooms;
 use crate::resources::game_data::GameData;
+use crate::resources::hero::gender::Gender;
 use crate::resources::language::Language;
+use crate::resources::mods::ModList;
+use crate::resources::playtest::Playtest;
+use crate::resources::profile::Profile;
 
 const LOADING_TEXT_FONT_SIZE: f32 = 30.0;
 const TEXT_FONT_SIZE: f32 = 40.0;
@@ -44,10 +42,11 @@ impl Plugin for LoadingScenePlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(OnEnter(SceneState::LoadingScene), setup);
         app.add_systems(Update, (
-            load_materials,
             load_data,
+            apply_deferred,
+            load_materials,
             update_loader
-        ).run_if(in_state(SceneState::LoadingScene)));
+        ).chain().run_if(in_state(SceneState::LoadingScene)));
         app.add_systems(OnExit(SceneState::LoadingScene), cleanup);
     }
 }
@@ -212,6 +211,9 @@ fn update_loader(
     mut query: Query<(&mut LoaderComponent, &mut Style, &Children)>,
     mut state: ResMut<NextState<SceneState>>,
     mut text_query: Query<&mut Text>,
+    mut commands: Commands,
+    playtest: Res<Playtest>,
+    game_data: Res<GameData>,
 ) {
     for (mut loader, mut style, children) in query.iter_mut() {
         if loader.current_width < loader.max_width {
@@ -223,6 +225,12 @@ fn update_loader(
                 let mut text = text_query.get_mut(children[0]).unwrap();
                 text.sections[0].value = value.to_string() + "%";
             }
+        } else if playtest.is_active() {
+            let hero = &game_data.get_heroes()[0];
+            let mut profile = Profile::new();
+            profile.set_hero(hero.hero_class.clone(), Gender::Male);
+            commands.insert_resource(profile);
+            state.set(SceneState::PreClassicMode);
         } else {
             state
                 .set(SceneState::MainMenuScene);
@@ -230,7 +238,12 @@ fn update_loader(
     }
 }
 
-fn load_materials(mut commands: Commands, asset_server: Res<AssetServer>) {
+fn load_materials(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    game_data: Res<GameData>,
+    mod_list: Res<ModList>,
+) {
     let font_materials: FontMaterials = FontMaterials {
         roboto_font: asset_server.load(ROBOTO_FONT),
         fibberish_font: asset_server.load(FIBBERISH_FONT),
@@ -240,135 +253,56 @@ fn load_materials(mut commands: Commands, asset_server: Res<AssetServer>) {
         main_background_image: asset_server.load(MAIN_MENU_BACKGROUND_IMAGE),
         sub_background_image: asset_server.load(SUB_MENU_BACKGROUND_IMAGE),
         menu_box_materials: MenuBoxMaterials {
-            top_right: asset_server.load("scenes/gui/menu_box/top_right.png"),
-            top_center: asset_server.load("scenes/gui/menu_box/top_center.png"),
-            top_left: asset_server.load("scenes/gui/menu_box/top_left.png"),
-            mid_right: asset_server.load("scenes/gui/menu_box/mid_right.png"),
-            mid_center: asset_server.load("scenes/gui/menu_box/mid_center.png"),
-            mid_left: asset_server.load("scenes/gui/menu_box/mid_left.png"),
-            bottom_right: asset_server.load("scenes/gui/menu_box/bottom_right.png"),
-            bottom_center: asset_server.load("scenes/gui/menu_box/bottom_center.png"),
-            bottom_left: asset_server.load("scenes/gui/menu_box/bottom_left.png"),
+            top_right: asset_server.load(mod_list.resolve_sprite("scenes/gui/menu_box/top_right.png")),
+            top_center: asset_server.load(mod_list.resolve_sprite("scenes/gui/menu_box/top_center.png")),
+            top_left: asset_server.load(mod_list.resolve_sprite("scenes/gui/menu_box/top_left.png")),
+            mid_right: asset_server.load(mod_list.resolve_sprite("scenes/gui/menu_box/mid_right.png")),
+            mid_center: asset_server.load(mod_list.resolve_sprite("scenes/gui/menu_box/mid_center.png")),
+            mid_left: asset_server.load(mod_list.resolve_sprite("scenes/gui/menu_box/mid_left.png")),
+            bottom_right: asset_server.load(mod_list.resolve_sprite("scenes/gui/menu_box/bottom_right.png")),
+            bottom_center: asset_server.load(mod_list.resolve_sprite("scenes/gui/menu_box/bottom_center.png")),
+            bottom_left: asset_server.load(mod_list.resolve_sprite("scenes/gui/menu_box/bottom_left.png")),
         },
         icon_materials: IconMaterials {
-            home_icon_normal: asset_server.load("icons/home_icon_normal.png"),
-            home_icon_hovered: asset_server.load("icons/home_icon_hovered.png"),
-            home_icon_clicked: asset_server.load("icons/home_icon_clicked.png"),
-            music_icon_on: asset_server.load("icons/music_icon_on.png"),
-            music_icon_off: asset_server.load("icons/music_icon_off.png"),
-            music_icon_hovered: asset_server.load("icons/music_icon_hovered.png"),
-            sound_icon_on: asset_server.load("icons/sound_icon_on.png"),
-            sound_icon_off: asset_server.load("icons/sound_icon_off.png"),
-            sound_icon_hovered: asset_server.load("icons/sound_icon_hovered.png"),
-            leaderboard: asset_server.load("icons/leaderboard.png"),
-            leaderboard_hovered: asset_server.load("icons/leaderboard_hovered.png"),
-            restart: asset_server.load("icons/restart.png"),
-            restart_hovered: asset_server.load("icons/restart_hovered.png"),
+            home_icon_normal: asset_server.load(mod_list.resolve_sprite("icons/home_icon_normal.png")),
+            home_icon_hovered: asset_server.load(mod_list.resolve_sprite("icons/home_icon_hovered.png")),
+            home_icon_clicked: asset_server.load(mod_list.resolve_sprite("icons/home_icon_clicked.png")),
+            music_icon_on: asset_server.load(mod_list.resolve_sprite("icons/music_icon_on.png")),
+            music_icon_off: asset_server.load(mod_list.resolve_sprite("icons/music_icon_off.png")),
+            music_icon_hovered: asset_server.load(mod_list.resolve_sprite("icons/music_icon_hovered.png")),
+            sound_icon_on: asset_server.load(mod_list.resolve_sprite("icons/sound_icon_on.png")),
+            sound_icon_off: asset_server.load(mod_list.resolve_sprite("icons/sound_icon_off.png")),
+            sound_icon_hovered: asset_server.load(mod_list.resolve_sprite("icons/sound_icon_hovered.png")),
+            leaderboard: asset_server.load(mod_list.resolve_sprite("icons/leaderboard.png")),
+            leaderboard_hovered: asset_server.load(mod_list.resolve_sprite("icons/leaderboard_hovered.png")),
+            restart: asset_server.load(mod_list.resolve_sprite("icons/restart.png")),
+            restart_hovered: asset_server.load(mod_list.resolve_sprite("icons/restart_hovered.png")),
         },
-        book_tileset: asset_server.load("scenes/book.png"),
+        book_tileset: asset_server.load(mod_list.resolve_sprite("scenes/book.png")),
         heroes_materials: HeroesMaterials {
-            male_elf: asset_server.load("scenes/heroes/male_elf.png"),
-            male_knight: asset_server.load("scenes/heroes/male_knight.png"),
-            male_wizard: asset_server.load("scenes/heroes/male_wizard.png"),
-            male_lizard: asset_server.load("scenes/heroes/male_lizard.png"),
-            female_elf: asset_server.load("scenes/heroes/female_elf.png"),
-            female_knight: asset_server.load("scenes/heroes/female_knight.png"),
-            female_wizard: asset_server.load("scenes/heroes/female_wizard.png"),
-            female_lizard: asset_server.load("scenes/heroes/female_lizard.png"),
+            textures: game_data
+                .get_heroes()
+                .iter()
+                .flat_map(|hero| hero.variants.iter())
+                .map(|variant| (variant.portrait.clone(), asset_server.load(mod_list.resolve_sprite(&variant.portrait))))
+                .collect(),
         },
         flag_materials: FlagMaterials {
-            vietnam: asset_server.load("scenes/vietnam.png"),
-            united_states: asset_server.load("scenes/united_states.png"),
+            vietnam: asset_server.load(mod_list.resolve_sprite("scenes/vietnam.png")),
+            united_states: asset_server.load(mod_list.resolve_sprite("scenes/united_states.png")),
         },
     };
 
-    let ingame_materials: InGameMaterials = InGameMaterials {
-        heroes_materials: HeroesMaterials {
-            male_elf: asset_server.load("ingame/heroes/male_elf.png"),
-            male_knight: asset_server.load("ingame/heroes/male_knight.png"),
-            male_wizard: asset_server.load("ingame/heroes/male_wizard.png"),
-            male_lizard: asset_server.load("ingame/heroes/male_lizard.png"),
-            female_elf: asset_server.load("ingame/heroes/female_elf.png"),
-            female_knight: asset_server.load("ingame/heroes/female_knight.png"),
-            female_wizard: asset_server.load("ingame/heroes/female_wizard.png"),
-            female_lizard: asset_server.load("ingame/heroes/female_lizard.png"),
-        },
-        weapons_materials: WeaponsMaterials {
-            bow: asset_server.load("ingame/weapons/bow.png"),
-            short_sword: asset_server.load("ingame/weapons/short_sword.png"),
-            sword: asset_server.load("ingame/weapons/sword.png"),
-            machete: asset_server.load("ingame/weapons/machete.png"),
-            small_hammer: asset_server.load("ingame/weapons/small_hammer.png"),
-            mace: asset_server.load("ingame/weapons/mace.png"),
-            big_hammer: asset_server.load("ingame/weapons/big_hammer.png"),
-            small_wand: asset_server.load("ingame/weapons/small_wand.png"),
-            magic_wand: asset_server.load("ingame/weapons/magic_wand.png"),
-            magic_sword: asset_server.load("ingame/weapons/magic_sword.png"),
-            spear: asset_server.load("ingame/weapons/spear.png"),
-        },
-        bullet_materials: BulletsMaterials {
-            arrow: asset_server.load("ingame/bullets/arrow.png"),
-            bullet: asset_server.load("ingame/bullets/bullet.png"),
-        },
-        dungeon_materials: DungeonMaterials {
-            floor: asset_server.load("ingame/dungeon/floor.png"),
-            ladder: asset_server.load("ingame/dungeon/ladder.png"),
-            wall: asset_server.load("ingame/dungeon/wall.png"),
-            treasure: asset_server.load("ingame/dungeon/treasure.png"),
-            wall_border_mid: asset_server.load("ingame/dungeon/wall_border_mid.png"),
-            wall_border_corner_top_left: asset_server
-                .load("ingame/dungeon/wall_border_corner_top_left.png"),
-            wall_border_corner_top_right: asset_server
-                .load("ingame/dungeon/wall_border_corner_top_right.png"),
-            wall_border_corner_bottom_left: asset_server
-                .load("ingame/dungeon/wall_border_corner_bottom_left.png"),
-            wall_border_corner_bottom_right: asset_server
-                .load("ingame/dungeon/wall_border_corner_bottom_right.png"),
-            wall_border_left: asset_server.load("ingame/dungeon/wall_border_left.png"),
-            wall_border_right: asset_server.load("ingame/dungeon/wall_border_right.png"),
-            wall_border_corner_left: asset_server
-                .load("ingame/dungeon/wall_border_corner_left.png"),
-            wall_border_corner_right: asset_server
-                .load("ingame/dungeon/wall_border_corner_right.png"),
-            wall_left: asset_server.load("ingame/dungeon/wall_left.png"),
-            wall_right: asset_server.load("ingame/dungeon/wall_right.png"),
-            door_opened: asset_server.load("ingame/dungeon/door_opened.png"),
-            door_closed: asset_server.load("ingame/dungeon/door_closed.png"),
-            door_top_part: asset_server.load("ingame/dungeon/door_top_part.png"),
-            door_left_part: asset_server.load("ingame/dungeon/door_left_part.png"),
-            door_right_part: asset_server.load("ingame/dungeon/door_right_part.png"),
-        },
-        hearts_materials: HeartsMaterials {
-            full_heart: asset_server.load("ingame/hearts/full_heart.png"),
-            half_heart: asset_server.load("ingame/hearts/half_heart.png"),
-            empty_heart: asset_server.load("ingame/hearts/empty_heart.png"),
-        },
-        monsters_materials: MonstersMaterials {
-            small_zombie: asset_server.load("ingame/monsters/small_zombie.png"),
-            zombie: asset_server.load("ingame/monsters/zombie.png"),
-            big_zombie: asset_server.load("ingame/monsters/big_zombie.png"),
-            goblin: asset_server.load("ingame/monsters/goblin.png"),
-            orc: asset_server.load("ingame/monsters/orc.png"),
-            ogre: asset_server.load("ingame/monsters/ogre.png"),
-            imp: asset_server.load("ingame/monsters/imp.png"),
-            chort: asset_server.load("ingame/monsters/chort.png"),
-            big_demon: asset_server.load("ingame/monsters/big_demon.png"),
-            swampy: asset_server.load("ingame/monsters/swampy.png"),
-        },
-        potions_materials: PotionsMaterials {
-            heal: asset_server.load("ingame/potions/heal.png"),
-            focus: asset_server.load("ingame/potions/focus.png"),
-            speed_up: asset_server.load("ingame/potions/speed_up.png"),
-            evasion_up: asset_server.load("ingame/potions/evasion_up.png"),
-        },
-    };
+    let ingame_materials = InGameMaterials::new(&asset_server, &game_data, &mod_list);
 
     commands.insert_resource(font_materials);
     commands.insert_resource(scenes_materials);
     commands.insert_resource(ingame_materials);
 }
 
-fn load_data(mut commands: Commands) {
-    commands.insert_resource(GameData::new());
-    commands.insert_resource(Rooms::new());
+fn load_data(mut commands: Commands, mod_list: Res<ModList>) {
+    let game_data = GameData::new(&mod_list);
+
+    commands.insert_resource(Rooms::new(&mod_list, &game_data));
+    commands.insert_resource(game_data);
 }
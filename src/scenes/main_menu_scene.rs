@@ -8,7 +8,7 @@ use crate::materials::scenes::ScenesMaterials;
 use crate::resources::dictionary::Dictionary;
 use crate::scenes::SceneState;
 
-const MAIN_MENU_BOX_ARRAY: [[i8; 5]; 8] = [
+const MAIN_MENU_BOX_ARRAY: [[i8; 5]; 9] = [
     [0, 1, 1, 1, 2],
     [3, 4, 4, 4, 5],
     [3, 4, 4, 4, 5],
@@ -16,14 +16,17 @@ const MAIN_MENU_BOX_ARRAY: [[i8; 5]; 8] = [
     [3, 4, 4, 4, 5],
     [3, 4, 4, 4, 5],
     [3, 4, 4, 4, 5],
+    [3, 4, 4, 4, 5],
     [6, 7, 7, 7, 8],
 ];
 const FONT_SIZE: f32 = 36.0;
 const MAIN_MENU_BOX_TILE_SIZE: f32 = 50.0;
+const MAIN_MENU_BOX_TOP: f32 = 100.0;
 
 #[derive(Component, Copy, Clone)]
 enum ButtonComponent {
     Play,
+    Upgrades,
     Highscore,
     Options,
     Help,
@@ -35,6 +38,7 @@ impl ButtonComponent {
     pub fn iterator() -> Iter<'static, ButtonComponent> {
         [
             ButtonComponent::Play,
+            ButtonComponent::Upgrades,
             ButtonComponent::Highscore,
             ButtonComponent::Options,
             ButtonComponent::Help,
@@ -118,7 +122,7 @@ fn main_menu_box(root: &mut ChildBuilder, menu_box_materials: &MenuBoxMaterials)
                 style: Style {
                     position_type: PositionType::Absolute,
                     left: Val::Px(10.0 + MAIN_MENU_BOX_TILE_SIZE * column_index as f32),
-                    top: Val::Px(150.0 + MAIN_MENU_BOX_TILE_SIZE * row_index as f32),
+                    top: Val::Px(MAIN_MENU_BOX_TOP + MAIN_MENU_BOX_TILE_SIZE * row_index as f32),
                     bottom: Val::Auto,
                     right: Val::Auto,
                     width: Val::Px(MAIN_MENU_BOX_TILE_SIZE),
@@ -146,7 +150,7 @@ fn buttons(root: &mut ChildBuilder, materials: &Res<FontMaterials>, dictionary:
                 align_self: AlignSelf::FlexEnd,
                 left: Val::Px(10.0 + MAIN_MENU_BOX_TILE_SIZE * (3.0 - 1.0) / 2.0),
                 right: Val::Auto,
-                top: Val::Px(150.0 + MAIN_MENU_BOX_TILE_SIZE * (index as f32 + 1.0)),
+                top: Val::Px(MAIN_MENU_BOX_TOP + MAIN_MENU_BOX_TILE_SIZE * (index as f32 + 1.0)),
                 bottom: Val::Auto,
                 ..Default::default()
             },
@@ -156,6 +160,7 @@ fn buttons(root: &mut ChildBuilder, materials: &Res<FontMaterials>, dictionary:
         .with_children(|parent| {
             let text: &str = match button {
                 ButtonComponent::Play => glossary.main_menu_scene_text.play.as_str(),
+                ButtonComponent::Upgrades => glossary.main_menu_scene_text.upgrades.as_str(),
                 ButtonComponent::Highscore => glossary.main_menu_scene_text.highscore.as_str(),
                 ButtonComponent::Options => glossary.main_menu_scene_text.options.as_str(),
                 ButtonComponent::Help => glossary.main_menu_scene_text.help.as_str(),
@@ -198,6 +203,7 @@ fn button_handle_system(
                 text.sections[0].style.color = Color::RED;
                 match button {
                     ButtonComponent::Play => state.set(SceneState::GameModeSelectScene),
+                    ButtonComponent::Upgrades => state.set(SceneState::MetaProgressionScene),
                     ButtonComponent::Highscore => state.set(SceneState::HighscoreScene),
                     ButtonComponent::Options => state.set(SceneState::OptionsScene),
                     ButtonComponent::Help => state.set(SceneState::HelpScene),
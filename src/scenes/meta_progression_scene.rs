@@ -0,0 +1,472 @@
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+use crate::config::*;
+use crate::materials::font::FontMaterials;
+use crate::materials::menu_box::MenuBoxMaterials;
+use crate::materials::scenes::ScenesMaterials;
+use crate::resources::dictionary::Dictionary;
+use crate::resources::game_data::GameData;
+use crate::resources::glossary::Glossary;
+use crate::resources::meta_progression::achievement::{AchievementRequirement, Unlock};
+use crate::resources::meta_progression::meta_upgrade::MetaUpgradeType;
+use crate::resources::meta_progression::MetaProgression;
+use crate::scenes::SceneState;
+
+const RETURN_BUTTON_SIDE: f32 = 50.0;
+
+const BOX_TILE_SIZE: f32 = 60.0;
+const BOX_WIDTH_TILES: f32 = 16.0;
+const BOX_HEIGHT_TILES: f32 = 9.0;
+
+const BOX_ARRAY: [[i8; 16]; 9] = [
+    [0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 2],
+    [3, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 5],
+    [3, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 5],
+    [3, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 5],
+    [3, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 5],
+    [3, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 5],
+    [3, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 5],
+    [3, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 5],
+    [6, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 8],
+];
+
+const UPGRADES_LEFT: f32 = 80.0;
+const BUY_BUTTON_LEFT: f32 = 400.0;
+const ACHIEVEMENTS_LEFT: f32 = 560.0;
+const ROWS_TOP: f32 = 170.0;
+
+#[derive(Component)]
+struct ReturnButtonComponent;
+
+#[derive(Component, Clone, Copy)]
+struct BuyButtonComponent(MetaUpgradeType);
+
+#[derive(Component)]
+struct MetaProgressionListComponent;
+
+type ReturnButtonQuery<'w, 's> = Query<
+    'w,
+    's,
+    (&'static Interaction, &'static mut UiImage),
+    (Changed<Interaction>, With<ReturnButtonComponent>),
+>;
+
+type BuyButtonQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static Interaction,
+        &'static BuyButtonComponent,
+        &'static Children,
+    ),
+    (Changed<Interaction>, With<Button>),
+>;
+
+#[derive(SystemParam)]
+struct ListText<'w> {
+    font_materials: Res<'w, FontMaterials>,
+    dictionary: Res<'w, Dictionary>,
+}
+
+#[derive(Resource)]
+struct MetaProgressionSceneData {
+    user_interface_root: Entity,
+}
+
+pub struct MetaProgressionScenePlugin;
+
+impl Plugin for MetaProgressionScenePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(SceneState::MetaProgressionScene), setup);
+        app.add_systems(
+            Update,
+            (return_button_handle, buy_button_handle)
+                .run_if(in_state(SceneState::MetaProgressionScene)),
+        );
+        app.add_systems(OnExit(SceneState::MetaProgressionScene), cleanup);
+    }
+}
+
+fn setup(
+    scenes_materials: Res<ScenesMaterials>,
+    font_materials: Res<FontMaterials>,
+    dictionary: Res<Dictionary>,
+    game_data: Res<GameData>,
+    meta_progression: Res<MetaProgression>,
+    mut commands: Commands,
+) {
+    let font = font_materials.get_font(dictionary.get_current_language());
+    let glossary = dictionary.get_glossary();
+
+    let user_interface_root = commands
+        .spawn(ImageBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                ..Default::default()
+            },
+            image: UiImage::new(scenes_materials.sub_background_image.clone()),
+            ..Default::default()
+        })
+        .with_children(|parent| {
+            menu_box(parent, &scenes_materials.menu_box_materials);
+            title_text(parent, font.clone(), &glossary);
+            return_button(parent, &scenes_materials);
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        position_type: PositionType::Absolute,
+                        width: Val::Percent(100.0),
+                        height: Val::Percent(100.0),
+                        ..Default::default()
+                    },
+                    background_color: BackgroundColor(Color::NONE),
+                    ..Default::default()
+                })
+                .with_children(|parent| {
+                    list(parent, font.clone(), &glossary, &game_data, &meta_progression);
+                })
+                .insert(MetaProgressionListComponent)
+                .insert(Name::new("MetaProgressionList"));
+        })
+        .insert(Name::new("UIRoot"))
+        .id();
+
+    commands.insert_resource(MetaProgressionSceneData {
+        user_interface_root,
+    });
+}
+
+fn cleanup(mut commands: Commands, meta_progression_scene_data: Res<MetaProgressionSceneData>) {
+    commands
+        .entity(meta_progression_scene_data.user_interface_root)
+        .despawn_recursive();
+}
+
+fn menu_box(root: &mut ChildBuilder, menu_box_materials: &MenuBoxMaterials) {
+    let start_left = (WINDOW_HEIGHT * RESOLUTION - BOX_TILE_SIZE * BOX_WIDTH_TILES) / 2.0;
+    let start_top = (WINDOW_HEIGHT - BOX_TILE_SIZE * BOX_HEIGHT_TILES) / 2.0;
+
+    for (row_index, row) in BOX_ARRAY.iter().enumerate() {
+        for (column_index, value) in row.iter().enumerate() {
+            let image: Handle<Image> = match value {
+                0 => menu_box_materials.top_left.clone(),
+                1 => menu_box_materials.top_center.clone(),
+                2 => menu_box_materials.top_right.clone(),
+                3 => menu_box_materials.mid_left.clone(),
+                4 => menu_box_materials.mid_center.clone(),
+                5 => menu_box_materials.mid_right.clone(),
+                6 => menu_box_materials.bottom_left.clone(),
+                7 => menu_box_materials.bottom_center.clone(),
+                8 => menu_box_materials.bottom_right.clone(),
+                _ => panic!("Unknown resources"),
+            };
+
+            root.spawn(ImageBundle {
+                image: UiImage::new(image),
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(start_left + BOX_TILE_SIZE * column_index as f32),
+                    top: Val::Px(start_top + BOX_TILE_SIZE * row_index as f32),
+                    bottom: Val::Auto,
+                    right: Val::Auto,
+                    width: Val::Px(BOX_TILE_SIZE),
+                    height: Val::Px(BOX_TILE_SIZE),
+                    ..Default::default()
+                },
+                ..Default::default()
+            });
+        }
+    }
+}
+
+fn return_button(root: &mut ChildBuilder, scenes_materials: &ScenesMaterials) {
+    let handle_image = scenes_materials.icon_materials.home_icon_normal.clone();
+
+    root.spawn(ButtonBundle {
+        style: Style {
+            left: Val::Px(RETURN_BUTTON_SIDE / 2.0),
+            top: Val::Px(RETURN_BUTTON_SIDE / 2.0),
+            right: Val::Auto,
+            bottom: Val::Auto,
+            width: Val::Px(RETURN_BUTTON_SIDE),
+            height: Val::Px(RETURN_BUTTON_SIDE),
+            justify_content: JustifyContent::Center,
+            position_type: PositionType::Absolute,
+            ..Default::default()
+        },
+        image: UiImage::new(handle_image),
+        ..Default::default()
+    })
+    .insert(Name::new("ReturnButton"))
+    .insert(ReturnButtonComponent);
+}
+
+fn title_text(root: &mut ChildBuilder, font: Handle<Font>, glossary: &Glossary) {
+    root.spawn(NodeBundle {
+        style: Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(40.0),
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            ..Default::default()
+        },
+        background_color: BackgroundColor(Color::NONE),
+        ..Default::default()
+    })
+    .with_children(|parent| {
+        parent.spawn(TextBundle {
+            text: Text::from_section(
+                glossary.meta_progression_scene_text.upgrades.clone(),
+                TextStyle {
+                    font,
+                    font_size: 50.0,
+                    color: Color::BLACK,
+                },
+            ),
+            ..Default::default()
+        });
+    })
+    .insert(Name::new("TitleText"));
+}
+
+fn list(
+    root: &mut ChildBuilder,
+    font: Handle<Font>,
+    glossary: &Glossary,
+    game_data: &GameData,
+    meta_progression: &MetaProgression,
+) {
+    let text = &glossary.meta_progression_scene_text;
+
+    root.spawn(TextBundle {
+        style: Style {
+            position_type: PositionType::Absolute,
+            left: Val::Px(UPGRADES_LEFT),
+            top: Val::Px(110.0),
+            ..Default::default()
+        },
+        text: Text::from_section(
+            format!("{}: {}", text.currency, meta_progression.currency),
+            TextStyle {
+                font: font.clone(),
+                font_size: 30.0,
+                color: Color::BLACK,
+            },
+        ),
+        ..Default::default()
+    });
+
+    for (index, meta_upgrade) in game_data.get_meta_upgrades().iter().enumerate() {
+        let top_position = ROWS_TOP + index as f32 * 50.0;
+        let level = meta_progression.get_upgrade_level(meta_upgrade.meta_upgrade_type);
+
+        let name = match meta_upgrade.meta_upgrade_type {
+            MetaUpgradeType::MaxHealth => text.max_health.clone(),
+            MetaUpgradeType::Strength => text.strength.clone(),
+            MetaUpgradeType::Intelligence => text.intelligence.clone(),
+            MetaUpgradeType::CriticalChance => text.critical_chance.clone(),
+            MetaUpgradeType::StartingPotion => text.starting_potion.clone(),
+            MetaUpgradeType::ExtraReroll => text.extra_reroll.clone(),
+        };
+
+        root.spawn(TextBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                left: Val::Px(UPGRADES_LEFT),
+                top: Val::Px(top_position),
+                ..Default::default()
+            },
+            text: Text::from_section(
+                format!("{} {}/{}", name, level, meta_upgrade.max_level),
+                TextStyle {
+                    font: font.clone(),
+                    font_size: 24.0,
+                    color: Color::BLACK,
+                },
+            )
+            .with_no_wrap(),
+            ..Default::default()
+        });
+
+        let button_text = if level >= meta_upgrade.max_level {
+            text.max.clone()
+        } else {
+            meta_upgrade.get_price(level).to_string()
+        };
+
+        root.spawn(ButtonBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                left: Val::Px(BUY_BUTTON_LEFT),
+                top: Val::Px(top_position),
+                width: Val::Px(120.0),
+                height: Val::Px(30.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..Default::default()
+            },
+            background_color: BackgroundColor(Color::NONE),
+            ..Default::default()
+        })
+        .with_children(|parent| {
+            parent.spawn(TextBundle {
+                text: Text::from_section(
+                    button_text,
+                    TextStyle {
+                        font: font.clone(),
+                        font_size: 24.0,
+                        color: Color::GRAY,
+                    },
+                )
+                .with_no_wrap(),
+                ..Default::default()
+            });
+        })
+        .insert(BuyButtonComponent(meta_upgrade.meta_upgrade_type));
+    }
+
+    root.spawn(TextBundle {
+        style: Style {
+            position_type: PositionType::Absolute,
+            left: Val::Px(ACHIEVEMENTS_LEFT),
+            top: Val::Px(110.0),
+            ..Default::default()
+        },
+        text: Text::from_section(
+            text.achievements.clone(),
+            TextStyle {
+                font: font.clone(),
+                font_size: 30.0,
+                color: Color::BLACK,
+            },
+        ),
+        ..Default::default()
+    });
+
+    for (index, achievement) in game_data.get_achievements().iter().enumerate() {
+        let top_position = ROWS_TOP + index as f32 * 60.0;
+        let is_achieved = meta_progression.achievements.contains(&achievement.name);
+
+        let name = match glossary.achievements.get(&achievement.name) {
+            Some(localized_name) => localized_name.clone(),
+            None => achievement.name.clone(),
+        };
+        let unlock = match achievement.unlock {
+            Unlock::Hero(ref hero_class) => hero_class.0.clone(),
+            Unlock::Weapon(ref weapon_type) => weapon_type.0.clone(),
+        };
+        let status = if is_achieved {
+            text.unlocked.clone() + unlock.as_str()
+        } else {
+            format!("{} ({})", unlock, text.locked)
+        };
+
+        let requirement = match achievement.requirement {
+            AchievementRequirement::TotalKilledMonsters(_) => text.killed_monsters.clone(),
+            AchievementRequirement::TotalClearedRooms(_) => text.cleared_rooms.clone(),
+            AchievementRequirement::TotalClearedWaves(_) => text.cleared_waves.clone(),
+            AchievementRequirement::CompletedRuns(_) => text.completed_runs.clone(),
+            AchievementRequirement::ReachedLevel(_) => text.reached_level.clone(),
+        };
+        let goal = achievement.requirement.get_goal();
+        let progress = meta_progression.get_progress(achievement.requirement).min(goal);
+
+        let color = if is_achieved {
+            Color::DARK_GREEN
+        } else {
+            Color::BLACK
+        };
+
+        root.spawn(TextBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                left: Val::Px(ACHIEVEMENTS_LEFT),
+                top: Val::Px(top_position),
+                ..Default::default()
+            },
+            text: Text::from_sections([
+                TextSection::new(
+                    format!("{} - {}\n", name, status),
+                    TextStyle {
+                        font: font.clone(),
+                        font_size: 22.0,
+                        color,
+                    },
+                ),
+                TextSection::new(
+                    format!("{}: {}/{}", requirement, progress, goal),
+                    TextStyle {
+                        font: font.clone(),
+                        font_size: 18.0,
+                        color,
+                    },
+                ),
+            ]),
+            ..Default::default()
+        });
+    }
+}
+
+fn return_button_handle(
+    mut button_query: ReturnButtonQuery,
+    scenes_materials: Res<ScenesMaterials>,
+    mut state: ResMut<NextState<SceneState>>,
+) {
+    for (interaction, mut ui_image) in button_query.iter_mut() {
+        match *interaction {
+            Interaction::None => {
+                ui_image.texture = scenes_materials.icon_materials.home_icon_normal.clone()
+            }
+            Interaction::Hovered => {
+                ui_image.texture = scenes_materials.icon_materials.home_icon_hovered.clone()
+            }
+            Interaction::Pressed => {
+                ui_image.texture = scenes_materials.icon_materials.home_icon_clicked.clone();
+                state.set(SceneState::MainMenuScene);
+            }
+        }
+    }
+}
+
+fn buy_button_handle(
+    mut button_query: BuyButtonQuery,
+    mut text_query: Query<&mut Text>,
+    list_query: Query<Entity, With<MetaProgressionListComponent>>,
+    mut meta_progression: ResMut<MetaProgression>,
+    game_data: Res<GameData>,
+    list_text: ListText,
+    mut commands: Commands,
+) {
+    for (interaction, buy_button, children) in button_query.iter_mut() {
+        let mut text = text_query.get_mut(children[0]).unwrap();
+        match *interaction {
+            Interaction::None => text.sections[0].style.color = Color::GRAY,
+            Interaction::Hovered => text.sections[0].style.color = Color::BLACK,
+            Interaction::Pressed => {
+                let meta_upgrade = game_data
+                    .get_meta_upgrades()
+                    .into_iter()
+                    .find(|meta_upgrade| meta_upgrade.meta_upgrade_type == buy_button.0)
+                    .unwrap();
+
+                if meta_progression.buy_upgrade(&meta_upgrade) {
+                    meta_progression.store();
+
+                    let dictionary = &list_text.dictionary;
+                    let font = list_text
+                        .font_materials
+                        .get_font(dictionary.get_current_language());
+                    let glossary = dictionary.get_glossary();
+                    commands
+                        .entity(list_query.single())
+                        .despawn_descendants()
+                        .with_children(|parent| {
+                            list(parent, font, &glossary, &game_data, &meta_progression);
+                        });
+                }
+            }
+        }
+    }
+}
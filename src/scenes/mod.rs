@@ -7,9 +7,11 @@ pub mod hero_select_scene;
 pub mod highscore_scene;
 pub mod loading_scene;
 pub mod main_menu_scene;
+pub mod meta_progression_scene;
 pub mod options_scene;
 pub mod pause_scene;
 pub mod result_scene;
+pub mod reward_scene;
 
 #[derive(Debug, Clone, Default, Eq, PartialEq, Hash, States)]
 pub enum SceneState {
@@ -20,6 +22,7 @@ pub enum SceneState {
     OptionsScene,
     HelpScene,
     CreditsScene,
+    MetaProgressionScene,
     GameModeSelectScene,
     HeroSelectScene,
     PreClassicMode,
@@ -27,5 +30,4 @@ pub enum SceneState {
     PreSurvivalMode,
     InGameSurvivalMode,
     ResultScene,
-    RewardScene,
 }
@@ -7,6 +7,7 @@ use crate::materials::menu_box::MenuBoxMaterials;
 use crate::materials::scenes::ScenesMaterials;
 use crate::resources::dictionary::Dictionary;
 use crate::resources::language::Language;
+use crate::resources::mods::ModList;
 use crate::resources::setting::Setting;
 use crate::scenes::SceneState;
 
@@ -16,14 +17,20 @@ const NORMAL_BUTTON_SIZE: f32 = 32.0;
 const MENU_BOX_TILE_SIZE: f32 = 60.0;
 
 const MENU_BOX_WIDTH_TILES: f32 = 8.0;
-const MENU_BOX_HEIGHT_TILES: f32 = 6.0;
+const MENU_BOX_HEIGHT_TILES: f32 = 8.0;
 
-const MENU_BOX_ARRAY: [[i8; 8]; 6] = [
+const MODS_LIST_TOP: f32 = 395.0;
+const MODS_LIST_LINE_HEIGHT: f32 = 24.0;
+const MODS_LIST_MAX_LINES: usize = 4;
+
+const MENU_BOX_ARRAY: [[i8; 8]; 8] = [
     [0, 1, 1, 1, 1, 1, 1, 2],
     [3, 4, 4, 4, 4, 4, 4, 5],
     [3, 4, 4, 4, 4, 4, 4, 5],
     [3, 4, 4, 4, 4, 4, 4, 5],
     [3, 4, 4, 4, 4, 4, 4, 5],
+    [3, 4, 4, 4, 4, 4, 4, 5],
+    [3, 4, 4, 4, 4, 4, 4, 5],
     [6, 7, 7, 7, 7, 7, 7, 8],
 ];
 
@@ -81,8 +88,12 @@ enum TextComponent {
     EnableSound,
     EnableMusic,
     Language,
+    Mods,
 }
 
+#[derive(Component)]
+struct ModsListComponent;
+
 impl TextComponent {
     pub fn iterator() -> Iter<'static, TextComponent> {
         [
@@ -90,6 +101,7 @@ impl TextComponent {
             TextComponent::EnableSound,
             TextComponent::EnableMusic,
             TextComponent::Language,
+            TextComponent::Mods,
         ]
         .iter()
     }
@@ -111,6 +123,7 @@ impl Plugin for OptionsScenePlugin {
                 button_handle_system,
                 pair_button_handle_system,
                 text_handle_system,
+                mods_list_handle_system,
             )
                 .run_if(in_state(SceneState::OptionsScene)),
         );
@@ -124,6 +137,7 @@ fn setup(
     scenes_materials: Res<ScenesMaterials>,
     setting: Res<Setting>,
     dictionary: Res<Dictionary>,
+    mod_list: Res<ModList>,
 ) {
     // user interface root
     let user_interface_root = commands
@@ -141,6 +155,22 @@ fn setup(
             texts(parent, &font_materials, &dictionary);
             buttons(parent, &setting, &scenes_materials);
             pair_buttons(parent, &setting, &scenes_materials);
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        position_type: PositionType::Absolute,
+                        left: Val::Px(320.0),
+                        top: Val::Px(MODS_LIST_TOP),
+                        flex_direction: FlexDirection::Column,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                })
+                .with_children(|parent| {
+                    mods_list(parent, &font_materials, &dictionary, &mod_list);
+                })
+                .insert(Name::new("ModsList"))
+                .insert(ModsListComponent);
         })
         .id();
     commands.insert_resource(OptionsSceneData {
@@ -207,11 +237,12 @@ fn texts(root: &mut ChildBuilder, font_materials: &FontMaterials, dictionary: &D
     let font = font_materials.get_font(dictionary.get_current_language());
     let glossary = dictionary.get_glossary();
 
-    let position_of_texts: [[f32; 2]; 4] = [
-        [440.0, 160.0],
+    let position_of_texts: [[f32; 2]; 5] = [
+        [440.0, 100.0],
+        [320.0, 170.0],
         [320.0, 230.0],
         [320.0, 290.0],
-        [320.0, 350.0],
+        [320.0, 345.0],
     ];
 
     for (index, prevalue) in TextComponent::iterator().enumerate() {
@@ -220,6 +251,7 @@ fn texts(root: &mut ChildBuilder, font_materials: &FontMaterials, dictionary: &D
             1 => glossary.options_scene_text.enable_music.clone(),
             2 => glossary.options_scene_text.enable_sound.clone(),
             3 => glossary.options_scene_text.language.clone(),
+            4 => glossary.options_scene_text.mods.clone(),
             _ => panic!("Unknown text"),
         };
 
@@ -228,6 +260,7 @@ fn texts(root: &mut ChildBuilder, font_materials: &FontMaterials, dictionary: &D
             1 => "EnableMusicText",
             2 => "EnableSoundText",
             3 => "LanguageText",
+            4 => "ModsText",
             _ => "Unknown text",
         };
 
@@ -269,13 +302,13 @@ fn buttons(root: &mut ChildBuilder, setting: &Setting, scenes_materials: &Scenes
         },
         UiRect {
             left: Val::Px(610.0),
-            top: Val::Px(230.0),
+            top: Val::Px(170.0),
             right: Val::Auto,
             bottom: Val::Auto,
         },
         UiRect {
             left: Val::Px(610.0),
-            top: Val::Px(290.0),
+            top: Val::Px(230.0),
             right: Val::Auto,
             bottom: Val::Auto,
         },
@@ -336,13 +369,13 @@ fn pair_buttons(root: &mut ChildBuilder, setting: &Setting, scenes_materials: &S
     let positions: [UiRect; 2] = [
         UiRect {
             left: Val::Px(570.0),
-            top: Val::Px(350.0),
+            top: Val::Px(290.0),
             right: Val::Auto,
             bottom: Val::Auto,
         },
         UiRect {
             left: Val::Px(620.0),
-            top: Val::Px(350.0),
+            top: Val::Px(290.0),
             right: Val::Auto,
             bottom: Val::Auto,
         },
@@ -509,7 +542,93 @@ fn text_handle_system(
                 TextComponent::Language => {
                     text.sections[0].value = glossary.options_scene_text.language.clone();
                 }
+                TextComponent::Mods => {
+                    text.sections[0].value = glossary.options_scene_text.mods.clone();
+                }
+            }
+        }
+    }
+}
+
+fn mods_list(
+    root: &mut ChildBuilder,
+    font_materials: &FontMaterials,
+    dictionary: &Dictionary,
+    mod_list: &ModList,
+) {
+    let font = font_materials.get_font(dictionary.get_current_language());
+    let glossary = dictionary.get_glossary();
+
+    let mut lines: Vec<String> = mod_list
+        .packs
+        .iter()
+        .map(|pack| {
+            let conflicts = mod_list.get_conflicts(&pack.manifest.id);
+            if !pack.manifest.enabled {
+                format!(
+                    "{} {} - {}",
+                    pack.manifest.name, pack.manifest.version, glossary.options_scene_text.disabled
+                )
+            } else if conflicts > 0 {
+                format!(
+                    "{} {} - {} {}",
+                    pack.manifest.name,
+                    pack.manifest.version,
+                    conflicts,
+                    glossary.options_scene_text.conflicts
+                )
+            } else {
+                format!("{} {}", pack.manifest.name, pack.manifest.version)
             }
+        })
+        .collect();
+
+    if lines.is_empty() {
+        lines.push(glossary.options_scene_text.no_mods.clone());
+    } else if lines.len() > MODS_LIST_MAX_LINES {
+        let hidden_mods = lines.len() - (MODS_LIST_MAX_LINES - 1);
+        lines.truncate(MODS_LIST_MAX_LINES - 1);
+        lines.push(format!(
+            "+{} {}",
+            hidden_mods, glossary.options_scene_text.more_mods
+        ));
+    }
+
+    for line in lines {
+        root.spawn(TextBundle {
+            style: Style {
+                height: Val::Px(MODS_LIST_LINE_HEIGHT),
+                ..Default::default()
+            },
+            text: Text::from_section(
+                line,
+                TextStyle {
+                    font: font.clone(),
+                    font_size: 20.0,
+                    color: Color::BLACK,
+                },
+            ),
+            ..Default::default()
+        })
+        .insert(Name::new("ModText"));
+    }
+}
+
+fn mods_list_handle_system(
+    mods_list_query: Query<Entity, With<ModsListComponent>>,
+    font_materials: Res<FontMaterials>,
+    dictionary: Res<Dictionary>,
+    mod_list: Res<ModList>,
+    mut commands: Commands,
+) {
+    if dictionary.is_changed() {
+        for mods_list_entity in mods_list_query.iter() {
+            commands
+                .entity(mods_list_entity)
+                .despawn_descendants()
+                .with_children(|parent| {
+                    mods_list(parent, &font_materials, &dictionary, &mod_list);
+                });
         }
     }
 }
@@ -10,7 +10,9 @@ use crate::materials::menu_box::MenuBoxMaterials;
 use crate::materials::scenes::ScenesMaterials;
 use crate::resources::dictionary::Dictionary;
 use crate::resources::game_mode::GameMode;
+use crate::resources::game_data::GameData;
 use crate::resources::language::Language;
+use crate::resources::meta_progression::MetaProgression;
 use crate::resources::profile::Profile;
 use crate::resources::stored_profile::StoredProfile;
 use crate::scenes::SceneState;
@@ -25,6 +27,20 @@ const MENU_BOX_TILE_SIZE: f32 = 60.0;
 const MENU_BOX_WIDTH_TILES: f32 = 9.0;
 const MENU_BOX_HEIGHT_TILES: f32 = 9.0;
 
+const DEATH_RECAP_MENU_BOX_WIDTH_TILES: f32 = 15.0;
+
+const DEATH_RECAP_MENU_BOX_ARRAY: [[i8; 15]; 9] = [
+    [0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 2],
+    [3, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 5],
+    [3, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 5],
+    [3, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 5],
+    [3, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 5],
+    [3, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 5],
+    [3, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 5],
+    [3, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 5],
+    [6, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 8],
+];
+
 const MENU_BOX_ARRAY: [[i8; 9]; 9] = [
     [0, 1, 1, 1, 1, 1, 1, 1, 2],
     [3, 4, 4, 4, 4, 4, 4, 4, 5],
@@ -90,7 +106,7 @@ pub struct ResultScenePlugin;
 
 impl Plugin for ResultScenePlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(OnEnter(SceneState::ResultScene), setup);
+        app.add_systems(OnEnter(SceneState::ResultScene), (record_run, setup).chain());
         app.add_systems(
             Update,
             (
@@ -104,6 +120,17 @@ impl Plugin for ResultScenePlugin {
     }
 }
 
+fn record_run(
+    mut meta_progression: ResMut<MetaProgression>,
+    mut profile: ResMut<Profile>,
+    game_data: Res<GameData>,
+) {
+    profile.earned_currency = MetaProgression::calculate_currency(&profile);
+    profile.unlocked_achievements =
+        meta_progression.record_run(&profile, &game_data.get_achievements());
+    meta_progression.store();
+}
+
 fn setup(
     mut commands: Commands,
     font_materials: Res<FontMaterials>,
@@ -123,9 +150,25 @@ fn setup(
             ..Default::default()
         })
         .with_children(|parent| {
-            menu_box(parent, &scenes_materials.menu_box_materials);
+            if profile.death_recap.is_player_dead {
+                menu_box(
+                    parent,
+                    &scenes_materials.menu_box_materials,
+                    DEATH_RECAP_MENU_BOX_WIDTH_TILES,
+                    &DEATH_RECAP_MENU_BOX_ARRAY,
+                );
+                death_recap_texts(parent, &font_materials, &dictionary, &profile);
+            } else {
+                menu_box(
+                    parent,
+                    &scenes_materials.menu_box_materials,
+                    MENU_BOX_WIDTH_TILES,
+                    &MENU_BOX_ARRAY,
+                );
+            }
             result_text(parent, &font_materials, &dictionary);
             texts(parent, &font_materials, &dictionary, &profile);
+            meta_progression_text(parent, &font_materials, &dictionary, &profile);
             return_button(parent, &scenes_materials);
             save_profile_button(parent, &scenes_materials, profile);
             play_again_button(parent, &scenes_materials);
@@ -147,15 +190,20 @@ fn cleanup(mut commands: Commands, result_scene_data: Res<ResultSceneData>) {
         .despawn_recursive();
 }
 
-fn menu_box(root: &mut ChildBuilder, menu_box_materials: &MenuBoxMaterials) {
-    let start_left = (WINDOW_HEIGHT * RESOLUTION - MENU_BOX_TILE_SIZE * MENU_BOX_WIDTH_TILES) / 2.0;
+fn menu_box<const WIDTH: usize>(
+    root: &mut ChildBuilder,
+    menu_box_materials: &MenuBoxMaterials,
+    menu_box_width_tiles: f32,
+    menu_box_array: &[[i8; WIDTH]; 9],
+) {
+    let start_left = (WINDOW_HEIGHT * RESOLUTION - MENU_BOX_TILE_SIZE * menu_box_width_tiles) / 2.0;
     let start_top = (WINDOW_HEIGHT - MENU_BOX_TILE_SIZE * MENU_BOX_HEIGHT_TILES) / 2.0;
 
     root.spawn(NodeBundle {
         ..Default::default()
     })
     .with_children(|parent| {
-        for (row_index, row) in MENU_BOX_ARRAY.iter().enumerate() {
+        for (row_index, row) in menu_box_array.iter().enumerate() {
             for (column_index, value) in row.iter().enumerate() {
                 let image: Handle<Image> = match value {
                     0 => menu_box_materials.top_left.clone(),
@@ -237,7 +285,11 @@ fn texts(
     .with_children(|parent| {
         for (index, prefix) in PrefixWordComponent::iterator().enumerate() {
             let top_position = 110.0 + (index as f32) * 40.0;
-            let left_position = 300.0;
+            let left_position = if profile.death_recap.is_player_dead {
+                100.0
+            } else {
+                300.0
+            };
 
             let value: String = match prefix {
                 PrefixWordComponent::GameMode => {
@@ -427,6 +479,147 @@ fn texts(
     .insert(Name::new("Texts"));
 }
 
+fn meta_progression_text(
+    root: &mut ChildBuilder,
+    font_materials: &FontMaterials,
+    dictionary: &Dictionary,
+    profile: &Profile,
+) {
+    let font = font_materials.get_font(dictionary.get_current_language());
+    let glossary = dictionary.get_glossary();
+
+    let mut value = glossary.meta_progression_scene_text.earned.clone()
+        + profile.earned_currency.to_string().as_str();
+
+    if !profile.unlocked_achievements.is_empty() {
+        let achievements = profile
+            .unlocked_achievements
+            .iter()
+            .map(|name| match glossary.achievements.get(name) {
+                Some(localized_name) => localized_name.clone(),
+                None => name.clone(),
+            })
+            .collect::<Vec<String>>()
+            .join(", ");
+        value = format!(
+            "{}  {}{}",
+            value, glossary.meta_progression_scene_text.unlocked, achievements
+        );
+    }
+
+    root.spawn(NodeBundle {
+        style: Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(515.0),
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            ..Default::default()
+        },
+        background_color: BackgroundColor(Color::NONE),
+        ..Default::default()
+    })
+    .with_children(|parent| {
+        parent.spawn(TextBundle {
+            text: Text::from_section(
+                value,
+                TextStyle {
+                    font,
+                    font_size: 22.0,
+                    color: Color::BLACK,
+                },
+            ),
+            ..Default::default()
+        });
+    })
+    .insert(Name::new("MetaProgressionText"));
+}
+
+fn death_recap_texts(
+    root: &mut ChildBuilder,
+    font_materials: &FontMaterials,
+    dictionary: &Dictionary,
+    profile: &Profile,
+) {
+    let font = font_materials.get_font(dictionary.get_current_language());
+    let glossary = dictionary.get_glossary();
+    let death_recap = &profile.death_recap;
+
+    let mut values: Vec<String> = Vec::new();
+    values.push(glossary.result_scene_text.death_recap.clone());
+
+    let killed_by = match &death_recap.killed_by {
+        Some(source) => glossary.get_hit_source_name(source),
+        None => glossary.result_scene_text.none.clone(),
+    };
+    values.push(glossary.result_scene_text.killed_by.clone() + killed_by.as_str());
+
+    let reached = match (&profile.game_mode, death_recap.floor, death_recap.rooms_cleared, death_recap.wave) {
+        (GameMode::ClassicMode, Some(floor), Some(rooms_cleared), _) => format!(
+            "{} {} - {}{}",
+            glossary.ingame_text.floor,
+            floor,
+            glossary.result_scene_text.rooms_cleared,
+            rooms_cleared
+        ),
+        (GameMode::SurvivalMode, _, _, Some(wave)) => format!(
+            "{} {}",
+            glossary.ingame_text.wave,
+            wave
+        ),
+        _ => glossary.result_scene_text.none.clone(),
+    };
+    values.push(glossary.result_scene_text.reached.clone() + reached.as_str());
+    values.push(format!("{} {}", glossary.ingame_text.level, profile.level));
+
+    let active_effects = if death_recap.active_effects.is_empty() {
+        glossary.result_scene_text.none.clone()
+    } else {
+        death_recap
+            .active_effects
+            .iter()
+            .map(|effect_id| glossary.get_effect_name(effect_id))
+            .collect::<Vec<String>>()
+            .join(", ")
+    };
+    values.push(glossary.result_scene_text.active_effects.clone() + active_effects.as_str());
+
+    values.push(glossary.result_scene_text.last_hits.clone());
+    for hit in death_recap.last_hits.iter().rev() {
+        values.push(format!(
+            "  {} -{}",
+            glossary.get_hit_source_name(&hit.source),
+            hit.damage
+        ));
+    }
+
+    root.spawn(NodeBundle {
+        ..Default::default()
+    })
+    .with_children(|parent| {
+        for (index, value) in values.into_iter().enumerate() {
+            parent.spawn(TextBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(560.0),
+                    top: Val::Px(110.0 + (index as f32) * 40.0),
+                    ..Default::default()
+                },
+                text: Text::from_section(
+                    value,
+                    TextStyle {
+                        font: font.clone(),
+                        font_size: if index == 0 { 35.0 } else { 28.0 },
+                        color: Color::BLACK,
+                    },
+                )
+                .with_no_wrap(),
+                ..Default::default()
+            });
+        }
+    })
+    .insert(Name::new("DeathRecapTexts"));
+}
+
 fn return_button(root: &mut ChildBuilder, scenes_materials: &ScenesMaterials) {
     let handle_image = scenes_materials.icon_materials.home_icon_normal.clone();
 
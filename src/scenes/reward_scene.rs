@@ -0,0 +1,874 @@
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+use crate::config::*;
+use crate::components::dash::DashComponent;
+use crate::components::player::PlayerComponent;
+use crate::components::player_list_effects::PlayerListEffectsComponent;
+use crate::components::skill::SkillComponent;
+use crate::components::weapon::WeaponComponent;
+use crate::components::weapon_inventory::WeaponInventoryComponent;
+use crate::components::weapon_shoot_attack::WeaponShootAttackComponent;
+use crate::components::weapon_swing_attack::WeaponSwingAttackComponent;
+use crate::materials::font::FontMaterials;
+use crate::materials::menu_box::MenuBoxMaterials;
+use crate::materials::scenes::ScenesMaterials;
+use crate::plugins::weapon::inventory;
+use crate::resources::dictionary::Dictionary;
+use crate::resources::game_data::{GameData, PauseSceneData};
+use crate::resources::glossary::Glossary;
+use crate::resources::hero::hero_class::HeroClass;
+use crate::resources::upgrade::rarity::Rarity;
+use crate::resources::upgrade::reward::Reward;
+use crate::resources::upgrade::upgrade_controller::{UpgradeController, REWARD_CHOICES};
+use crate::resources::upgrade::upgrade_type::UpgradeType;
+use crate::resources::weapon::weapon_type::WeaponType;
+use crate::resources::weapon::Weapon;
+use crate::scenes::SceneState;
+
+const BOX_TILE_SIZE: f32 = 60.0;
+const BOX_WIDTH_TILES: f32 = 14.0;
+const BOX_HEIGHT_TILES: f32 = 7.0;
+
+const BOX_ARRAY: [[i8; 14]; 7] = [
+    [0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 2],
+    [3, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 5],
+    [3, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 5],
+    [3, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 5],
+    [3, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 5],
+    [3, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 5],
+    [6, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 8],
+];
+
+const CARD_WIDTH: f32 = 250.0;
+const CARD_HEIGHT: f32 = 240.0;
+const OPTION_WIDTH: f32 = 190.0;
+
+#[derive(Component, Copy, Clone, PartialEq, Eq)]
+pub enum RewardSceneButton {
+    Choice(usize),
+    Reroll,
+    Banish,
+}
+
+#[derive(Component)]
+pub struct RewardChoicesComponent;
+
+// shared by classic mode floor exits and survival mode waves, drawn on top of the running game
+#[derive(Resource)]
+pub struct RewardSceneFlag;
+
+#[derive(SystemParam)]
+pub struct RewardText<'w> {
+    font_materials: Res<'w, FontMaterials>,
+    dictionary: Res<'w, Dictionary>,
+}
+
+#[derive(SystemParam)]
+pub struct RewardPool<'w> {
+    upgrade_controller: ResMut<'w, UpgradeController>,
+    game_data: Res<'w, GameData>,
+}
+
+#[derive(SystemParam)]
+pub struct OpenRewardScene<'w> {
+    reward_scene_data: ResMut<'w, RewardSceneData>,
+    pause_scene_data: Res<'w, PauseSceneData>,
+}
+
+// level ups, wave ends and floor exits only queue a reward, open_pending_rewards shows them one at a time
+#[derive(Resource, Default)]
+pub struct PendingRewards {
+    pub count: usize,
+}
+
+#[derive(Resource)]
+pub struct RewardSceneData {
+    pub choices: Vec<Reward>,
+    pub is_banishing: bool,
+    pub has_options: bool,
+}
+
+pub struct RewardScenePlugin;
+
+impl Plugin for RewardScenePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PendingRewards>();
+
+        app.add_systems(Update, (
+            choices_handle,
+            button_handle_system
+        ).run_if(resource_exists::<RewardSceneFlag>()));
+
+        app.add_systems(Update, open_pending_rewards.run_if(
+            in_state(SceneState::InGameClassicMode).or_else(in_state(SceneState::InGameSurvivalMode)).and_then(not(resource_exists::<PauseSceneData>()))
+        ));
+    }
+}
+
+pub fn roll_rewards(
+    upgrade_controller: &UpgradeController,
+    game_data: &GameData,
+    hero_class: HeroClass,
+    weapon_type: WeaponType,
+    skill_name: String,
+    excluded: &[Reward],
+) -> Vec<Reward> {
+    let weapon_upgrades = game_data.get_weapon_upgrades(hero_class, weapon_type);
+    upgrade_controller.get_rewards(skill_name, weapon_upgrades, excluded)
+}
+
+pub fn open_pending_rewards(
+    mut pending_rewards: ResMut<PendingRewards>,
+    player_query: Query<(&PlayerComponent, &SkillComponent)>,
+    weapon_query: Query<&WeaponComponent>,
+    upgrade_controller: Res<UpgradeController>,
+    game_data: Res<GameData>,
+    scenes_materials: Res<ScenesMaterials>,
+    mut commands: Commands,
+) {
+    if pending_rewards.count == 0 {
+        return;
+    }
+    pending_rewards.count -= 1;
+
+    let (player, player_skill) = player_query.single();
+    let weapon = weapon_query.single();
+    let rewards = roll_rewards(
+        &upgrade_controller,
+        &game_data,
+        player.class.clone(),
+        weapon.name.clone(),
+        player_skill.skill.name.clone(),
+        &[],
+    );
+
+    if !rewards.is_empty() {
+        open(&mut commands, &scenes_materials, rewards);
+    }
+}
+
+pub fn open(commands: &mut Commands, scenes_materials: &ScenesMaterials, choices: Vec<Reward>) {
+    spawn(commands, scenes_materials, choices, true);
+}
+
+// weapon branches are already paid for, so they can't be rerolled or banished into other rewards
+pub fn open_weapon_branches(
+    commands: &mut Commands,
+    scenes_materials: &ScenesMaterials,
+    weapon_upgrades: Vec<Weapon>,
+) {
+    let choices = weapon_upgrades
+        .into_iter()
+        .take(REWARD_CHOICES)
+        .map(Reward::Weapon)
+        .collect();
+    spawn(commands, scenes_materials, choices, false);
+}
+
+fn spawn(
+    commands: &mut Commands,
+    scenes_materials: &ScenesMaterials,
+    choices: Vec<Reward>,
+    has_options: bool,
+) {
+    let user_interface_root = commands
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                position_type: PositionType::Absolute,
+                ..Default::default()
+            },
+            background_color: BackgroundColor(Color::NONE),
+            ..Default::default()
+        })
+        .with_children(|parent| {
+            menu_box(parent, &scenes_materials.menu_box_materials);
+            parent
+                .spawn(NodeBundle {
+                    ..Default::default()
+                })
+                .insert(RewardChoicesComponent)
+                .insert(Name::new("RewardChoices"));
+        })
+        .insert(Name::new("RewardUI"))
+        .id();
+
+    commands.insert_resource(PauseSceneData {
+        user_interface_root,
+    });
+    commands.insert_resource(RewardSceneData {
+        choices,
+        is_banishing: false,
+        has_options,
+    });
+    commands.insert_resource(RewardSceneFlag);
+}
+
+fn close(commands: &mut Commands, pause_scene_data: &PauseSceneData) {
+    commands
+        .entity(pause_scene_data.user_interface_root)
+        .despawn_recursive();
+    commands.remove_resource::<PauseSceneData>();
+    commands.remove_resource::<RewardSceneData>();
+    commands.remove_resource::<RewardSceneFlag>();
+}
+
+fn menu_box(root: &mut ChildBuilder, menu_box_materials: &MenuBoxMaterials) {
+    let start_left = (WINDOW_HEIGHT * RESOLUTION - BOX_TILE_SIZE * BOX_WIDTH_TILES) / 2.0;
+    let start_top = (WINDOW_HEIGHT - BOX_TILE_SIZE * BOX_HEIGHT_TILES) / 2.0;
+
+    root.spawn(NodeBundle {
+        ..Default::default()
+    })
+    .with_children(|parent| {
+        for (row_index, row) in BOX_ARRAY.iter().enumerate() {
+            for (column_index, value) in row.iter().enumerate() {
+                let image: Handle<Image> = match value {
+                    0 => menu_box_materials.top_left.clone(),
+                    1 => menu_box_materials.top_center.clone(),
+                    2 => menu_box_materials.top_right.clone(),
+                    3 => menu_box_materials.mid_left.clone(),
+                    4 => menu_box_materials.mid_center.clone(),
+                    5 => menu_box_materials.mid_right.clone(),
+                    6 => menu_box_materials.bottom_left.clone(),
+                    7 => menu_box_materials.bottom_center.clone(),
+                    8 => menu_box_materials.bottom_right.clone(),
+                    _ => panic!("Unknown resources"),
+                };
+
+                parent.spawn(ImageBundle {
+                    image: UiImage::new(image),
+                    style: Style {
+                        position_type: PositionType::Absolute,
+                        left: Val::Px(start_left + BOX_TILE_SIZE * column_index as f32),
+                        top: Val::Px(start_top + BOX_TILE_SIZE * row_index as f32),
+                        bottom: Val::Auto,
+                        right: Val::Auto,
+                        width: Val::Px(BOX_TILE_SIZE),
+                        height: Val::Px(BOX_TILE_SIZE),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                });
+            }
+        }
+    })
+    .insert(Name::new("MenuBox"));
+}
+
+fn reward_label(glossary: &Glossary, reward: &Reward) -> String {
+    match reward {
+        Reward::Weapon(weapon) => format!(
+            "{}: {}",
+            glossary.ingame_text.weapon,
+            glossary.get_weapon_name(&weapon.name)
+        ),
+        Reward::Upgrade(upgrade) => match upgrade.upgrade_type {
+            UpgradeType::Stats => glossary.ingame_text.stats.clone(),
+            UpgradeType::Skill => glossary.ingame_text.skill.clone(),
+            UpgradeType::Effect => match upgrade.effect_upgrade {
+                Some(ref effect_upgrade) => {
+                    format!(
+                        "{}: {}",
+                        glossary.ingame_text.effect,
+                        glossary.get_effect_name(&effect_upgrade.name)
+                    )
+                }
+                None => glossary.ingame_text.effect.clone(),
+            },
+            UpgradeType::Weapon => glossary.ingame_text.weapon.clone(),
+        },
+    }
+}
+
+fn rarity_label(glossary: &Glossary, rarity: Rarity) -> (String, Color) {
+    match rarity {
+        Rarity::Common => (glossary.upgrade_text.common.clone(), Color::DARK_GRAY),
+        Rarity::Rare => (glossary.upgrade_text.rare.clone(), Color::rgb(0.1, 0.35, 0.85)),
+        Rarity::Epic => (glossary.upgrade_text.epic.clone(), Color::PURPLE),
+        Rarity::Legendary => (glossary.upgrade_text.legendary.clone(), Color::rgb(0.9, 0.5, 0.0)),
+    }
+}
+
+fn percent(value: f32) -> String {
+    format!("{}%", (value * 100.0).round() as i64)
+}
+
+fn signed_percent(value: f32) -> String {
+    format!("{:+}%", (value * 100.0).round() as i64)
+}
+
+fn comparison(label: &str, bonus: String, before: String, after: String) -> String {
+    format!("{} {} ({} -> {})", bonus, label, before, after)
+}
+
+fn percent_comparison(label: &str, before: f32, bonus: Option<f32>) -> Option<String> {
+    bonus.map(|bonus| {
+        comparison(label, signed_percent(bonus), percent(before), percent(before + bonus))
+    })
+}
+
+fn reward_details(
+    glossary: &Glossary,
+    reward: &Reward,
+    player: &PlayerComponent,
+    player_skill: &SkillComponent,
+    player_list_effects: &PlayerListEffectsComponent,
+    player_dash: &DashComponent,
+    weapon: &WeaponComponent,
+) -> Vec<String> {
+    let ingame_text = &glossary.ingame_text;
+    let upgrade_text = &glossary.upgrade_text;
+    let mut details: Vec<Option<String>> = Vec::new();
+
+    match reward {
+        Reward::Weapon(raw_weapon) => {
+            details.push(Some(comparison(
+                &ingame_text.strength,
+                format!("{:+}", raw_weapon.strength - weapon.strength),
+                format!("{}", weapon.strength),
+                format!("{}", raw_weapon.strength),
+            )));
+            details.push(Some(comparison(
+                &ingame_text.intelligence,
+                format!("{:+}", raw_weapon.intelligence - weapon.intelligence),
+                format!("{}", weapon.intelligence),
+                format!("{}", raw_weapon.intelligence),
+            )));
+        }
+        Reward::Upgrade(upgrade) => match upgrade.upgrade_type {
+            UpgradeType::Stats => {
+                let stats_upgrade = upgrade.stats_upgrade.clone().unwrap();
+                let base_stats = &player.base_stats;
+                let dash = &player_dash.dash;
+
+                details.push(stats_upgrade.max_health_bonus.map(|bonus| {
+                    comparison(
+                        &upgrade_text.max_health,
+                        format!("{:+}", bonus),
+                        format!("{}", player.max_health_points),
+                        format!("{}", player.max_health_points + bonus),
+                    )
+                }));
+                details.push(stats_upgrade.speed_percent_bonus.map(|bonus| {
+                    comparison(
+                        &ingame_text.movement_speed,
+                        signed_percent(bonus),
+                        format!("{}", base_stats.speed.round()),
+                        format!("{}", (base_stats.speed + bonus * base_stats.speed).round()),
+                    )
+                }));
+                details.push(stats_upgrade.strength_bonus.map(|bonus| {
+                    comparison(
+                        &ingame_text.strength,
+                        format!("{:+}", bonus),
+                        format!("{}", player.strength),
+                        format!("{}", player.strength + bonus),
+                    )
+                }));
+                details.push(stats_upgrade.intelligence_bonus.map(|bonus| {
+                    comparison(
+                        &ingame_text.intelligence,
+                        format!("{:+}", bonus),
+                        format!("{}", player.intelligence),
+                        format!("{}", player.intelligence + bonus),
+                    )
+                }));
+                details.push(percent_comparison(
+                    &ingame_text.critical_chance,
+                    base_stats.critical_chance,
+                    stats_upgrade.critical_chance_bonus,
+                ));
+                details.push(percent_comparison(
+                    &ingame_text.dodge_chance,
+                    base_stats.dodge_chance,
+                    stats_upgrade.dodge_chance_bonus,
+                ));
+                details.push(percent_comparison(
+                    &ingame_text.restore_chance,
+                    base_stats.restore_chance,
+                    stats_upgrade.restore_chance_bonus,
+                ));
+                details.push(stats_upgrade.dash_cooldown_reduce.map(|reduce| {
+                    comparison(
+                        &upgrade_text.dash_cooldown,
+                        format!("-{:.1}s", reduce),
+                        format!("{:.1}s", dash.cooldown),
+                        format!("{:.1}s", (dash.cooldown - reduce).max(0.3)),
+                    )
+                }));
+                details.push(stats_upgrade.dash_invincible_duration_bonus.map(|bonus| {
+                    comparison(
+                        &upgrade_text.dash_invincible_duration,
+                        format!("+{:.1}s", bonus),
+                        format!("{:.1}s", dash.invincible_duration),
+                        format!("{:.1}s", dash.invincible_duration + bonus),
+                    )
+                }));
+            }
+            UpgradeType::Skill => {
+                let skill_upgrade = upgrade.skill_upgrade.clone().unwrap();
+                let skill = &player_skill.skill;
+
+                details.push(skill_upgrade.duration_bonus.map(|bonus| {
+                    let duration = skill.duration.unwrap_or(0);
+                    comparison(
+                        &upgrade_text.skill_duration,
+                        format!("{:+}s", bonus),
+                        format!("{}s", duration),
+                        format!("{}s", duration + bonus),
+                    )
+                }));
+                if let (Some(cooldown), Some(reduce)) = (skill.cooldown, skill_upgrade.cooldown_reduce)
+                {
+                    details.push(Some(comparison(
+                        &upgrade_text.skill_cooldown,
+                        format!("-{}s", reduce),
+                        format!("{}s", cooldown),
+                        format!("{}s", (cooldown - reduce).max(1)),
+                    )));
+                }
+                if let (Some(require_monsters), Some(reduce)) =
+                    (skill.require_monsters, skill_upgrade.require_monsters_reduce)
+                {
+                    details.push(Some(comparison(
+                        &upgrade_text.required_kills,
+                        format!("-{}", reduce),
+                        format!("{}", require_monsters),
+                        format!("{}", require_monsters.saturating_sub(reduce).max(1)),
+                    )));
+                }
+                details.push(skill_upgrade.area_bonus.map(|bonus| {
+                    let area = skill.area.unwrap_or(0.0);
+                    comparison(
+                        &upgrade_text.skill_area,
+                        format!("{:+}", bonus),
+                        format!("{}", area),
+                        format!("{}", area + bonus),
+                    )
+                }));
+                details.push(skill_upgrade.heal_bonus.map(|bonus| {
+                    let heal = skill.heal.unwrap_or(0.0);
+                    comparison(
+                        &upgrade_text.skill_heal,
+                        format!("{:+}", bonus),
+                        format!("{}", heal),
+                        format!("{}", heal + bonus),
+                    )
+                }));
+                if let (Some(damage), Some(bonus)) =
+                    (skill.damage.as_ref(), skill_upgrade.damage_multiplier_bonus)
+                {
+                    details.push(Some(comparison(
+                        &upgrade_text.skill_damage,
+                        format!("{:+}", bonus),
+                        format!("x{}", damage.multiplier),
+                        format!("x{}", damage.multiplier + bonus),
+                    )));
+                }
+                details.push(percent_comparison(
+                    &ingame_text.movement_speed,
+                    skill.speed_percent_bonus.unwrap_or(0.0),
+                    skill_upgrade.speed_percent_bonus,
+                ));
+                details.push(percent_comparison(
+                    &ingame_text.damage_percent_bonus,
+                    skill.damge_precent_bonus.unwrap_or(0.0),
+                    skill_upgrade.damage_percent_bonus,
+                ));
+                details.push(percent_comparison(
+                    &ingame_text.critical_chance,
+                    skill.critical_chance_bonus.unwrap_or(0.0),
+                    skill_upgrade.critical_chance_bonus,
+                ));
+                details.push(percent_comparison(
+                    &ingame_text.dodge_chance,
+                    skill.dodge_chance_bonus.unwrap_or(0.0),
+                    skill_upgrade.dodge_chance_bonus,
+                ));
+                details.push(percent_comparison(
+                    &ingame_text.restore_chance,
+                    skill.restore_chance_bonus.unwrap_or(0.0),
+                    skill_upgrade.restore_chance_bonus,
+                ));
+            }
+            UpgradeType::Effect => {
+                let effect_upgrade = upgrade.effect_upgrade.clone().unwrap();
+                let information = player_list_effects
+                    .information
+                    .iter()
+                    .find(|effect_information| effect_information.name == effect_upgrade.name)
+                    .unwrap();
+
+                let duration = effect_upgrade.duration_bonus.unwrap_or(0)
+                    - effect_upgrade.duration_reduce.unwrap_or(0);
+                if duration != 0 {
+                    let new_duration = if information.duration + duration > 0 {
+                        information.duration + duration
+                    } else {
+                        2
+                    };
+                    details.push(Some(comparison(
+                        &upgrade_text.effect_duration,
+                        format!("{:+}s", duration),
+                        format!("{}s", information.duration),
+                        format!("{}s", new_duration),
+                    )));
+                }
+
+                let bonus = effect_upgrade.speed_percent_bonus.unwrap_or(0.0)
+                    - effect_upgrade.speed_percent_reduce.unwrap_or(0.0)
+                    + effect_upgrade.critical_chance_bonus.unwrap_or(0.0)
+                    + effect_upgrade.dodge_chance_bonus.unwrap_or(0.0);
+                if bonus != 0.0 {
+                    details.push(percent_comparison(
+                        &upgrade_text.effect_bonus,
+                        information.bonus,
+                        Some(bonus),
+                    ));
+                }
+            }
+            UpgradeType::Weapon => {}
+        },
+    }
+
+    details.into_iter().flatten().collect()
+}
+
+fn button_text_color(button: RewardSceneButton, is_banishing: bool) -> Color {
+    match button {
+        RewardSceneButton::Choice(_) if is_banishing => Color::MAROON,
+        RewardSceneButton::Banish if is_banishing => Color::MAROON,
+        _ => Color::GRAY,
+    }
+}
+
+pub fn choices_handle(
+    mut commands: Commands,
+    reward_scene_data: Res<RewardSceneData>,
+    reward_choices_query: Query<Entity, With<RewardChoicesComponent>>,
+    player_query: Query<(
+        &PlayerComponent,
+        &SkillComponent,
+        &PlayerListEffectsComponent,
+        &DashComponent,
+    )>,
+    weapon_query: Query<&WeaponComponent>,
+    upgrade_controller: Res<UpgradeController>,
+    reward_text: RewardText,
+) {
+    if !reward_scene_data.is_changed() {
+        return;
+    }
+
+    let dictionary = &reward_text.dictionary;
+    let font = reward_text
+        .font_materials
+        .get_font(dictionary.get_current_language());
+    let glossary = dictionary.get_glossary();
+    let reward_choices = reward_choices_query.single();
+    let (player, player_skill, player_list_effects, player_dash) = player_query.single();
+    let weapon = weapon_query.single();
+
+    let center = WINDOW_HEIGHT * RESOLUTION / 2.0;
+    let cards_width = (CARD_WIDTH + 10.0) * REWARD_CHOICES as f32 - 10.0;
+
+    let options = if reward_scene_data.has_options {
+        vec![
+            (
+                RewardSceneButton::Reroll,
+                format!(
+                    "{} ({})",
+                    glossary.reward_scene_text.reroll, upgrade_controller.rerolls
+                ),
+                center - OPTION_WIDTH - 5.0,
+            ),
+            (
+                RewardSceneButton::Banish,
+                format!(
+                    "{} ({})",
+                    glossary.reward_scene_text.banish, upgrade_controller.banishes
+                ),
+                center + 5.0,
+            ),
+        ]
+    } else {
+        Vec::new()
+    };
+
+    commands
+        .entity(reward_choices)
+        .despawn_descendants()
+        .with_children(|grandparent| {
+            grandparent.spawn(TextBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(center - cards_width / 2.0),
+                    top: Val::Px(90.0),
+                    width: Val::Px(cards_width),
+                    justify_content: JustifyContent::Center,
+                    ..Default::default()
+                },
+                text: Text::from_section(
+                    glossary.reward_scene_text.choose_reward.clone(),
+                    TextStyle {
+                        font: font.clone(),
+                        font_size: 35.0,
+                        color: Color::BLACK,
+                    },
+                )
+                .with_alignment(TextAlignment::Center),
+                ..Default::default()
+            });
+
+            for (index, reward) in reward_scene_data.choices.iter().enumerate() {
+                let button = RewardSceneButton::Choice(index);
+                let value = reward_label(&glossary, reward);
+                let (rarity, rarity_color) = rarity_label(&glossary, reward.rarity());
+                let details = reward_details(
+                    &glossary,
+                    reward,
+                    player,
+                    player_skill,
+                    player_list_effects,
+                    player_dash,
+                    weapon,
+                );
+
+                grandparent
+                    .spawn(ButtonBundle {
+                        style: Style {
+                            left: Val::Px(center - cards_width / 2.0 + (CARD_WIDTH + 10.0) * index as f32),
+                            top: Val::Px(140.0),
+                            right: Val::Auto,
+                            bottom: Val::Auto,
+                            width: Val::Px(CARD_WIDTH),
+                            height: Val::Px(CARD_HEIGHT),
+                            flex_direction: FlexDirection::Column,
+                            align_items: AlignItems::Center,
+                            padding: UiRect::all(Val::Px(8.0)),
+                            position_type: PositionType::Absolute,
+                            ..Default::default()
+                        },
+                        background_color: BackgroundColor(Color::rgba(0.0, 0.0, 0.0, 0.08)),
+                        ..Default::default()
+                    })
+                    .with_children(|parent| {
+                        parent.spawn(TextBundle {
+                            text: Text::from_section(
+                                value.clone(),
+                                TextStyle {
+                                    font: font.clone(),
+                                    font_size: 26.0,
+                                    color: button_text_color(
+                                        button,
+                                        reward_scene_data.is_banishing,
+                                    ),
+                                },
+                            )
+                            .with_alignment(TextAlignment::Center),
+                            ..Default::default()
+                        });
+
+                        parent.spawn(TextBundle {
+                            text: Text::from_section(
+                                rarity,
+                                TextStyle {
+                                    font: font.clone(),
+                                    font_size: 22.0,
+                                    color: rarity_color,
+                                },
+                            )
+                            .with_alignment(TextAlignment::Center),
+                            ..Default::default()
+                        });
+
+                        for detail in details {
+                            parent.spawn(TextBundle {
+                                style: Style {
+                                    margin: UiRect::top(Val::Px(6.0)),
+                                    max_width: Val::Px(CARD_WIDTH - 16.0),
+                                    ..Default::default()
+                                },
+                                text: Text::from_section(
+                                    detail,
+                                    TextStyle {
+                                        font: font.clone(),
+                                        font_size: 18.0,
+                                        color: Color::BLACK,
+                                    },
+                                )
+                                .with_alignment(TextAlignment::Center),
+                                ..Default::default()
+                            });
+                        }
+                    })
+                    .insert(Name::new(value))
+                    .insert(button);
+            }
+
+            for (button, value, left_position) in options {
+                grandparent
+                    .spawn(ButtonBundle {
+                        style: Style {
+                            left: Val::Px(left_position),
+                            top: Val::Px(400.0),
+                            right: Val::Auto,
+                            bottom: Val::Auto,
+                            width: Val::Px(OPTION_WIDTH),
+                            height: Val::Px(35.0),
+                            justify_content: JustifyContent::Center,
+                            position_type: PositionType::Absolute,
+                            ..Default::default()
+                        },
+                        background_color: BackgroundColor(Color::NONE),
+                        ..Default::default()
+                    })
+                    .with_children(|parent| {
+                        parent.spawn(TextBundle {
+                            text: Text::from_section(
+                                value.clone(),
+                                TextStyle {
+                                    font: font.clone(),
+                                    font_size: 30.0,
+                                    color: button_text_color(
+                                        button,
+                                        reward_scene_data.is_banishing,
+                                    ),
+                                },
+                            )
+                            .with_alignment(TextAlignment::Center),
+                            ..Default::default()
+                        });
+                    })
+                    .insert(Name::new(value))
+                    .insert(button);
+            }
+        });
+}
+
+pub fn button_handle_system(
+    mut button_query: Query<
+        (&Interaction, &RewardSceneButton, &Children),
+        Changed<Interaction>,
+    >,
+    mut text_query: Query<&mut Text>,
+    mut player_query: Query<(
+        &mut PlayerComponent,
+        &mut SkillComponent,
+        &mut PlayerListEffectsComponent,
+        &mut DashComponent,
+        &mut WeaponInventoryComponent,
+    )>,
+    mut weapon_query: Query<(
+        &mut WeaponComponent,
+        &mut WeaponSwingAttackComponent,
+        &mut WeaponShootAttackComponent,
+    )>,
+    open_reward_scene: OpenRewardScene,
+    reward_pool: RewardPool,
+    mut commands: Commands,
+) {
+    let OpenRewardScene {
+        mut reward_scene_data,
+        pause_scene_data,
+    } = open_reward_scene;
+    let RewardPool {
+        mut upgrade_controller,
+        game_data,
+    } = reward_pool;
+
+    for (interaction, button, children) in button_query.iter_mut() {
+        let mut text = text_query.get_mut(children[0]).unwrap();
+        match *interaction {
+            Interaction::None => {
+                text.sections[0].style.color =
+                    button_text_color(*button, reward_scene_data.is_banishing)
+            }
+            Interaction::Hovered => text.sections[0].style.color = Color::BLACK,
+            Interaction::Pressed => {
+                let (
+                    mut player,
+                    mut player_skill,
+                    mut player_list_effects,
+                    mut player_dash,
+                    mut weapon_inventory,
+                ) = player_query.single_mut();
+                let (mut weapon, mut swing_attack, mut shoot_attack) = weapon_query.single_mut();
+
+                match *button {
+                    RewardSceneButton::Choice(index) => {
+                        let reward = reward_scene_data.choices[index].clone();
+
+                        if reward_scene_data.is_banishing {
+                            upgrade_controller.banish(reward);
+                            let choices = reward_scene_data.choices.clone();
+                            let replacement = roll_rewards(
+                                &upgrade_controller,
+                                &game_data,
+                                player.class.clone(),
+                                weapon.name.clone(),
+                                player_skill.skill.name.clone(),
+                                &choices,
+                            );
+
+                            reward_scene_data.choices.remove(index);
+                            if let Some(new_reward) = replacement.first() {
+                                reward_scene_data.choices.insert(index, new_reward.clone());
+                            }
+                            reward_scene_data.is_banishing = false;
+
+                            if reward_scene_data.choices.is_empty() {
+                                close(&mut commands, &pause_scene_data);
+                            }
+                            continue;
+                        }
+
+                        match reward {
+                            Reward::Weapon(raw_weapon) => {
+                                inventory::equip(
+                                    &mut weapon,
+                                    &mut swing_attack,
+                                    &mut shoot_attack,
+                                    &raw_weapon,
+                                );
+                                weapon_inventory.replace_current(raw_weapon);
+                            }
+                            Reward::Upgrade(upgrade) => match upgrade.upgrade_type {
+                                UpgradeType::Stats => {
+                                    player.upgrade_stats(upgrade.clone());
+                                    player_dash.upgrade(upgrade);
+                                }
+                                UpgradeType::Effect => player_list_effects.upgrade(upgrade),
+                                UpgradeType::Skill => player_skill.upgrade(upgrade),
+                                UpgradeType::Weapon => {}
+                            },
+                        }
+                        close(&mut commands, &pause_scene_data);
+                    }
+                    RewardSceneButton::Reroll => {
+                        if upgrade_controller.rerolls > 0 {
+                            upgrade_controller.rerolls -= 1;
+                            reward_scene_data.choices = roll_rewards(
+                                &upgrade_controller,
+                                &game_data,
+                                player.class.clone(),
+                                weapon.name.clone(),
+                                player_skill.skill.name.clone(),
+                                &[],
+                            );
+                            reward_scene_data.is_banishing = false;
+                        }
+                    }
+                    RewardSceneButton::Banish => {
+                        if upgrade_controller.banishes > 0 {
+                            reward_scene_data.is_banishing = !reward_scene_data.is_banishing;
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use serde::Serialize;
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::config::*;
+use crate::resources::dungeon::floor::Floor;
+use crate::resources::dungeon::room::Room;
+use crate::resources::dungeon::rooms::Rooms;
+use crate::resources::dungeon::validation::{validate_floor, validate_room};
+use crate::resources::dungeon::TOTAL_DUNGEON_FLOORS;
+use crate::resources::game_data::GameData;
+use crate::resources::glossary::Glossary;
+use crate::resources::language::Language;
+use crate::resources::mods::{ModList, UPGRADES_ID_KEYS};
+use crate::resources::upgrade::UpgradesData;
+
+#[derive(Serialize, Debug, Clone)]
+pub struct ValidationError {
+    pub file: String,
+    pub message: String,
+}
+
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct ValidationReport {
+    pub checked_files: Vec<String>,
+    pub errors: Vec<ValidationError>,
+}
+
+impl ValidationReport {
+    pub fn new() -> Self {
+        let mut report = ValidationReport::default();
+
+        let mod_list = match report.check(MODS_DIRECTORY, ModList::load) {
+            Some(mod_list) => mod_list,
+            None => return report,
+        };
+
+        let game_data = report.check(DATA_FILE, || GameData::new(&mod_list));
+        let upgrades_data: Option<UpgradesData> = report.check(UPGRADES_FILE, || {
+            mod_list.load_json(UPGRADES_FILE, &UPGRADES_ID_KEYS)
+        });
+
+        let mut glossaries = Vec::new();
+        for (file, language) in [
+            (ENGLISH_LANGUAGE_FILE, Language::EN),
+            (VIETNAMESE_LANGUAGE_FILE, Language::VI),
+        ] {
+            if let Some(glossary) = report.check(file, || Glossary::new(language, &mod_list)) {
+                glossaries.push((file, glossary));
+            }
+        }
+
+        if let Some(game_data) = &game_data {
+            report.add_errors(DATA_FILE, game_data.validate_references());
+
+            if let Some(upgrades_data) = &upgrades_data {
+                report.add_errors(UPGRADES_FILE, game_data.validate_upgrades(upgrades_data));
+            }
+
+            for (file, glossary) in glossaries.iter() {
+                report.add_errors(file, game_data.validate_glossary(glossary));
+            }
+        }
+
+        let room_files = report
+            .check(LIST_ROOM_FILE, || mod_list.get_room_files())
+            .unwrap_or_default();
+
+        let mut rooms = Vec::new();
+        for path in room_files {
+            let room = report.check(&path, || Room::load(path.clone(), game_data.as_ref()));
+            if let Some(room) = room {
+                report.add_errors(&path, validate_room(&room, game_data.as_ref()));
+                rooms.push(room);
+            }
+        }
+        let rooms = Rooms::from_rooms(rooms);
+
+        let floor_files = report
+            .check(LIST_FLOOR_FILE, || mod_list.get_floor_files())
+            .unwrap_or_default();
+
+        if floor_files.len() < TOTAL_DUNGEON_FLOORS {
+            report.add_errors(
+                LIST_FLOOR_FILE,
+                vec![format!(
+                    "Found {} floors, expected at least {}",
+                    floor_files.len(),
+                    TOTAL_DUNGEON_FLOORS
+                )],
+            );
+        }
+
+        for path in floor_files.iter() {
+            if let Some(map) = report.check(path, || Floor::load_map(path)) {
+                report.add_errors(path, validate_floor(path, &map, &rooms));
+            }
+        }
+
+        report
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    fn check<T>(&mut self, file: &str, load: impl FnOnce() -> T) -> Option<T> {
+        if !self
+            .checked_files
+            .iter()
+            .any(|checked_file| checked_file == file)
+        {
+            self.checked_files.push(file.to_string());
+        }
+
+        // Content errors are reported as panics by the loaders, keep them out of stderr
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(|_| {}));
+        let result = panic::catch_unwind(AssertUnwindSafe(load));
+        panic::set_hook(hook);
+
+        match result {
+            Ok(value) => Some(value),
+            Err(payload) => {
+                self.add_errors(file, vec![get_panic_message(payload)]);
+                None
+            }
+        }
+    }
+
+    fn add_errors(&mut self, file: &str, messages: Vec<String>) {
+        for message in messages {
+            self.errors.push(ValidationError {
+                file: file.to_string(),
+                message,
+            });
+        }
+    }
+}
+
+fn get_panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => "Unknown error".to_string(),
+        },
+    }
+}